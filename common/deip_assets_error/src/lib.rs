@@ -8,5 +8,6 @@ pub enum ReserveError<AssetId> {
 
 pub enum UnreserveError<AssetId> {
     NoSuchInvestment,
+    NotEnoughBalance,
     AssetTransferFailed(AssetId),
 }
@@ -63,7 +63,17 @@ impl runtime::WrappedCall<<RuntimeT as DeipProposal>::Call> {
             }
             .serialize(serializer),
 
-            create_investment_opportunity(external_id, creator, shares, funding_model) => {
+            create_investment_opportunity(
+                external_id,
+                creator,
+                shares,
+                funding_model,
+                vesting,
+                allowlist,
+                contribution_limits,
+                withdrawal,
+                exchange_rates,
+            ) => {
                 CallObject {
                     module: "deip",
                     call: "create_investment_opportunity",
@@ -72,6 +82,11 @@ impl runtime::WrappedCall<<RuntimeT as DeipProposal>::Call> {
                         creator,
                         shares,
                         funding_model,
+                        vesting,
+                        allowlist,
+                        contribution_limits,
+                        withdrawal,
+                        exchange_rates,
                     },
                 }
                 .serialize(serializer)
@@ -98,6 +113,20 @@ impl runtime::WrappedCall<<RuntimeT as DeipProposal>::Call> {
             }
             .serialize(serializer),
 
+            cancel_investment_opportunity(sale_id) => CallObject {
+                module: "deip",
+                call: "cancel_investment_opportunity",
+                args: &DeipCancelInvestmentOpportunityCallArgs { sale_id },
+            }
+            .serialize(serializer),
+
+            retry_settlement(sale_id) => CallObject {
+                module: "deip",
+                call: "retry_settlement",
+                args: &DeipRetrySettlementCallArgs { sale_id },
+            }
+            .serialize(serializer),
+
             invest(id, amount) => CallObject {
                 module: "deip",
                 call: "invest",
@@ -105,6 +134,70 @@ impl runtime::WrappedCall<<RuntimeT as DeipProposal>::Call> {
             }
             .serialize(serializer),
 
+            withdraw_investment(sale_id, amount) => CallObject {
+                module: "deip",
+                call: "withdraw_investment",
+                args: &DeipWithdrawInvestmentCallArgs { sale_id, amount },
+            }
+            .serialize(serializer),
+
+            submit_milestone(sale_id, content_id) => CallObject {
+                module: "deip",
+                call: "submit_milestone",
+                args: &DeipSubmitMilestoneCallArgs { sale_id, content_id },
+            }
+            .serialize(serializer),
+
+            vote_milestone(sale_id, approve) => CallObject {
+                module: "deip",
+                call: "vote_milestone",
+                args: &DeipVoteMilestoneCallArgs { sale_id, approve },
+            }
+            .serialize(serializer),
+
+            refund_missed_milestone(sale_id, investors) => CallObject {
+                module: "deip",
+                call: "refund_missed_milestone",
+                args: &DeipRefundMissedMilestoneCallArgs { sale_id, investors },
+            }
+            .serialize(serializer),
+
+            claim_vested(sale_id) => CallObject {
+                module: "deip",
+                call: "claim_vested",
+                args: &DeipClaimVestedCallArgs { sale_id },
+            }
+            .serialize(serializer),
+
+            create_allowlist(id, members) => CallObject {
+                module: "deip",
+                call: "create_allowlist",
+                args: &DeipCreateAllowlistCallArgs { id, members },
+            }
+            .serialize(serializer),
+
+            update_allowlist(id, to_add, to_remove) => CallObject {
+                module: "deip",
+                call: "update_allowlist",
+                args: &DeipUpdateAllowlistCallArgs {
+                    id,
+                    to_add,
+                    to_remove,
+                },
+            }
+            .serialize(serializer),
+
+            update_investment_allowlist(id, to_add, to_remove) => CallObject {
+                module: "deip",
+                call: "update_investment_allowlist",
+                args: &DeipUpdateAllowlistCallArgs {
+                    id,
+                    to_add,
+                    to_remove,
+                },
+            }
+            .serialize(serializer),
+
             update_project(project_id, description, is_private) => CallObject {
                 module: "deip",
                 call: "update_project",
@@ -116,6 +209,31 @@ impl runtime::WrappedCall<<RuntimeT as DeipProposal>::Call> {
             }
             .serialize(serializer),
 
+            remove_project(project_id, witness) => CallObject {
+                module: "deip",
+                call: "remove_project",
+                args: &DeipRemoveProjectCallArgs { project_id, witness },
+            }
+            .serialize(serializer),
+
+            transfer_project(project_id, new_team_id, with_acceptance) => CallObject {
+                module: "deip",
+                call: "transfer_project",
+                args: &DeipTransferProjectCallArgs {
+                    project_id,
+                    new_team_id,
+                    with_acceptance,
+                },
+            }
+            .serialize(serializer),
+
+            accept_project_transfer(project_id) => CallObject {
+                module: "deip",
+                call: "accept_project_transfer",
+                args: &DeipAcceptProjectTransferCallArgs { project_id },
+            }
+            .serialize(serializer),
+
             create_project_content(
                 external_id,
                 project_external_id,
@@ -141,6 +259,66 @@ impl runtime::WrappedCall<<RuntimeT as DeipProposal>::Call> {
             }
             .serialize(serializer),
 
+            amend_project_content(project_id, content_id, description, content, authors) => {
+                CallObject {
+                    module: "deip",
+                    call: "amend_project_content",
+                    args: &DeipAmendProjectContentCallArgs {
+                        project_id,
+                        content_id,
+                        description,
+                        content,
+                        authors,
+                    },
+                }
+                .serialize(serializer)
+            }
+
+            set_project_content_author_shares(project_id, content_id, shares) => CallObject {
+                module: "deip",
+                call: "set_project_content_author_shares",
+                args: &DeipSetProjectContentAuthorSharesCallArgs {
+                    project_id,
+                    content_id,
+                    shares,
+                },
+            }
+            .serialize(serializer),
+
+            distribute_project_content_revenue(project_id, content_id, asset) => CallObject {
+                module: "deip",
+                call: "distribute_project_content_revenue",
+                args: &DeipDistributeProjectContentRevenueCallArgs {
+                    project_id,
+                    content_id,
+                    asset,
+                },
+            }
+            .serialize(serializer),
+
+            create_licence_offer(external_id, project_id, content_id, price, duration, terms) => {
+                CallObject {
+                    module: "deip",
+                    call: "create_licence_offer",
+                    args: &DeipCreateLicenceOfferCallArgs {
+                        external_id,
+                        project_id,
+                        content_id,
+                        price,
+                        duration,
+                        terms,
+                    },
+                }
+                .serialize(serializer)
+            }
+
+            purchase_licence(offer_id) => CallObject {
+                module: "deip",
+                call: "purchase_licence",
+                args: &DeipPurchaseLicenceCallArgs { offer_id },
+            }
+            .serialize(serializer),
+
             create_project_nda(
                 external_id,
                 end_date,
@@ -241,6 +419,27 @@ impl runtime::WrappedCall<<RuntimeT as DeipProposal>::Call> {
             }
             .serialize(serializer),
 
+            deprecate_domain(domain_id) => CallObject {
+                module: "deip",
+                call: "deprecate_domain",
+                args: &DeipDeprecateDomainCallArgs { domain_id },
+            }
+            .serialize(serializer),
+
+            merge_domains(from, into, witness) => CallObject {
+                module: "deip",
+                call: "merge_domains",
+                args: &DeipMergeDomainsCallArgs { from, into, witness },
+            }
+            .serialize(serializer),
+
+            remove_domain(domain_id) => CallObject {
+                module: "deip",
+                call: "remove_domain",
+                args: &DeipRemoveDomainCallArgs { domain_id },
+            }
+            .serialize(serializer),
+
             __PhantomItem(..) => unreachable!(),
         }
     }
@@ -451,6 +650,59 @@ impl runtime::WrappedCall<<RuntimeT as DeipProposal>::Call> {
             }
             .serialize(serializer),
 
+            snapshot(id) => CallObject {
+                module: "deip_assets",
+                call: "snapshot",
+                args: &DeipAssetsSnapshotCallArgs { id },
+            }
+            .serialize(serializer),
+
+            distribute_dividend(id, payout_asset, amount) => CallObject {
+                module: "deip_assets",
+                call: "distribute_dividend",
+                args: &DeipAssetsDistributeDividendCallArgs {
+                    id,
+                    payout_asset,
+                    amount,
+                },
+            }
+            .serialize(serializer),
+
+            claim_dividend(dividend_id) => CallObject {
+                module: "deip_assets",
+                call: "claim_dividend",
+                args: &DeipAssetsDividendCallArgs { dividend_id },
+            }
+            .serialize(serializer),
+
+            reclaim_dividend(dividend_id) => CallObject {
+                module: "deip_assets",
+                call: "reclaim_dividend",
+                args: &DeipAssetsDividendCallArgs { dividend_id },
+            }
+            .serialize(serializer),
+
+            set_transfer_rules(id, rules) => CallObject {
+                module: "deip_assets",
+                call: "set_transfer_rules",
+                args: &DeipAssetsSetTransferRulesCallArgs { id, rules },
+            }
+            .serialize(serializer),
+
+            update_holder_allowlist(id, add, remove) => CallObject {
+                module: "deip_assets",
+                call: "update_holder_allowlist",
+                args: &DeipAssetsUpdateHolderAllowlistCallArgs { id, add, remove },
+            }
+            .serialize(serializer),
+
+            set_jurisdiction(who, jurisdiction) => CallObject {
+                module: "deip_assets",
+                call: "set_jurisdiction",
+                args: &DeipAssetsSetJurisdictionCallArgs { who, jurisdiction },
+            }
+            .serialize(serializer),
+
             __Ignore(..) => unreachable!(),
         }
     }
@@ -459,6 +711,42 @@ impl runtime::WrappedCall<<RuntimeT as DeipProposal>::Call> {
 #[derive(Serialize)]
 struct UnsupportedCallArgs {}
 
+#[derive(Serialize)]
+struct DeipAssetsSnapshotCallArgs<A> {
+    id: A,
+}
+
+#[derive(Serialize)]
+struct DeipAssetsDistributeDividendCallArgs<A, B, C> {
+    id: A,
+    payout_asset: B,
+    amount: C,
+}
+
+#[derive(Serialize)]
+struct DeipAssetsDividendCallArgs<A> {
+    dividend_id: A,
+}
+
+#[derive(Serialize)]
+struct DeipAssetsSetTransferRulesCallArgs<A, B> {
+    id: A,
+    rules: B,
+}
+
+#[derive(Serialize)]
+struct DeipAssetsUpdateHolderAllowlistCallArgs<A, B, C> {
+    id: A,
+    add: B,
+    remove: C,
+}
+
+#[derive(Serialize)]
+struct DeipAssetsSetJurisdictionCallArgs<A, B> {
+    who: A,
+    jurisdiction: B,
+}
+
 #[derive(Serialize)]
 struct DeipAssetsSetMetadataCallArgs<A, B, C, D> {
     id: A,
@@ -585,6 +873,23 @@ struct DeipAddDomainCallArgs<A> {
     domain: A,
 }
 
+#[derive(Serialize)]
+struct DeipDeprecateDomainCallArgs<A> {
+    domain_id: A,
+}
+
+#[derive(Serialize)]
+struct DeipMergeDomainsCallArgs<A, B> {
+    from: A,
+    into: A,
+    witness: B,
+}
+
+#[derive(Serialize)]
+struct DeipRemoveDomainCallArgs<A> {
+    domain_id: A,
+}
+
 #[derive(Serialize)]
 struct DeipCreateReviewCallArgs<A, B, C, D, E, F, G> {
     external_id: A,
@@ -644,12 +949,92 @@ struct DeipCreateProjectContentCallArgs<A, B, C, D, E, F, G, H> {
     references: H,
 }
 
+#[derive(Serialize)]
+struct DeipAmendProjectContentCallArgs<A, B, C, D, E> {
+    project_id: A,
+    content_id: B,
+    description: C,
+    content: D,
+    authors: E,
+}
+
+#[derive(Serialize)]
+struct DeipSetProjectContentAuthorSharesCallArgs<A, B, C> {
+    project_id: A,
+    content_id: B,
+    shares: C,
+}
+
+#[derive(Serialize)]
+struct DeipDistributeProjectContentRevenueCallArgs<A, B, C> {
+    project_id: A,
+    content_id: B,
+    asset: C,
+}
+
+#[derive(Serialize)]
+struct DeipCreateLicenceOfferCallArgs<A, B, C, D, E, F> {
+    external_id: A,
+    project_id: B,
+    content_id: C,
+    price: D,
+    duration: E,
+    terms: F,
+}
+
+#[derive(Serialize)]
+struct DeipPurchaseLicenceCallArgs<A> {
+    offer_id: A,
+}
+
 #[derive(Serialize)]
 struct DeipInvestCallArgs<A, B> {
     id: A,
     amount: B,
 }
 
+#[derive(Serialize)]
+struct DeipWithdrawInvestmentCallArgs<A, B> {
+    sale_id: A,
+    amount: B,
+}
+
+#[derive(Serialize)]
+struct DeipSubmitMilestoneCallArgs<A, B> {
+    sale_id: A,
+    content_id: B,
+}
+
+#[derive(Serialize)]
+struct DeipVoteMilestoneCallArgs<A, B> {
+    sale_id: A,
+    approve: B,
+}
+
+#[derive(Serialize)]
+struct DeipRefundMissedMilestoneCallArgs<A, B> {
+    sale_id: A,
+    investors: B,
+}
+
+#[derive(Serialize)]
+struct DeipClaimVestedCallArgs<A> {
+    sale_id: A,
+}
+
+#[derive(Serialize)]
+struct DeipCreateAllowlistCallArgs<A, B> {
+    id: A,
+    members: B,
+}
+
+#[derive(Serialize)]
+struct DeipUpdateAllowlistCallArgs<A, B, C> {
+    id: A,
+    to_add: B,
+    to_remove: C,
+}
+
 #[derive(Serialize)]
 struct DeipUpdateProjectCallArgs<A, B, C> {
     project_id: A,
@@ -657,6 +1042,24 @@ struct DeipUpdateProjectCallArgs<A, B, C> {
     is_private: C,
 }
 
+#[derive(Serialize)]
+struct DeipTransferProjectCallArgs<A, B, C> {
+    project_id: A,
+    new_team_id: B,
+    with_acceptance: C,
+}
+
+#[derive(Serialize)]
+struct DeipAcceptProjectTransferCallArgs<A> {
+    project_id: A,
+}
+
+#[derive(Serialize)]
+struct DeipRemoveProjectCallArgs<A, B> {
+    project_id: A,
+    witness: B,
+}
+
 #[derive(Serialize)]
 struct DeipFinishCrowdfundingCallArgs<A> {
     sale_id: A,
@@ -667,17 +1070,32 @@ struct DeipExpireCrowdfundingCallArgs<A> {
     sale_id: A,
 }
 
+#[derive(Serialize)]
+struct DeipCancelInvestmentOpportunityCallArgs<A> {
+    sale_id: A,
+}
+
+#[derive(Serialize)]
+struct DeipRetrySettlementCallArgs<A> {
+    sale_id: A,
+}
+
 #[derive(Serialize)]
 struct DeipActivateCrowdfundingCallArgs<A> {
     sale_id: A,
 }
 
 #[derive(Serialize)]
-struct DeipCreateInvestmentOpportunityCallArgs<A, B, C, D> {
+struct DeipCreateInvestmentOpportunityCallArgs<A, B, C, D, E, F, G, H, I> {
     external_id: A,
     creator: B,
     shares: C,
     funding_model: D,
+    vesting: E,
+    allowlist: F,
+    contribution_limits: G,
+    withdrawal: H,
+    exchange_rates: I,
 }
 
 #[derive(Serialize)]
@@ -102,19 +102,43 @@ impl<T: DeipProposal + Deip + DeipOrg + DeipAssets> Serialize for DomainEventDat
             ProjectCreated(e) => e.serialize(serializer),
             ProjectRemoved(e) => e.serialize(serializer),
             ProjectUpdated(e) => e.serialize(serializer),
+            ProjectTransferRequested(e) => e.serialize(serializer),
+            ProjectTransferred(e) => e.serialize(serializer),
             ProjectContentCreated(e) => e.serialize(serializer),
+            ProjectContentAmended(e) => e.serialize(serializer),
+            ProjectContentAuthorSharesSet(e) => e.serialize(serializer),
+            ProjectContentAuthorSharesReset(e) => e.serialize(serializer),
+            ProjectContentRevenueDistributed(e) => e.serialize(serializer),
+            LicenceOfferCreated(e) => e.serialize(serializer),
+            LicencePurchased(e) => e.serialize(serializer),
             NdaCreated(e) => e.serialize(serializer),
             NdaAccessRequestCreated(e) => e.serialize(serializer),
             NdaAccessRequestFulfilled(e) => e.serialize(serializer),
             NdaAccessRequestRejected(e) => e.serialize(serializer),
             DomainAdded(e) => e.serialize(serializer),
+            DomainDeprecated(e) => e.serialize(serializer),
+            DomainsMerged(e) => e.serialize(serializer),
+            DomainRemoved(e) => e.serialize(serializer),
             ReviewCreated(e) => e.serialize(serializer),
             ReviewUpvoted(e) => e.serialize(serializer),
             SimpleCrowdfundingCreated(e) => e.serialize(serializer),
             SimpleCrowdfundingActivated(e) => e.serialize(serializer),
             SimpleCrowdfundingFinished(e) => e.serialize(serializer),
             SimpleCrowdfundingExpired(e) => e.serialize(serializer),
+            SimpleCrowdfundingCanceled(e) => e.serialize(serializer),
+            SettlementFailed(e) => e.serialize(serializer),
             Invested(e) => e.serialize(serializer),
+            InvestmentWithdrawn(e) => e.serialize(serializer),
+            MilestoneSubmitted(e) => e.serialize(serializer),
+            MilestoneTrancheReleased(e) => e.serialize(serializer),
+            MilestoneTrancheRefunded(e) => e.serialize(serializer),
+            MilestoneDeadlineMissed(e) => e.serialize(serializer),
+            DutchAuctionCleared(e) => e.serialize(serializer),
+            DutchAuctionAllocated(e) => e.serialize(serializer),
+            VestedClaimed(e) => e.serialize(serializer),
+            AllowlistCreated(e) => e.serialize(serializer),
+            AllowlistUpdated(e) => e.serialize(serializer),
+            InvestmentAllowlistUpdated(e) => e.serialize(serializer),
             // =============== DeipOrg:
             OrgCreate(e) => e.serialize(serializer),
             OrgTransferOwnership(e) => e.serialize(serializer),
@@ -134,6 +158,13 @@ impl<T: DeipProposal + Deip + DeipOrg + DeipAssets> Serialize for DomainEventDat
             AssetClassForceCreated(e) => e.serialize(serializer),
             AssetMaxZombiesChanged(e) => e.serialize(serializer),
             AssetMetadataSet(e) => e.serialize(serializer),
+            AssetSnapshotTaken(e) => e.serialize(serializer),
+            AssetDividendDistributed(e) => e.serialize(serializer),
+            AssetDividendClaimed(e) => e.serialize(serializer),
+            AssetDividendReclaimed(e) => e.serialize(serializer),
+            AssetTransferRulesSet(e) => e.serialize(serializer),
+            AssetHolderAllowlistUpdated(e) => e.serialize(serializer),
+            AssetAccountJurisdictionSet(e) => e.serialize(serializer),
         }
     }
 }
@@ -152,19 +183,43 @@ pub enum DomainEventData<T: DeipProposal + Deip + DeipOrg + DeipAssets> {
     ProjectCreated(deip::ProjectCreatedEvent<T>),
     ProjectRemoved(deip::ProjectRemovedEvent<T>),
     ProjectUpdated(deip::ProjectUpdatedEvent<T>),
+    ProjectTransferRequested(deip::ProjectTransferRequestedEvent<T>),
+    ProjectTransferred(deip::ProjectTransferredEvent<T>),
     ProjectContentCreated(deip::ProjectContentCreatedEvent<T>),
+    ProjectContentAmended(deip::ProjectContentAmendedEvent<T>),
+    ProjectContentAuthorSharesSet(deip::ProjectContentAuthorSharesSetEvent<T>),
+    ProjectContentAuthorSharesReset(deip::ProjectContentAuthorSharesResetEvent<T>),
+    ProjectContentRevenueDistributed(deip::ProjectContentRevenueDistributedEvent<T>),
+    LicenceOfferCreated(deip::LicenceOfferCreatedEvent<T>),
+    LicencePurchased(deip::LicencePurchasedEvent<T>),
     NdaCreated(deip::NdaCreatedEvent<T>),
     NdaAccessRequestCreated(deip::NdaAccessRequestCreatedEvent<T>),
     NdaAccessRequestFulfilled(deip::NdaAccessRequestFulfilledEvent<T>),
     NdaAccessRequestRejected(deip::NdaAccessRequestRejectedEvent<T>),
     DomainAdded(deip::DomainAddedEvent<T>),
+    DomainDeprecated(deip::DomainDeprecatedEvent<T>),
+    DomainsMerged(deip::DomainsMergedEvent<T>),
+    DomainRemoved(deip::DomainRemovedEvent<T>),
     ReviewCreated(deip::ReviewCreatedEvent<T>),
     ReviewUpvoted(deip::ReviewUpvotedEvent<T>),
     SimpleCrowdfundingCreated(deip::SimpleCrowdfundingCreatedEvent<T>),
     SimpleCrowdfundingActivated(deip::SimpleCrowdfundingActivatedEvent<T>),
     SimpleCrowdfundingFinished(deip::SimpleCrowdfundingFinishedEvent<T>),
     SimpleCrowdfundingExpired(deip::SimpleCrowdfundingExpiredEvent<T>),
+    SimpleCrowdfundingCanceled(deip::SimpleCrowdfundingCanceledEvent<T>),
+    SettlementFailed(deip::SettlementFailedEvent<T>),
     Invested(deip::InvestedEvent<T>),
+    InvestmentWithdrawn(deip::InvestmentWithdrawnEvent<T>),
+    MilestoneSubmitted(deip::MilestoneSubmittedEvent<T>),
+    MilestoneTrancheReleased(deip::MilestoneTrancheReleasedEvent<T>),
+    MilestoneTrancheRefunded(deip::MilestoneTrancheRefundedEvent<T>),
+    MilestoneDeadlineMissed(deip::MilestoneDeadlineMissedEvent<T>),
+    DutchAuctionCleared(deip::DutchAuctionClearedEvent<T>),
+    DutchAuctionAllocated(deip::DutchAuctionAllocatedEvent<T>),
+    VestedClaimed(deip::VestedClaimedEvent<T>),
+    AllowlistCreated(deip::AllowlistCreatedEvent<T>),
+    AllowlistUpdated(deip::AllowlistUpdatedEvent<T>),
+    InvestmentAllowlistUpdated(deip::InvestmentAllowlistUpdatedEvent<T>),
     // DeipOrg:
     OrgCreate(deip_org::OrgCreateEvent<T>),
     OrgTransferOwnership(deip_org::OrgTransferOwnershipEvent<T>),
@@ -184,6 +239,13 @@ pub enum DomainEventData<T: DeipProposal + Deip + DeipOrg + DeipAssets> {
     AssetClassForceCreated(deip_assets::ForceCreatedEvent<T>),
     AssetMaxZombiesChanged(deip_assets::MaxZombiesChangedEvent<T>),
     AssetMetadataSet(deip_assets::MetadataSetEvent<T>),
+    AssetSnapshotTaken(deip_assets::SnapshotTakenEvent<T>),
+    AssetDividendDistributed(deip_assets::DividendDistributedEvent<T>),
+    AssetDividendClaimed(deip_assets::DividendClaimedEvent<T>),
+    AssetDividendReclaimed(deip_assets::DividendReclaimedEvent<T>),
+    AssetTransferRulesSet(deip_assets::TransferRulesSetEvent<T>),
+    AssetHolderAllowlistUpdated(deip_assets::HolderAllowlistUpdatedEvent<T>),
+    AssetAccountJurisdictionSet(deip_assets::JurisdictionSetEvent<T>),
 }
 
 pub fn known_domain_events<T: DeipProposal + Deip + DeipOrg + DeipAssets + Debug>(
@@ -264,6 +326,22 @@ pub fn known_domain_events<T: DeipProposal + Deip + DeipOrg + DeipAssets + Debug
             data: decode_event_data(raw).map(ProjectUpdated)?,
             meta,
         },
+        (                               
+            deip::ProjectTransferRequestedEvent::<T>::MODULE,
+            deip::ProjectTransferRequestedEvent::<T>::EVENT
+        ) => DomainEvent {
+            name: "project_transferRequested".to_string(),
+            data: decode_event_data(raw).map(ProjectTransferRequested)?,
+            meta,
+        },
+        (                               
+            deip::ProjectTransferredEvent::<T>::MODULE,
+            deip::ProjectTransferredEvent::<T>::EVENT
+        ) => DomainEvent {
+            name: "project_transferred".to_string(),
+            data: decode_event_data(raw).map(ProjectTransferred)?,
+            meta,
+        },
         (                               
             deip::ProjectContentCreatedEvent::<T>::MODULE,
             deip::ProjectContentCreatedEvent::<T>::EVENT
@@ -272,6 +350,54 @@ pub fn known_domain_events<T: DeipProposal + Deip + DeipOrg + DeipAssets + Debug
             data: decode_event_data(raw).map(ProjectContentCreated)?,
             meta,
         },
+        (                               
+            deip::ProjectContentAmendedEvent::<T>::MODULE,
+            deip::ProjectContentAmendedEvent::<T>::EVENT
+        ) => DomainEvent {
+            name: "project_contentAmended".to_string(),
+            data: decode_event_data(raw).map(ProjectContentAmended)?,
+            meta,
+        },
+        (                               
+            deip::ProjectContentAuthorSharesSetEvent::<T>::MODULE,
+            deip::ProjectContentAuthorSharesSetEvent::<T>::EVENT
+        ) => DomainEvent {
+            name: "project_contentAuthorSharesSet".to_string(),
+            data: decode_event_data(raw).map(ProjectContentAuthorSharesSet)?,
+            meta,
+        },
+        (                               
+            deip::ProjectContentAuthorSharesResetEvent::<T>::MODULE,
+            deip::ProjectContentAuthorSharesResetEvent::<T>::EVENT
+        ) => DomainEvent {
+            name: "project_contentAuthorSharesReset".to_string(),
+            data: decode_event_data(raw).map(ProjectContentAuthorSharesReset)?,
+            meta,
+        },
+        (                               
+            deip::ProjectContentRevenueDistributedEvent::<T>::MODULE,
+            deip::ProjectContentRevenueDistributedEvent::<T>::EVENT
+        ) => DomainEvent {
+            name: "project_contentRevenueDistributed".to_string(),
+            data: decode_event_data(raw).map(ProjectContentRevenueDistributed)?,
+            meta,
+        },
+        (                               
+            deip::LicenceOfferCreatedEvent::<T>::MODULE,
+            deip::LicenceOfferCreatedEvent::<T>::EVENT
+        ) => DomainEvent {
+            name: "project_licenceOfferCreated".to_string(),
+            data: decode_event_data(raw).map(LicenceOfferCreated)?,
+            meta,
+        },
+        (                               
+            deip::LicencePurchasedEvent::<T>::MODULE,
+            deip::LicencePurchasedEvent::<T>::EVENT
+        ) => DomainEvent {
+            name: "project_licencePurchased".to_string(),
+            data: decode_event_data(raw).map(LicencePurchased)?,
+            meta,
+        },
         (                               
             deip::NdaCreatedEvent::<T>::MODULE,
             deip::NdaCreatedEvent::<T>::EVENT
@@ -312,6 +438,30 @@ pub fn known_domain_events<T: DeipProposal + Deip + DeipOrg + DeipAssets + Debug
             data: decode_event_data(raw).map(DomainAdded)?,
             meta,
         },
+        (                               
+            deip::DomainDeprecatedEvent::<T>::MODULE,
+            deip::DomainDeprecatedEvent::<T>::EVENT
+        ) => DomainEvent {
+            name: "project_domainDeprecated".to_string(),
+            data: decode_event_data(raw).map(DomainDeprecated)?,
+            meta,
+        },
+        (                               
+            deip::DomainsMergedEvent::<T>::MODULE,
+            deip::DomainsMergedEvent::<T>::EVENT
+        ) => DomainEvent {
+            name: "project_domainsMerged".to_string(),
+            data: decode_event_data(raw).map(DomainsMerged)?,
+            meta,
+        },
+        (                               
+            deip::DomainRemovedEvent::<T>::MODULE,
+            deip::DomainRemovedEvent::<T>::EVENT
+        ) => DomainEvent {
+            name: "project_domainRemoved".to_string(),
+            data: decode_event_data(raw).map(DomainRemoved)?,
+            meta,
+        },
         (                               
             deip::ReviewCreatedEvent::<T>::MODULE,
             deip::ReviewCreatedEvent::<T>::EVENT
@@ -360,6 +510,22 @@ pub fn known_domain_events<T: DeipProposal + Deip + DeipOrg + DeipAssets + Debug
             data: decode_event_data(raw).map(SimpleCrowdfundingExpired)?,
             meta,
         },
+        (                               
+            deip::SimpleCrowdfundingCanceledEvent::<T>::MODULE,
+            deip::SimpleCrowdfundingCanceledEvent::<T>::EVENT
+        ) => DomainEvent {
+            name: "project_tokenSaleCanceled".to_string(),
+            data: decode_event_data(raw).map(SimpleCrowdfundingCanceled)?,
+            meta,
+        },
+        (                               
+            deip::SettlementFailedEvent::<T>::MODULE,
+            deip::SettlementFailedEvent::<T>::EVENT
+        ) => DomainEvent {
+            name: "project_tokenSaleSettlementFailed".to_string(),
+            data: decode_event_data(raw).map(SettlementFailed)?,
+            meta,
+        },
         (                               
             deip::InvestedEvent::<T>::MODULE,
             deip::InvestedEvent::<T>::EVENT
@@ -368,6 +534,94 @@ pub fn known_domain_events<T: DeipProposal + Deip + DeipOrg + DeipAssets + Debug
             data: decode_event_data(raw).map(Invested)?,
             meta,
         },
+        (                               
+            deip::InvestmentWithdrawnEvent::<T>::MODULE,
+            deip::InvestmentWithdrawnEvent::<T>::EVENT
+        ) => DomainEvent {
+            name: "project_tokenSaleWithdrawn".to_string(),
+            data: decode_event_data(raw).map(InvestmentWithdrawn)?,
+            meta,
+        },
+        (                               
+            deip::MilestoneSubmittedEvent::<T>::MODULE,
+            deip::MilestoneSubmittedEvent::<T>::EVENT
+        ) => DomainEvent {
+            name: "project_tokenSaleMilestoneSubmitted".to_string(),
+            data: decode_event_data(raw).map(MilestoneSubmitted)?,
+            meta,
+        },
+        (                               
+            deip::MilestoneTrancheReleasedEvent::<T>::MODULE,
+            deip::MilestoneTrancheReleasedEvent::<T>::EVENT
+        ) => DomainEvent {
+            name: "project_tokenSaleTrancheReleased".to_string(),
+            data: decode_event_data(raw).map(MilestoneTrancheReleased)?,
+            meta,
+        },
+        (                               
+            deip::MilestoneTrancheRefundedEvent::<T>::MODULE,
+            deip::MilestoneTrancheRefundedEvent::<T>::EVENT
+        ) => DomainEvent {
+            name: "project_tokenSaleTrancheRefunded".to_string(),
+            data: decode_event_data(raw).map(MilestoneTrancheRefunded)?,
+            meta,
+        },
+        (                               
+            deip::MilestoneDeadlineMissedEvent::<T>::MODULE,
+            deip::MilestoneDeadlineMissedEvent::<T>::EVENT
+        ) => DomainEvent {
+            name: "project_tokenSaleMilestoneDeadlineMissed".to_string(),
+            data: decode_event_data(raw).map(MilestoneDeadlineMissed)?,
+            meta,
+        },
+        (                               
+            deip::DutchAuctionClearedEvent::<T>::MODULE,
+            deip::DutchAuctionClearedEvent::<T>::EVENT
+        ) => DomainEvent {
+            name: "project_tokenSaleAuctionCleared".to_string(),
+            data: decode_event_data(raw).map(DutchAuctionCleared)?,
+            meta,
+        },
+        (                               
+            deip::DutchAuctionAllocatedEvent::<T>::MODULE,
+            deip::DutchAuctionAllocatedEvent::<T>::EVENT
+        ) => DomainEvent {
+            name: "project_tokenSaleAuctionAllocated".to_string(),
+            data: decode_event_data(raw).map(DutchAuctionAllocated)?,
+            meta,
+        },
+        (                               
+            deip::VestedClaimedEvent::<T>::MODULE,
+            deip::VestedClaimedEvent::<T>::EVENT
+        ) => DomainEvent {
+            name: "project_tokenSaleVestedClaimed".to_string(),
+            data: decode_event_data(raw).map(VestedClaimed)?,
+            meta,
+        },
+        (                               
+            deip::AllowlistCreatedEvent::<T>::MODULE,
+            deip::AllowlistCreatedEvent::<T>::EVENT
+        ) => DomainEvent {
+            name: "project_tokenSaleAllowlistCreated".to_string(),
+            data: decode_event_data(raw).map(AllowlistCreated)?,
+            meta,
+        },
+        (                               
+            deip::AllowlistUpdatedEvent::<T>::MODULE,
+            deip::AllowlistUpdatedEvent::<T>::EVENT
+        ) => DomainEvent {
+            name: "project_tokenSaleAllowlistUpdated".to_string(),
+            data: decode_event_data(raw).map(AllowlistUpdated)?,
+            meta,
+        },
+        (                               
+            deip::InvestmentAllowlistUpdatedEvent::<T>::MODULE,
+            deip::InvestmentAllowlistUpdatedEvent::<T>::EVENT
+        ) => DomainEvent {
+            name: "project_tokenSaleInvestmentAllowlistUpdated".to_string(),
+            data: decode_event_data(raw).map(InvestmentAllowlistUpdated)?,
+            meta,
+        },
         // =========== DeipOrg:
         (                               
             deip_org::OrgCreateEvent::<T>::MODULE,
@@ -506,6 +760,62 @@ pub fn known_domain_events<T: DeipProposal + Deip + DeipOrg + DeipAssets + Debug
             data: decode_event_data(raw).map(AssetMetadataSet)?,
             meta,
         },
+        (                               
+            deip_assets::SnapshotTakenEvent::<T>::MODULE,
+            deip_assets::SnapshotTakenEvent::<T>::EVENT
+        ) => DomainEvent {
+            name: "asset_snapshot_taken".to_string(),
+            data: decode_event_data(raw).map(AssetSnapshotTaken)?,
+            meta,
+        },
+        (                               
+            deip_assets::DividendDistributedEvent::<T>::MODULE,
+            deip_assets::DividendDistributedEvent::<T>::EVENT
+        ) => DomainEvent {
+            name: "asset_dividend_distributed".to_string(),
+            data: decode_event_data(raw).map(AssetDividendDistributed)?,
+            meta,
+        },
+        (                               
+            deip_assets::DividendClaimedEvent::<T>::MODULE,
+            deip_assets::DividendClaimedEvent::<T>::EVENT
+        ) => DomainEvent {
+            name: "asset_dividend_claimed".to_string(),
+            data: decode_event_data(raw).map(AssetDividendClaimed)?,
+            meta,
+        },
+        (                               
+            deip_assets::DividendReclaimedEvent::<T>::MODULE,
+            deip_assets::DividendReclaimedEvent::<T>::EVENT
+        ) => DomainEvent {
+            name: "asset_dividend_reclaimed".to_string(),
+            data: decode_event_data(raw).map(AssetDividendReclaimed)?,
+            meta,
+        },
+        (                               
+            deip_assets::TransferRulesSetEvent::<T>::MODULE,
+            deip_assets::TransferRulesSetEvent::<T>::EVENT
+        ) => DomainEvent {
+            name: "asset_transfer_rules_set".to_string(),
+            data: decode_event_data(raw).map(AssetTransferRulesSet)?,
+            meta,
+        },
+        (                               
+            deip_assets::HolderAllowlistUpdatedEvent::<T>::MODULE,
+            deip_assets::HolderAllowlistUpdatedEvent::<T>::EVENT
+        ) => DomainEvent {
+            name: "asset_holder_allowlist_updated".to_string(),
+            data: decode_event_data(raw).map(AssetHolderAllowlistUpdated)?,
+            meta,
+        },
+        (                               
+            deip_assets::JurisdictionSetEvent::<T>::MODULE,
+            deip_assets::JurisdictionSetEvent::<T>::EVENT
+        ) => DomainEvent {
+            name: "asset_account_jurisdiction_set".to_string(),
+            data: decode_event_data(raw).map(AssetAccountJurisdictionSet)?,
+            meta,
+        },
         _ => return Ok(None),
     };
     Ok(Some(event.into()))
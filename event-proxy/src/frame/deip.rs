@@ -5,7 +5,7 @@ use substrate_subxt::{module, Event};
 use sp_std::prelude::*;
 use codec::{Decode};
 use frame_support::{Parameter};
-use sp_runtime::traits::Member;
+use sp_runtime::{traits::Member, DispatchError};
 
 use serde::{Serialize, ser::{Serializer, SerializeStruct}};
 
@@ -19,8 +19,12 @@ pub trait Deip: System {
     type NdaId: Parameter + Member + Serialize;
     type NdaAccessRequestId: Parameter + Member + Serialize;
     type ProjectContentId: Parameter + Member + Serialize;
+    type ProjectContentVersion: Parameter + Member + Serialize;
     type InvestmentId: Parameter + Member + Serialize;
+    type AllowlistId: Parameter + Member + Serialize;
     type FundingModel: Parameter + Member + Serialize;
+    type DeipAsset: Parameter + Member + Serialize;
+    type LicenceOfferId: Parameter + Member + Serialize;
 }
 
 const ACCOUNT_ID_KEY: &str = "account_id";
@@ -64,6 +68,34 @@ impl<T: Deip> Serialize for ProjectUpdatedEvent<T> {
     }
 }
 
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct ProjectTransferRequestedEvent<T: Deip>(T::AccountId, T::ProjectId, T::AccountId);
+impl<T: Deip> Serialize for ProjectTransferRequestedEvent<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+        where S: Serializer
+    {
+        let mut s = serializer.serialize_struct("ProjectTransferRequestedEvent", 3)?;
+        s.serialize_field(ACCOUNT_ID_KEY, &self.0)?;
+        s.serialize_field("project_id", &self.1)?;
+        s.serialize_field("new_team_id", &self.2)?;
+        s.end()
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct ProjectTransferredEvent<T: Deip>(T::AccountId, T::ProjectId, T::AccountId);
+impl<T: Deip> Serialize for ProjectTransferredEvent<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+        where S: Serializer
+    {
+        let mut s = serializer.serialize_struct("ProjectTransferredEvent", 3)?;
+        s.serialize_field(ACCOUNT_ID_KEY, &self.0)?;
+        s.serialize_field("project_id", &self.1)?;
+        s.serialize_field("new_team_id", &self.2)?;
+        s.end()
+    }
+}
+
 #[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
 pub struct ProjectContentCreatedEvent<T: Deip>(T::AccountId, T::ProjectContentId);
 impl<T: Deip> Serialize for ProjectContentCreatedEvent<T> {
@@ -77,6 +109,86 @@ impl<T: Deip> Serialize for ProjectContentCreatedEvent<T> {
     }
 }
 
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct ProjectContentAmendedEvent<T: Deip>(T::AccountId, T::ProjectContentId, T::ProjectContentVersion);
+impl<T: Deip> Serialize for ProjectContentAmendedEvent<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+        where S: Serializer
+    {
+        let mut s = serializer.serialize_struct("ProjectContentAmendedEvent", 3)?;
+        s.serialize_field(ACCOUNT_ID_KEY, &self.0)?;
+        s.serialize_field("content_id", &self.1)?;
+        s.serialize_field("version", &self.2)?;
+        s.end()
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct ProjectContentAuthorSharesSetEvent<T: Deip>(T::AccountId, T::ProjectContentId);
+impl<T: Deip> Serialize for ProjectContentAuthorSharesSetEvent<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+        where S: Serializer
+    {
+        let mut s = serializer.serialize_struct("ProjectContentAuthorSharesSetEvent", 2)?;
+        s.serialize_field(ACCOUNT_ID_KEY, &self.0)?;
+        s.serialize_field("content_id", &self.1)?;
+        s.end()
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct ProjectContentAuthorSharesResetEvent<T: Deip>(T::AccountId, T::ProjectContentId);
+impl<T: Deip> Serialize for ProjectContentAuthorSharesResetEvent<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+        where S: Serializer
+    {
+        let mut s = serializer.serialize_struct("ProjectContentAuthorSharesResetEvent", 2)?;
+        s.serialize_field(ACCOUNT_ID_KEY, &self.0)?;
+        s.serialize_field("content_id", &self.1)?;
+        s.end()
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct ProjectContentRevenueDistributedEvent<T: Deip>(T::AccountId, T::ProjectContentId, T::DeipAsset);
+impl<T: Deip> Serialize for ProjectContentRevenueDistributedEvent<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+        where S: Serializer
+    {
+        let mut s = serializer.serialize_struct("ProjectContentRevenueDistributedEvent", 3)?;
+        s.serialize_field(ACCOUNT_ID_KEY, &self.0)?;
+        s.serialize_field("content_id", &self.1)?;
+        s.serialize_field("asset", &self.2)?;
+        s.end()
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct LicenceOfferCreatedEvent<T: Deip>(T::AccountId, T::LicenceOfferId);
+impl<T: Deip> Serialize for LicenceOfferCreatedEvent<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+        where S: Serializer
+    {
+        let mut s = serializer.serialize_struct("LicenceOfferCreatedEvent", 2)?;
+        s.serialize_field(ACCOUNT_ID_KEY, &self.0)?;
+        s.serialize_field("offer_id", &self.1)?;
+        s.end()
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct LicencePurchasedEvent<T: Deip>(T::AccountId, T::LicenceOfferId);
+impl<T: Deip> Serialize for LicencePurchasedEvent<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+        where S: Serializer
+    {
+        let mut s = serializer.serialize_struct("LicencePurchasedEvent", 2)?;
+        s.serialize_field(ACCOUNT_ID_KEY, &self.0)?;
+        s.serialize_field("offer_id", &self.1)?;
+        s.end()
+    }
+}
+
 #[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
 pub struct NdaCreatedEvent<T: Deip>(T::AccountId, T::NdaId);
 impl<T: Deip> Serialize for NdaCreatedEvent<T> {
@@ -130,7 +242,7 @@ impl<T: Deip> Serialize for NdaAccessRequestRejectedEvent<T> {
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
-pub struct DomainAddedEvent<T: Deip>(T::AccountId, T::DomainId);
+pub struct DomainAddedEvent<T: Deip>(Option<T::AccountId>, T::DomainId);
 impl<T: Deip> Serialize for DomainAddedEvent<T> {
     fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
         where S: Serializer
@@ -142,6 +254,43 @@ impl<T: Deip> Serialize for DomainAddedEvent<T> {
     }
 }
 
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct DomainDeprecatedEvent<T: Deip>(T::DomainId);
+impl<T: Deip> Serialize for DomainDeprecatedEvent<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+        where S: Serializer
+    {
+        let mut s = serializer.serialize_struct("DomainDeprecatedEvent", 1)?;
+        s.serialize_field("domain_id", &self.0)?;
+        s.end()
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct DomainsMergedEvent<T: Deip>(T::DomainId, T::DomainId);
+impl<T: Deip> Serialize for DomainsMergedEvent<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+        where S: Serializer
+    {
+        let mut s = serializer.serialize_struct("DomainsMergedEvent", 2)?;
+        s.serialize_field("from", &self.0)?;
+        s.serialize_field("into", &self.1)?;
+        s.end()
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct DomainRemovedEvent<T: Deip>(T::DomainId);
+impl<T: Deip> Serialize for DomainRemovedEvent<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+        where S: Serializer
+    {
+        let mut s = serializer.serialize_struct("DomainRemovedEvent", 1)?;
+        s.serialize_field("domain_id", &self.0)?;
+        s.end()
+    }
+}
+
 #[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
 pub struct ReviewCreatedEvent<T: Deip>(T::AccountId, T::Review);
 impl<T: Deip> Serialize for ReviewCreatedEvent<T> {
@@ -219,6 +368,31 @@ impl<T: Deip> Serialize for SimpleCrowdfundingExpiredEvent<T> {
     }
 }
 
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct SimpleCrowdfundingCanceledEvent<T: Deip>(T::InvestmentId);
+impl<T: Deip> Serialize for SimpleCrowdfundingCanceledEvent<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+        where S: Serializer
+    {
+        let mut s = serializer.serialize_struct("SimpleCrowdfundingCanceledEvent", 1)?;
+        s.serialize_field(INVESTMENT_ID_KEY, &self.0)?;
+        s.end()
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct SettlementFailedEvent<T: Deip>(T::InvestmentId, DispatchError);
+impl<T: Deip> Serialize for SettlementFailedEvent<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+        where S: Serializer
+    {
+        let mut s = serializer.serialize_struct("SettlementFailedEvent", 2)?;
+        s.serialize_field(INVESTMENT_ID_KEY, &self.0)?;
+        s.serialize_field("error", &self.1)?;
+        s.end()
+    }
+}
+
 #[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
 pub struct InvestedEvent<T: Deip>(T::InvestmentId, T::AccountId);
 impl<T: Deip> Serialize for InvestedEvent<T> {
@@ -231,3 +405,148 @@ impl<T: Deip> Serialize for InvestedEvent<T> {
         s.end()
     }
 }
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct InvestmentWithdrawnEvent<T: Deip>(T::InvestmentId, T::AccountId, T::DeipAsset);
+impl<T: Deip> Serialize for InvestmentWithdrawnEvent<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+        where S: Serializer
+    {
+        let mut s = serializer.serialize_struct("InvestmentWithdrawnEvent", 3)?;
+        s.serialize_field(INVESTMENT_ID_KEY, &self.0)?;
+        s.serialize_field(ACCOUNT_ID_KEY, &self.1)?;
+        s.serialize_field("withdrawn", &self.2)?;
+        s.end()
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct MilestoneSubmittedEvent<T: Deip>(T::InvestmentId, T::ProjectContentId);
+impl<T: Deip> Serialize for MilestoneSubmittedEvent<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+        where S: Serializer
+    {
+        let mut s = serializer.serialize_struct("MilestoneSubmittedEvent", 2)?;
+        s.serialize_field(INVESTMENT_ID_KEY, &self.0)?;
+        s.serialize_field("project_content_id", &self.1)?;
+        s.end()
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct MilestoneTrancheReleasedEvent<T: Deip>(T::InvestmentId, T::DeipAsset);
+impl<T: Deip> Serialize for MilestoneTrancheReleasedEvent<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+        where S: Serializer
+    {
+        let mut s = serializer.serialize_struct("MilestoneTrancheReleasedEvent", 2)?;
+        s.serialize_field(INVESTMENT_ID_KEY, &self.0)?;
+        s.serialize_field("released", &self.1)?;
+        s.end()
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct MilestoneTrancheRefundedEvent<T: Deip>(T::InvestmentId, T::AccountId, T::DeipAsset);
+impl<T: Deip> Serialize for MilestoneTrancheRefundedEvent<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+        where S: Serializer
+    {
+        let mut s = serializer.serialize_struct("MilestoneTrancheRefundedEvent", 3)?;
+        s.serialize_field(INVESTMENT_ID_KEY, &self.0)?;
+        s.serialize_field(ACCOUNT_ID_KEY, &self.1)?;
+        s.serialize_field("refunded", &self.2)?;
+        s.end()
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct MilestoneDeadlineMissedEvent<T: Deip>(T::InvestmentId);
+impl<T: Deip> Serialize for MilestoneDeadlineMissedEvent<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+        where S: Serializer
+    {
+        let mut s = serializer.serialize_struct("MilestoneDeadlineMissedEvent", 1)?;
+        s.serialize_field(INVESTMENT_ID_KEY, &self.0)?;
+        s.end()
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct DutchAuctionClearedEvent<T: Deip>(T::InvestmentId, T::DeipAsset);
+impl<T: Deip> Serialize for DutchAuctionClearedEvent<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+        where S: Serializer
+    {
+        let mut s = serializer.serialize_struct("DutchAuctionClearedEvent", 2)?;
+        s.serialize_field(INVESTMENT_ID_KEY, &self.0)?;
+        s.serialize_field("clearing_price", &self.1)?;
+        s.end()
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct DutchAuctionAllocatedEvent<T: Deip>(T::InvestmentId, T::AccountId, T::DeipAsset, T::DeipAsset);
+impl<T: Deip> Serialize for DutchAuctionAllocatedEvent<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+        where S: Serializer
+    {
+        let mut s = serializer.serialize_struct("DutchAuctionAllocatedEvent", 4)?;
+        s.serialize_field(INVESTMENT_ID_KEY, &self.0)?;
+        s.serialize_field(ACCOUNT_ID_KEY, &self.1)?;
+        s.serialize_field("paid", &self.2)?;
+        s.serialize_field("refunded", &self.3)?;
+        s.end()
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct VestedClaimedEvent<T: Deip>(T::InvestmentId, T::AccountId, T::DeipAsset);
+impl<T: Deip> Serialize for VestedClaimedEvent<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+        where S: Serializer
+    {
+        let mut s = serializer.serialize_struct("VestedClaimedEvent", 3)?;
+        s.serialize_field(INVESTMENT_ID_KEY, &self.0)?;
+        s.serialize_field(ACCOUNT_ID_KEY, &self.1)?;
+        s.serialize_field("unlocked", &self.2)?;
+        s.end()
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct AllowlistCreatedEvent<T: Deip>(T::AllowlistId, T::AccountId);
+impl<T: Deip> Serialize for AllowlistCreatedEvent<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+        where S: Serializer
+    {
+        let mut s = serializer.serialize_struct("AllowlistCreatedEvent", 2)?;
+        s.serialize_field("allowlist_id", &self.0)?;
+        s.serialize_field(ACCOUNT_ID_KEY, &self.1)?;
+        s.end()
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct AllowlistUpdatedEvent<T: Deip>(T::AllowlistId);
+impl<T: Deip> Serialize for AllowlistUpdatedEvent<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+        where S: Serializer
+    {
+        let mut s = serializer.serialize_struct("AllowlistUpdatedEvent", 1)?;
+        s.serialize_field("allowlist_id", &self.0)?;
+        s.end()
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct InvestmentAllowlistUpdatedEvent<T: Deip>(T::InvestmentId);
+impl<T: Deip> Serialize for InvestmentAllowlistUpdatedEvent<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+        where S: Serializer
+    {
+        let mut s = serializer.serialize_struct("InvestmentAllowlistUpdatedEvent", 1)?;
+        s.serialize_field(INVESTMENT_ID_KEY, &self.0)?;
+        s.end()
+    }
+}
@@ -21,6 +21,7 @@ const FROM: &str = "from";
 const TO: &str = "to";
 const AMOUNT: &str = "amount";
 const WHO: &str = "who";
+const DIVIDEND_ID: &str = "dividend_id";
 
 #[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
 pub struct CreatedEvent<T: DeipAssets>(T::AssetId, T::AccountId, T::AccountId);
@@ -224,3 +225,95 @@ impl<T: DeipAssets> Serialize for MetadataSetEvent<T> {
         s.end()
     }
 }
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct SnapshotTakenEvent<T: DeipAssets>(T::AssetId, u32);
+impl<T: DeipAssets> Serialize for SnapshotTakenEvent<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+        where S: Serializer
+    {
+        let mut s = serializer.serialize_map(Some(2))?;
+        s.serialize_entry(ASSET_ID, &self.0)?;
+        s.serialize_entry("snapshot_id", &self.1)?;
+        s.end()
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct DividendDistributedEvent<T: DeipAssets>(u32, T::AssetId, T::AssetId, T::Balance);
+impl<T: DeipAssets> Serialize for DividendDistributedEvent<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+        where S: Serializer
+    {
+        let mut s = serializer.serialize_map(Some(4))?;
+        s.serialize_entry(DIVIDEND_ID, &self.0)?;
+        s.serialize_entry(ASSET_ID, &self.1)?;
+        s.serialize_entry("payout_asset_id", &self.2)?;
+        s.serialize_entry(AMOUNT, &self.3)?;
+        s.end()
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct DividendClaimedEvent<T: DeipAssets>(u32, T::AccountId, T::Balance);
+impl<T: DeipAssets> Serialize for DividendClaimedEvent<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+        where S: Serializer
+    {
+        let mut s = serializer.serialize_map(Some(3))?;
+        s.serialize_entry(DIVIDEND_ID, &self.0)?;
+        s.serialize_entry(WHO, &self.1)?;
+        s.serialize_entry(AMOUNT, &self.2)?;
+        s.end()
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct DividendReclaimedEvent<T: DeipAssets>(u32, T::Balance);
+impl<T: DeipAssets> Serialize for DividendReclaimedEvent<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+        where S: Serializer
+    {
+        let mut s = serializer.serialize_map(Some(2))?;
+        s.serialize_entry(DIVIDEND_ID, &self.0)?;
+        s.serialize_entry(AMOUNT, &self.1)?;
+        s.end()
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct TransferRulesSetEvent<T: DeipAssets>(T::AssetId);
+impl<T: DeipAssets> Serialize for TransferRulesSetEvent<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+        where S: Serializer
+    {
+        let mut s = serializer.serialize_map(Some(1))?;
+        s.serialize_entry(ASSET_ID, &self.0)?;
+        s.end()
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct HolderAllowlistUpdatedEvent<T: DeipAssets>(T::AssetId);
+impl<T: DeipAssets> Serialize for HolderAllowlistUpdatedEvent<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+        where S: Serializer
+    {
+        let mut s = serializer.serialize_map(Some(1))?;
+        s.serialize_entry(ASSET_ID, &self.0)?;
+        s.end()
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct JurisdictionSetEvent<T: DeipAssets>(T::AccountId, Option<[u8; 2]>);
+impl<T: DeipAssets> Serialize for JurisdictionSetEvent<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+        where S: Serializer
+    {
+        let mut s = serializer.serialize_map(Some(2))?;
+        s.serialize_entry(WHO, &self.0)?;
+        s.serialize_entry("jurisdiction", &self.1)?;
+        s.end()
+    }
+}
@@ -54,8 +54,12 @@ impl frame::deip::Deip for RuntimeT {
     type NdaId = pallet_deip::NdaId;
     type NdaAccessRequestId = pallet_deip::NdaAccessRequestId;
     type ProjectContentId = pallet_deip::ProjectContentId;
+    type ProjectContentVersion = pallet_deip::ProjectContentVersion;
     type InvestmentId = pallet_deip::InvestmentId;
+    type AllowlistId = pallet_deip::AllowlistId;
     type FundingModel = pallet_deip::FundingModelOf<RealRuntime>;
+    type DeipAsset = pallet_deip::DeipAssetOf<RealRuntime>;
+    type LicenceOfferId = pallet_deip::LicenceOfferId;
 }
 
 impl frame::deip_org::DeipOrg for RuntimeT {
@@ -23,8 +23,11 @@ pub fn register_types<T: Runtime>(c: ClientBuilder<T>) -> ClientBuilder<T>
         .register_type_size::<<T as Deip>::NdaId>("NdaId")
         .register_type_size::<<T as Deip>::NdaAccessRequestId>("NdaAccessRequestId")
         .register_type_size::<<T as Deip>::ProjectContentId>("ProjectContentId")
+        .register_type_size::<<T as Deip>::ProjectContentVersion>("ProjectContentVersion")
         .register_type_size::<<T as Deip>::FundingModel>("FundingModel")
         .register_type_size::<<T as Deip>::InvestmentId>("InvestmentId")
+        .register_type_size::<<T as Deip>::DeipAsset>("DeipAsset")
+        .register_type_size::<<T as Deip>::LicenceOfferId>("LicenceOfferId")
         // DeipOrg:
         .register_type_size::<<T as DeipOrg>::Org>("OrgOf<T>")
 }
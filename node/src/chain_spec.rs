@@ -170,7 +170,7 @@ fn testnet_genesis(
             balances: endowed_accounts.iter().cloned().map(|k|(k, 1 << 60)).collect(),
         }),
         pallet_deip: Some(DeipConfig {
-            domains: domains.iter().cloned().map(|k|(k, Domain { external_id: k })).collect(),
+            domains: domains.iter().cloned().map(|k|(k, Domain { external_id: k, ..Default::default() })).collect(),
             domain_count: domains.len() as u32,
         }),
         pallet_deip_proposal: Some(DeipProposalConfig {
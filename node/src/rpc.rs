@@ -7,7 +7,8 @@
 
 use std::sync::Arc;
 
-use node_template_runtime::{opaque::Block, AccountId, Balance, Index};
+use node_template_runtime::{opaque::Block, AccountId, AssetId, Balance, DeipAssetBalance, Index};
+use pallet_deip::{InvestmentId, ProjectId};
 use sp_api::ProvideRuntimeApi;
 use sp_blockchain::{Error as BlockChainError, HeaderMetadata, HeaderBackend};
 use sp_block_builder::BlockBuilder;
@@ -34,8 +35,9 @@ pub fn create_full<C, P>(
     C: Send + Sync + 'static,
     C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Index>,
     C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
-    C::Api: deip_runtime_api::DeipApi<Block, AccountId>,
+    C::Api: deip_runtime_api::DeipApi<Block, AccountId, AssetId, DeipAssetBalance>,
     C::Api: pallet_deip_org_rpc::DeipOrgRuntimeApi<Block, AccountId>,
+    C::Api: pallet_deip_assets_rpc::DeipAssetsRuntimeApi<Block, AssetId, AccountId, DeipAssetBalance, ProjectId, InvestmentId>,
     C::Api: BlockBuilder<Block>,
     P: TransactionPool + 'static,
 {
@@ -63,7 +65,11 @@ pub fn create_full<C, P>(
     ));
     
     io.extend_with(pallet_deip_org_rpc::DeipOrgRpcApi::to_delegate(
-        pallet_deip_org_rpc::DeipOrgRpcApiObj::new(client),
+        pallet_deip_org_rpc::DeipOrgRpcApiObj::new(client.clone()),
+    ));
+
+    io.extend_with(pallet_deip_assets_rpc::DeipAssetsRpcApi::to_delegate(
+        pallet_deip_assets_rpc::DeipAssetsRpcApiObj::new(client),
     ));
 
     // Extend this RPC with a custom API by using the following syntax.
@@ -11,27 +11,79 @@ use codec::{Codec};
 
 
 #[rpc]
-pub trait DeipStorageApi<BlockHash, AccountId> {
+pub trait DeipStorageApi<BlockHash, AccountId, AssetId, AssetBalance> {
     #[rpc(name = "deipStorage_getProjects")]
     fn get_projects(&self, at: Option<BlockHash>) -> Result<Vec<Project<H256, AccountId>>>;
     #[rpc(name = "deipStorage_getProject")]
     fn get_project(&self, at: Option<BlockHash>, project_id: ProjectId) -> Result<Project<H256, AccountId>>;
+    #[rpc(name = "deipStorage_getProjectsPage")]
+    fn get_projects_page(&self, at: Option<BlockHash>, filter: ProjectFilter<AccountId>, start_id: Option<ProjectId>, limit: u32) -> Result<Vec<Project<H256, AccountId>>>;
+    #[rpc(name = "deipStorage_getProjectRemovalWitness")]
+    fn get_project_removal_witness(&self, at: Option<BlockHash>, project_id: ProjectId) -> Result<ProjectRemovalWitness>;
     #[rpc(name = "deipStorage_getProjectContentList")]
     fn get_project_content_list(&self, at: Option<BlockHash>, content_ids: Option<Vec<ProjectContentId>>) -> Result<Vec<ProjectContent<H256, AccountId>>>;
+    #[rpc(name = "deipStorage_getProjectContentPage")]
+    fn get_project_content_page(&self, at: Option<BlockHash>, filter: ProjectContentFilter, start_id: Option<ProjectContentId>, limit: u32) -> Result<Vec<ProjectContent<H256, AccountId>>>;
     #[rpc(name = "deipStorage_getProjectContent")]
     fn get_project_content(&self, at: Option<BlockHash>, project_id: ProjectId, project_content_id: ProjectContentId) -> Result<ProjectContent<H256, AccountId>>;
+    #[rpc(name = "deipStorage_getProjectContentVersion")]
+    fn get_project_content_version(&self, at: Option<BlockHash>, project_id: ProjectId, project_content_id: ProjectContentId, version: ProjectContentVersion) -> Result<Option<ProjectContent<H256, AccountId>>>;
+    #[rpc(name = "deipStorage_getReferencesOf")]
+    fn get_references_of(&self, at: Option<BlockHash>, project_content_id: ProjectContentId, depth: u32) -> Result<Vec<ProjectContentId>>;
+    #[rpc(name = "deipStorage_getCitedBy")]
+    fn get_cited_by(&self, at: Option<BlockHash>, project_content_id: ProjectContentId, depth: u32) -> Result<Vec<ProjectContentId>>;
+    #[rpc(name = "deipStorage_hasValidLicence")]
+    fn has_valid_licence(&self, at: Option<BlockHash>, account: AccountId, project_content_id: ProjectContentId, moment: u64) -> Result<bool>;
     #[rpc(name = "deipStorage_getDomains")]
     fn get_domains(&self, at: Option<BlockHash>) -> Result<Vec<Domain>>;
     #[rpc(name = "deipStorage_getDomain")]
     fn get_domain(&self, at: Option<BlockHash>, domain_id: DomainId) -> Result<Domain>;
+    #[rpc(name = "deipStorage_getDomainSubtree")]
+    fn get_domain_subtree(&self, at: Option<BlockHash>, domain_id: DomainId) -> Result<Vec<Domain>>;
+    #[rpc(name = "deipStorage_getDomainMergeWitness")]
+    fn get_domain_merge_witness(&self, at: Option<BlockHash>, domain_id: DomainId) -> Result<DomainMergeWitness>;
     #[rpc(name = "deipStorage_getNdaList")]
     fn get_nda_list(&self, at: Option<BlockHash>) -> Result<Vec<Nda<H256, AccountId, u64>>>;
+    #[rpc(name = "deipStorage_getNdaPage")]
+    fn get_nda_page(&self, at: Option<BlockHash>, start_id: Option<NdaId>, limit: u32) -> Result<Vec<Nda<H256, AccountId, u64>>>;
     #[rpc(name = "deipStorage_getNda")]
     fn get_nda(&self, at: Option<BlockHash>, nda_id: NdaId) -> Result<Nda<H256, AccountId, u64>>;
     #[rpc(name = "deipStorage_getReviews")]
     fn get_reviews(&self, at: Option<BlockHash>) -> Result<Vec<Review<H256, AccountId>>>;
+    #[rpc(name = "deipStorage_getReviewsPage")]
+    fn get_reviews_page(&self, at: Option<BlockHash>, filter: ReviewFilter<AccountId>, start_id: Option<ReviewId>, limit: u32) -> Result<Vec<Review<H256, AccountId>>>;
     #[rpc(name = "deipStorage_getReview")]
     fn get_review(&self, at: Option<BlockHash>, review_id: ReviewId) -> Result<Review<H256, AccountId>>;
+    #[rpc(name = "deipStorage_getInvestmentOpportunity")]
+    fn get_investment_opportunity(&self, at: Option<BlockHash>, id: InvestmentId) -> Result<Option<SimpleCrowdfunding<u64, AssetId, AssetBalance>>>;
+    #[rpc(name = "deipStorage_getInvestmentOpportunitiesPage")]
+    fn get_investment_opportunities_page(&self, at: Option<BlockHash>, filter: InvestmentOpportunityFilter<AssetId>, start_id: Option<InvestmentId>, limit: u32) -> Result<Vec<SimpleCrowdfunding<u64, AssetId, AssetBalance>>>;
+    #[rpc(name = "deipStorage_getInvestments")]
+    fn get_investments(&self, at: Option<BlockHash>, sale_id: InvestmentId) -> Result<Vec<Investment<AccountId, AssetId, AssetBalance, u64>>>;
+    #[rpc(name = "deipStorage_getInvestedOpportunities")]
+    fn get_invested_opportunities(&self, at: Option<BlockHash>, investor: AccountId) -> Result<Vec<SimpleCrowdfunding<u64, AssetId, AssetBalance>>>;
+    #[rpc(name = "deipStorage_getMilestoneTranches")]
+    fn get_milestone_tranches(&self, at: Option<BlockHash>, sale_id: InvestmentId) -> Result<Option<MilestoneTranches<AccountId, AssetBalance, u64>>>;
+    #[rpc(name = "deipStorage_getFixedPriceMaxPerAccount")]
+    fn get_fixed_price_max_per_account(&self, at: Option<BlockHash>, sale_id: InvestmentId) -> Result<Option<AssetBalance>>;
+    #[rpc(name = "deipStorage_getDutchAuction")]
+    fn get_dutch_auction(&self, at: Option<BlockHash>, sale_id: InvestmentId) -> Result<Option<DutchAuctionTerms<AssetBalance>>>;
+    #[rpc(name = "deipStorage_getDutchAuctionPrice")]
+    fn get_dutch_auction_price(&self, at: Option<BlockHash>, sale_id: InvestmentId) -> Result<Option<AssetBalance>>;
+    #[rpc(name = "deipStorage_getDutchAuctionBids")]
+    fn get_dutch_auction_bids(&self, at: Option<BlockHash>, sale_id: InvestmentId) -> Result<Vec<DutchAuctionBid<AccountId, AssetBalance>>>;
+    #[rpc(name = "deipStorage_getVestingSchedule")]
+    fn get_vesting_schedule(&self, at: Option<BlockHash>, sale_id: InvestmentId) -> Result<Option<VestingSchedule<u64>>>;
+    #[rpc(name = "deipStorage_getVestingBalances")]
+    fn get_vesting_balances(&self, at: Option<BlockHash>, account: AccountId) -> Result<Vec<VestingBalance<AssetId, AssetBalance>>>;
+    #[rpc(name = "deipStorage_isInvestorAllowed")]
+    fn is_investor_allowed(&self, at: Option<BlockHash>, sale_id: InvestmentId, account: AccountId) -> Result<bool>;
+    #[rpc(name = "deipStorage_getContributionLimits")]
+    fn get_contribution_limits(&self, at: Option<BlockHash>, sale_id: InvestmentId) -> Result<Option<ContributionLimits<AssetBalance>>>;
+    #[rpc(name = "deipStorage_getWithdrawalTerms")]
+    fn get_withdrawal_terms(&self, at: Option<BlockHash>, sale_id: InvestmentId) -> Result<Option<WithdrawalTerms<u64>>>;
+    #[rpc(name = "deipStorage_getExchangeRates")]
+    fn get_exchange_rates(&self, at: Option<BlockHash>, sale_id: InvestmentId) -> Result<Vec<ExchangeRate<AssetId, AssetBalance>>>;
 }
 
 /// A struct that implements the `DeipStorage`.
@@ -69,14 +121,16 @@ impl<C, M> DeipStorage<C, M> {
 //     }
 // }
 
-impl<C, Block, AccountId> DeipStorageApi<<Block as BlockT>::Hash, AccountId> for DeipStorage<C, Block>
+impl<C, Block, AccountId, AssetId, AssetBalance> DeipStorageApi<<Block as BlockT>::Hash, AccountId, AssetId, AssetBalance> for DeipStorage<C, Block>
 where
     Block: BlockT,
     C: Send + Sync + 'static,
     C: ProvideRuntimeApi<Block>,
     C: HeaderBackend<Block>,
-    C::Api: DeipStorageRuntimeApi<Block, AccountId>,
+    C::Api: DeipStorageRuntimeApi<Block, AccountId, AssetId, AssetBalance>,
     AccountId: Codec,
+    AssetId: Codec,
+    AssetBalance: Codec,
 {
     fn get_projects(&self, at: Option<<Block as BlockT>::Hash>) -> Result<Vec<Project<H256, AccountId>>> {
         let api = self.client.runtime_api();
@@ -113,6 +167,36 @@ where
         })
     }
     
+    fn get_projects_page(&self, at: Option<<Block as BlockT>::Hash>, filter: ProjectFilter<AccountId>, start_id: Option<ProjectId>, limit: u32) -> Result<Vec<Project<H256, AccountId>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(||
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash));
+
+        let runtime_api_result = api.get_projects_page(&at, &filter, &start_id, limit);
+
+        runtime_api_result.map_err(|e| RpcError {
+            code: ErrorCode::ServerError(9876), // No real reason for this value
+            message: "Something wrong".into(),
+            data: Some(format!("{:?}", e).into()),
+        })
+    }
+
+    fn get_project_removal_witness(&self, at: Option<<Block as BlockT>::Hash>, project_id: ProjectId) -> Result<ProjectRemovalWitness> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(||
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash));
+
+        let runtime_api_result = api.get_project_removal_witness(&at, &project_id);
+
+        runtime_api_result.map_err(|e| RpcError {
+            code: ErrorCode::ServerError(9876), // No real reason for this value
+            message: "Something wrong".into(),
+            data: Some(format!("{:?}", e).into()),
+        })
+    }
+
     fn get_domains(&self, at: Option<<Block as BlockT>::Hash>) -> Result<Vec<Domain>> {
         let api = self.client.runtime_api();
         let at = BlockId::hash(at.unwrap_or_else(||
@@ -143,6 +227,36 @@ where
         })
     }
     
+    fn get_domain_subtree(&self, at: Option<<Block as BlockT>::Hash>, domain_id: DomainId) -> Result<Vec<Domain>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(||
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash));
+
+        let runtime_api_result = api.get_domain_subtree(&at, &domain_id);
+
+        runtime_api_result.map_err(|e| RpcError {
+            code: ErrorCode::ServerError(9876), // No real reason for this value
+            message: "Something wrong".into(),
+            data: Some(format!("{:?}", e).into()),
+        })
+    }
+
+    fn get_domain_merge_witness(&self, at: Option<<Block as BlockT>::Hash>, domain_id: DomainId) -> Result<DomainMergeWitness> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(||
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash));
+
+        let runtime_api_result = api.get_domain_merge_witness(&at, &domain_id);
+
+        runtime_api_result.map_err(|e| RpcError {
+            code: ErrorCode::ServerError(9876), // No real reason for this value
+            message: "Something wrong".into(),
+            data: Some(format!("{:?}", e).into()),
+        })
+    }
+
     fn get_project_content_list(&self, at: Option<<Block as BlockT>::Hash>, content_ids: Option<Vec<ProjectContentId>>) -> Result<Vec<ProjectContent<H256, AccountId>>> {
         let api = self.client.runtime_api();
         let at = BlockId::hash(at.unwrap_or_else(||
@@ -157,6 +271,20 @@ where
             data: Some(format!("{:?}", e).into()),
         })
     }
+    fn get_project_content_page(&self, at: Option<<Block as BlockT>::Hash>, filter: ProjectContentFilter, start_id: Option<ProjectContentId>, limit: u32) -> Result<Vec<ProjectContent<H256, AccountId>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(||
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash));
+
+        let runtime_api_result = api.get_project_content_page(&at, &filter, &start_id, limit);
+
+        runtime_api_result.map_err(|e| RpcError {
+            code: ErrorCode::ServerError(9876), // No real reason for this value
+            message: "Something wrong".into(),
+            data: Some(format!("{:?}", e).into()),
+        })
+    }
     fn get_project_content(&self, at: Option<<Block as BlockT>::Hash>, project_id: ProjectId, project_content_id: ProjectContentId) -> Result<ProjectContent<H256, AccountId>> {
         let api = self.client.runtime_api();
         let at = BlockId::hash(at.unwrap_or_else(||
@@ -173,6 +301,66 @@ where
         })
     }
 
+    fn get_project_content_version(&self, at: Option<<Block as BlockT>::Hash>, project_id: ProjectId, project_content_id: ProjectContentId, version: ProjectContentVersion) -> Result<Option<ProjectContent<H256, AccountId>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(||
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash));
+
+        let runtime_api_result = api.get_project_content_version(&at, &project_id, &project_content_id, version);
+
+        runtime_api_result.map_err(|e| RpcError {
+            code: ErrorCode::ServerError(9876), // No real reason for this value
+            message: "Something wrong".into(),
+            data: Some(format!("{:?}", e).into()),
+        })
+    }
+
+    fn get_references_of(&self, at: Option<<Block as BlockT>::Hash>, project_content_id: ProjectContentId, depth: u32) -> Result<Vec<ProjectContentId>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(||
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash));
+
+        let runtime_api_result = api.get_references_of(&at, &project_content_id, depth);
+
+        runtime_api_result.map_err(|e| RpcError {
+            code: ErrorCode::ServerError(9876), // No real reason for this value
+            message: "Something wrong".into(),
+            data: Some(format!("{:?}", e).into()),
+        })
+    }
+
+    fn get_cited_by(&self, at: Option<<Block as BlockT>::Hash>, project_content_id: ProjectContentId, depth: u32) -> Result<Vec<ProjectContentId>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(||
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash));
+
+        let runtime_api_result = api.get_cited_by(&at, &project_content_id, depth);
+
+        runtime_api_result.map_err(|e| RpcError {
+            code: ErrorCode::ServerError(9876), // No real reason for this value
+            message: "Something wrong".into(),
+            data: Some(format!("{:?}", e).into()),
+        })
+    }
+
+    fn has_valid_licence(&self, at: Option<<Block as BlockT>::Hash>, account: AccountId, project_content_id: ProjectContentId, moment: u64) -> Result<bool> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(||
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash));
+
+        let runtime_api_result = api.has_valid_licence(&at, &account, &project_content_id, moment);
+
+        runtime_api_result.map_err(|e| RpcError {
+            code: ErrorCode::ServerError(9876), // No real reason for this value
+            message: "Something wrong".into(),
+            data: Some(format!("{:?}", e).into()),
+        })
+    }
+
     fn get_nda_list(&self, at: Option<<Block as BlockT>::Hash>) -> Result<Vec<Nda<H256, AccountId, u64>>> {
         let api = self.client.runtime_api();
         let at = BlockId::hash(at.unwrap_or_else(||
@@ -187,6 +375,21 @@ where
             data: Some(format!("{:?}", e).into()),
         })
     }
+    fn get_nda_page(&self, at: Option<<Block as BlockT>::Hash>, start_id: Option<NdaId>, limit: u32) -> Result<Vec<Nda<H256, AccountId, u64>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(||
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash));
+
+        let runtime_api_result = api.get_nda_page(&at, &start_id, limit);
+
+        runtime_api_result.map_err(|e| RpcError {
+            code: ErrorCode::ServerError(9876), // No real reason for this value
+            message: "Something wrong".into(),
+            data: Some(format!("{:?}", e).into()),
+        })
+    }
+
     fn get_nda(&self, at: Option<<Block as BlockT>::Hash>, nda_id: NdaId) -> Result<Nda<H256, AccountId, u64>> {
         let api = self.client.runtime_api();
         let at = BlockId::hash(at.unwrap_or_else(||
@@ -217,6 +420,21 @@ where
             data: Some(format!("{:?}", e).into()),
         })
     }
+    fn get_reviews_page(&self, at: Option<<Block as BlockT>::Hash>, filter: ReviewFilter<AccountId>, start_id: Option<ReviewId>, limit: u32) -> Result<Vec<Review<H256, AccountId>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(||
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash));
+
+        let runtime_api_result = api.get_reviews_page(&at, &filter, &start_id, limit);
+
+        runtime_api_result.map_err(|e| RpcError {
+            code: ErrorCode::ServerError(9876), // No real reason for this value
+            message: "Something wrong".into(),
+            data: Some(format!("{:?}", e).into()),
+        })
+    }
+
     fn get_review(&self, at: Option<<Block as BlockT>::Hash>, review_id: ReviewId) -> Result<Review<H256, AccountId>> {
         let api = self.client.runtime_api();
         let at = BlockId::hash(at.unwrap_or_else(||
@@ -233,4 +451,228 @@ where
         })
     }
 
+    fn get_investment_opportunity(&self, at: Option<<Block as BlockT>::Hash>, id: InvestmentId) -> Result<Option<SimpleCrowdfunding<u64, AssetId, AssetBalance>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(||
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash));
+
+        let runtime_api_result = api.get_investment_opportunity(&at, &id);
+
+        runtime_api_result.map_err(|e| RpcError {
+            code: ErrorCode::ServerError(9876), // No real reason for this value
+            message: "Something wrong".into(),
+            data: Some(format!("{:?}", e).into()),
+        })
+    }
+
+    fn get_investment_opportunities_page(&self, at: Option<<Block as BlockT>::Hash>, filter: InvestmentOpportunityFilter<AssetId>, start_id: Option<InvestmentId>, limit: u32) -> Result<Vec<SimpleCrowdfunding<u64, AssetId, AssetBalance>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(||
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash));
+
+        let runtime_api_result = api.get_investment_opportunities_page(&at, &filter, &start_id, limit);
+
+        runtime_api_result.map_err(|e| RpcError {
+            code: ErrorCode::ServerError(9876), // No real reason for this value
+            message: "Something wrong".into(),
+            data: Some(format!("{:?}", e).into()),
+        })
+    }
+
+    fn get_investments(&self, at: Option<<Block as BlockT>::Hash>, sale_id: InvestmentId) -> Result<Vec<Investment<AccountId, AssetId, AssetBalance, u64>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(||
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash));
+
+        let runtime_api_result = api.get_investments(&at, &sale_id);
+
+        runtime_api_result.map_err(|e| RpcError {
+            code: ErrorCode::ServerError(9876), // No real reason for this value
+            message: "Something wrong".into(),
+            data: Some(format!("{:?}", e).into()),
+        })
+    }
+
+    fn get_invested_opportunities(&self, at: Option<<Block as BlockT>::Hash>, investor: AccountId) -> Result<Vec<SimpleCrowdfunding<u64, AssetId, AssetBalance>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(||
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash));
+
+        let runtime_api_result = api.get_invested_opportunities(&at, &investor);
+
+        runtime_api_result.map_err(|e| RpcError {
+            code: ErrorCode::ServerError(9876), // No real reason for this value
+            message: "Something wrong".into(),
+            data: Some(format!("{:?}", e).into()),
+        })
+    }
+
+    fn get_milestone_tranches(&self, at: Option<<Block as BlockT>::Hash>, sale_id: InvestmentId) -> Result<Option<MilestoneTranches<AccountId, AssetBalance, u64>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(||
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash));
+
+        let runtime_api_result = api.get_milestone_tranches(&at, &sale_id);
+
+        runtime_api_result.map_err(|e| RpcError {
+            code: ErrorCode::ServerError(9876), // No real reason for this value
+            message: "Something wrong".into(),
+            data: Some(format!("{:?}", e).into()),
+        })
+    }
+
+    fn get_fixed_price_max_per_account(&self, at: Option<<Block as BlockT>::Hash>, sale_id: InvestmentId) -> Result<Option<AssetBalance>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(||
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash));
+
+        let runtime_api_result = api.get_fixed_price_max_per_account(&at, &sale_id);
+
+        runtime_api_result.map_err(|e| RpcError {
+            code: ErrorCode::ServerError(9876), // No real reason for this value
+            message: "Something wrong".into(),
+            data: Some(format!("{:?}", e).into()),
+        })
+    }
+
+    fn get_dutch_auction(&self, at: Option<<Block as BlockT>::Hash>, sale_id: InvestmentId) -> Result<Option<DutchAuctionTerms<AssetBalance>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(||
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash));
+
+        let runtime_api_result = api.get_dutch_auction(&at, &sale_id);
+
+        runtime_api_result.map_err(|e| RpcError {
+            code: ErrorCode::ServerError(9876), // No real reason for this value
+            message: "Something wrong".into(),
+            data: Some(format!("{:?}", e).into()),
+        })
+    }
+
+    fn get_dutch_auction_price(&self, at: Option<<Block as BlockT>::Hash>, sale_id: InvestmentId) -> Result<Option<AssetBalance>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(||
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash));
+
+        let runtime_api_result = api.get_dutch_auction_price(&at, &sale_id);
+
+        runtime_api_result.map_err(|e| RpcError {
+            code: ErrorCode::ServerError(9876), // No real reason for this value
+            message: "Something wrong".into(),
+            data: Some(format!("{:?}", e).into()),
+        })
+    }
+
+    fn get_dutch_auction_bids(&self, at: Option<<Block as BlockT>::Hash>, sale_id: InvestmentId) -> Result<Vec<DutchAuctionBid<AccountId, AssetBalance>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(||
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash));
+
+        let runtime_api_result = api.get_dutch_auction_bids(&at, &sale_id);
+
+        runtime_api_result.map_err(|e| RpcError {
+            code: ErrorCode::ServerError(9876), // No real reason for this value
+            message: "Something wrong".into(),
+            data: Some(format!("{:?}", e).into()),
+        })
+    }
+
+    fn get_vesting_schedule(&self, at: Option<<Block as BlockT>::Hash>, sale_id: InvestmentId) -> Result<Option<VestingSchedule<u64>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(||
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash));
+
+        let runtime_api_result = api.get_vesting_schedule(&at, &sale_id);
+
+        runtime_api_result.map_err(|e| RpcError {
+            code: ErrorCode::ServerError(9876), // No real reason for this value
+            message: "Something wrong".into(),
+            data: Some(format!("{:?}", e).into()),
+        })
+    }
+
+    fn get_vesting_balances(&self, at: Option<<Block as BlockT>::Hash>, account: AccountId) -> Result<Vec<VestingBalance<AssetId, AssetBalance>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(||
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash));
+
+        let runtime_api_result = api.get_vesting_balances(&at, &account);
+
+        runtime_api_result.map_err(|e| RpcError {
+            code: ErrorCode::ServerError(9876), // No real reason for this value
+            message: "Something wrong".into(),
+            data: Some(format!("{:?}", e).into()),
+        })
+    }
+
+    fn is_investor_allowed(&self, at: Option<<Block as BlockT>::Hash>, sale_id: InvestmentId, account: AccountId) -> Result<bool> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(||
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash));
+
+        let runtime_api_result = api.is_investor_allowed(&at, &sale_id, &account);
+
+        runtime_api_result.map_err(|e| RpcError {
+            code: ErrorCode::ServerError(9876), // No real reason for this value
+            message: "Something wrong".into(),
+            data: Some(format!("{:?}", e).into()),
+        })
+    }
+
+    fn get_contribution_limits(&self, at: Option<<Block as BlockT>::Hash>, sale_id: InvestmentId) -> Result<Option<ContributionLimits<AssetBalance>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(||
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash));
+
+        let runtime_api_result = api.get_contribution_limits(&at, &sale_id);
+
+        runtime_api_result.map_err(|e| RpcError {
+            code: ErrorCode::ServerError(9876), // No real reason for this value
+            message: "Something wrong".into(),
+            data: Some(format!("{:?}", e).into()),
+        })
+    }
+
+    fn get_withdrawal_terms(&self, at: Option<<Block as BlockT>::Hash>, sale_id: InvestmentId) -> Result<Option<WithdrawalTerms<u64>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(||
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash));
+
+        let runtime_api_result = api.get_withdrawal_terms(&at, &sale_id);
+
+        runtime_api_result.map_err(|e| RpcError {
+            code: ErrorCode::ServerError(9876), // No real reason for this value
+            message: "Something wrong".into(),
+            data: Some(format!("{:?}", e).into()),
+        })
+    }
+
+    fn get_exchange_rates(&self, at: Option<<Block as BlockT>::Hash>, sale_id: InvestmentId) -> Result<Vec<ExchangeRate<AssetId, AssetBalance>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(||
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash));
+
+        let runtime_api_result = api.get_exchange_rates(&at, &sale_id);
+
+        runtime_api_result.map_err(|e| RpcError {
+            code: ErrorCode::ServerError(9876), // No real reason for this value
+            message: "Something wrong".into(),
+            data: Some(format!("{:?}", e).into()),
+        })
+    }
 }
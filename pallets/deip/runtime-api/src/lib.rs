@@ -12,18 +12,46 @@ use codec::{Codec};
 // Here we declare the runtime API. It is implemented it the `impl` block in
 // runtime amalgamator file (the `runtime/src/lib.rs`)
 sp_api::decl_runtime_apis! {
-    pub trait DeipApi<AccountId>
+    pub trait DeipApi<AccountId, AssetId, AssetBalance>
         where AccountId: Codec,
+        AssetId: Codec,
+        AssetBalance: Codec,
     {
         fn get_projects() -> Vec<(ProjectId, AccountId)>;
         fn get_project(project_id: &ProjectId) -> Project<H256, AccountId>;
+        fn get_projects_page(filter: &ProjectFilter<AccountId>, start_id: &Option<ProjectId>, limit: u32) -> Vec<Project<H256, AccountId>>;
+        fn get_project_removal_witness(project_id: &ProjectId) -> ProjectRemovalWitness;
         fn get_domains() -> Vec<Domain>;
         fn get_domain(domain_id: &DomainId) -> Domain;
+        fn get_domain_subtree(domain_id: &DomainId) -> Vec<Domain>;
+        fn get_domain_merge_witness(domain_id: &DomainId) -> DomainMergeWitness;
         fn get_project_content_list(content_ids: &Option<Vec<ProjectContentId>>) -> Vec<ProjectContent<H256, AccountId>>;
+        fn get_project_content_page(filter: &ProjectContentFilter, start_id: &Option<ProjectContentId>, limit: u32) -> Vec<ProjectContent<H256, AccountId>>;
         fn get_project_content(project_id: &ProjectId, project_content_id: &ProjectContentId) -> ProjectContent<H256, AccountId>;
+        fn get_project_content_version(project_id: &ProjectId, project_content_id: &ProjectContentId, version: ProjectContentVersion) -> Option<ProjectContent<H256, AccountId>>;
+        fn get_references_of(project_content_id: &ProjectContentId, depth: u32) -> Vec<ProjectContentId>;
+        fn get_cited_by(project_content_id: &ProjectContentId, depth: u32) -> Vec<ProjectContentId>;
+        fn has_valid_licence(account: &AccountId, project_content_id: &ProjectContentId, moment: u64) -> bool;
         fn get_nda_list() -> Vec<Nda<H256, AccountId, u64>>;
+        fn get_nda_page(start_id: &Option<NdaId>, limit: u32) -> Vec<Nda<H256, AccountId, u64>>;
         fn get_nda(nda_id: &NdaId) -> Nda<H256, AccountId, u64>;
         fn get_reviews() -> Vec<Review<H256, AccountId>>;
+        fn get_reviews_page(filter: &ReviewFilter<AccountId>, start_id: &Option<ReviewId>, limit: u32) -> Vec<Review<H256, AccountId>>;
         fn get_review(review_id: &ReviewId) -> Review<H256, AccountId>;
+        fn get_investment_opportunity(id: &InvestmentId) -> Option<SimpleCrowdfunding<u64, AssetId, AssetBalance>>;
+        fn get_investment_opportunities_page(filter: &InvestmentOpportunityFilter<AssetId>, start_id: &Option<InvestmentId>, limit: u32) -> Vec<SimpleCrowdfunding<u64, AssetId, AssetBalance>>;
+        fn get_investments(sale_id: &InvestmentId) -> Vec<Investment<AccountId, AssetId, AssetBalance, u64>>;
+        fn get_invested_opportunities(investor: &AccountId) -> Vec<SimpleCrowdfunding<u64, AssetId, AssetBalance>>;
+        fn get_milestone_tranches(sale_id: &InvestmentId) -> Option<MilestoneTranches<AccountId, AssetBalance, u64>>;
+        fn get_fixed_price_max_per_account(sale_id: &InvestmentId) -> Option<AssetBalance>;
+        fn get_dutch_auction(sale_id: &InvestmentId) -> Option<DutchAuctionTerms<AssetBalance>>;
+        fn get_dutch_auction_price(sale_id: &InvestmentId) -> Option<AssetBalance>;
+        fn get_dutch_auction_bids(sale_id: &InvestmentId) -> Vec<DutchAuctionBid<AccountId, AssetBalance>>;
+        fn get_vesting_schedule(sale_id: &InvestmentId) -> Option<VestingSchedule<u64>>;
+        fn get_vesting_balances(account: &AccountId) -> Vec<VestingBalance<AssetId, AssetBalance>>;
+        fn is_investor_allowed(sale_id: &InvestmentId, account: &AccountId) -> bool;
+        fn get_contribution_limits(sale_id: &InvestmentId) -> Option<ContributionLimits<AssetBalance>>;
+        fn get_withdrawal_terms(sale_id: &InvestmentId) -> Option<WithdrawalTerms<u64>>;
+        fn get_exchange_rates(sale_id: &InvestmentId) -> Vec<ExchangeRate<AssetId, AssetBalance>>;
     }
 }
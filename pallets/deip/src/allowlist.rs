@@ -0,0 +1,189 @@
+use crate::*;
+
+use sp_runtime::traits::{Saturating, Zero};
+
+/// Unique Allowlist reference
+pub type Id = H160;
+
+/// Investors allowed to invest to the investment opportunity
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub enum Allowlist<AccountId> {
+    /// Allowlist of the opportunity. It's managed by its creator.
+    Accounts(Vec<AccountId>),
+    /// Allowlist shared by opportunities, e.g. investors verified by an org owning it
+    Reference(Id),
+}
+
+/// Bounds of the total contribution of an investor
+#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct ContributionLimits<Balance> {
+    pub min: Option<Balance>,
+    pub max: Option<Balance>,
+}
+
+impl<DeipAccountId> Allowlist<DeipAccountId> {
+    pub(super) fn into_accounts<AccountId>(self) -> Allowlist<AccountId>
+    where
+        DeipAccountId: Into<AccountId>,
+    {
+        match self {
+            Allowlist::Accounts(members) => Allowlist::Accounts(members.into_iter().map(Into::into).collect()),
+            Allowlist::Reference(id) => Allowlist::Reference(id),
+        }
+    }
+}
+
+impl<T: Config> Module<T> {
+    pub(super) fn create_allowlist_impl(
+        account: T::AccountId,
+        id: Id,
+        members: Vec<T::AccountId>,
+    ) -> DispatchResult {
+        ensure!(!Allowlists::<T>::contains_key(id), Error::<T>::AllowlistAlreadyExists);
+
+        Allowlists::<T>::insert(id, account.clone());
+        for member in &members {
+            AllowlistMembers::<T>::insert(id, member, ());
+        }
+
+        Self::deposit_event(RawEvent::AllowlistCreated(id, account));
+
+        Ok(())
+    }
+
+    pub(super) fn update_allowlist_impl(
+        account: T::AccountId,
+        id: Id,
+        to_add: Vec<T::AccountId>,
+        to_remove: Vec<T::AccountId>,
+    ) -> DispatchResult {
+        let owner = Allowlists::<T>::get(id).ok_or(Error::<T>::NoSuchAllowlist)?;
+        ensure!(owner == account, Error::<T>::NoPermission);
+
+        for member in &to_remove {
+            AllowlistMembers::<T>::remove(id, member);
+        }
+        for member in &to_add {
+            AllowlistMembers::<T>::insert(id, member, ());
+        }
+
+        Self::deposit_event(RawEvent::AllowlistUpdated(id));
+
+        Ok(())
+    }
+
+    pub(super) fn update_investment_allowlist_impl(
+        account: T::AccountId,
+        sale_id: InvestmentId,
+        to_add: Vec<T::AccountId>,
+        to_remove: Vec<T::AccountId>,
+    ) -> DispatchResult {
+        let owner = InvestmentAllowlistOwners::<T>::get(sale_id).ok_or(Error::<T>::NoSuchAllowlist)?;
+        ensure!(owner == account, Error::<T>::NoPermission);
+
+        for member in &to_remove {
+            InvestmentAllowlistMembers::<T>::remove(sale_id, member);
+        }
+        for member in &to_add {
+            InvestmentAllowlistMembers::<T>::insert(sale_id, member, ());
+        }
+
+        Self::deposit_event(RawEvent::InvestmentAllowlistUpdated(sale_id));
+
+        Ok(())
+    }
+
+    /// Checks the allowlist and limits before the opportunity is created
+    pub(super) fn ensure_investor_terms_valid(
+        allowlist: &Option<AllowlistOf<T>>,
+        limits: &Option<ContributionLimitsOf<T>>,
+    ) -> DispatchResult {
+        if let Some(Allowlist::Reference(id)) = allowlist {
+            ensure!(Allowlists::<T>::contains_key(id), Error::<T>::NoSuchAllowlist);
+        }
+
+        if let Some(ContributionLimits { min, max }) = limits {
+            ensure!(
+                max.map_or(true, |max| !max.is_zero() && min.map_or(true, |min| min <= max)),
+                Error::<T>::ContributionLimitsInvalid
+            );
+        }
+
+        Ok(())
+    }
+
+    pub(super) fn set_investor_terms(
+        creator: &T::AccountId,
+        sale_id: InvestmentId,
+        allowlist: Option<AllowlistOf<T>>,
+        limits: Option<ContributionLimitsOf<T>>,
+    ) -> DispatchResult {
+        match allowlist {
+            Some(Allowlist::Accounts(members)) => {
+                InvestmentAllowlistOwners::<T>::insert(sale_id, creator);
+                for member in &members {
+                    InvestmentAllowlistMembers::<T>::insert(sale_id, member, ());
+                }
+            }
+            Some(Allowlist::Reference(id)) => InvestmentAllowlistMap::insert(sale_id, id),
+            None => (),
+        };
+
+        if let Some(limits) = limits {
+            InvestmentContributionLimits::<T>::insert(sale_id, limits);
+        }
+
+        Ok(())
+    }
+
+    /// Checks the investor is allowed to contribute `amount` to the sale.
+    /// The minimum applies to the `offered` amount, as the contribution that
+    /// reaches the hard cap may be cut below it.
+    pub(super) fn ensure_investor_terms_met(
+        sale_id: InvestmentId,
+        account: &T::AccountId,
+        offered: DeipAssetBalanceOf<T>,
+        amount: DeipAssetBalanceOf<T>,
+    ) -> DispatchResult {
+        ensure!(
+            Self::is_investor_allowed(&sale_id, account),
+            Error::<T>::InvestorNotAllowlisted
+        );
+
+        let limits = match InvestmentContributionLimits::<T>::get(sale_id) {
+            None => return Ok(()),
+            Some(limits) => limits,
+        };
+
+        ensure!(
+            limits.min.map_or(true, |min| offered >= min),
+            Error::<T>::ContributionBelowMinimum
+        );
+
+        let investments = InvestmentMap::<T>::get(sale_id);
+        let invested = investments
+            .binary_search_by_key(&account, |(investor, _)| investor)
+            .map_or(Zero::zero(), |i| investments[i].1.amount);
+        ensure!(
+            limits.max.map_or(true, |max| invested.saturating_add(amount) <= max),
+            Error::<T>::ContributionAboveMaximum
+        );
+
+        Ok(())
+    }
+
+    pub fn is_investor_allowed(sale_id: &InvestmentId, account: &T::AccountId) -> bool {
+        if InvestmentAllowlistOwners::<T>::contains_key(sale_id) {
+            return InvestmentAllowlistMembers::<T>::contains_key(sale_id, account);
+        }
+        InvestmentAllowlistMap::get(sale_id).map_or(true, |id| AllowlistMembers::<T>::contains_key(id, account))
+    }
+
+    pub fn get_contribution_limits(sale_id: &InvestmentId) -> Option<ContributionLimitsOf<T>> {
+        InvestmentContributionLimits::<T>::get(sale_id)
+    }
+}
@@ -1,20 +1,25 @@
 use crate::*;
 
 use crate::traits::DeipAssetSystem;
+use frame_support::transactional;
 use sp_runtime::traits::Saturating;
 use sp_std::vec;
 
 #[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq)]
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
-pub struct Contribution<AccountId, Balance, Moment> {
+pub struct Contribution<AccountId, AssetId, Balance, Moment> {
     pub sale_id: InvestmentId,
     pub owner: AccountId,
+    /// Value of paid assets in the asset the sale is capped in
     pub amount: Balance,
     pub time: Moment,
+    /// Assets paid by the investor
+    pub assets: Vec<(AssetId, Balance)>,
 }
 
 impl<T: Config> Module<T> {
+    #[transactional]
     pub(super) fn invest_to_crowdfunding_impl(
         account: T::AccountId,
         sale_id: InvestmentId,
@@ -28,21 +33,35 @@ impl<T: Config> Module<T> {
             Error::<T>::InvestingNotActive
         );
 
-        ensure!(sale.asset_id == asset.id, Error::<T>::InvestingWrongAsset);
+        let rate = Self::exchange_rate(&sale, asset.id)?;
 
-        let is_hard_cap_reached = sale.total_amount.saturating_add(asset.amount) >= sale.hard_cap;
-        let amount_to_contribute = if is_hard_cap_reached {
-            sale.hard_cap.saturating_sub(sale.total_amount)
+        let (amount_to_contribute, is_hard_cap_reached) = if DutchAuctionMap::<T>::contains_key(sale_id) {
+            Self::bid_to_dutch_auction(&sale, &account, asset.amount)?
+        } else if sale.total_amount.saturating_add(Self::exchange_to_value(&rate, asset.amount)) >= sale.hard_cap {
+            (Self::exchange_to_amount(&rate, sale.hard_cap.saturating_sub(sale.total_amount)), true)
         } else {
-            asset.amount
+            (asset.amount, false)
         };
+        let value_to_contribute = Self::exchange_to_value(&rate, amount_to_contribute);
+
+        Self::ensure_investor_terms_met(
+            sale_id,
+            &account,
+            Self::exchange_to_value(&rate, asset.amount),
+            value_to_contribute,
+        )?;
+        Self::ensure_fixed_price_limit(sale_id, &account, value_to_contribute)?;
 
         ensure!(
-            T::AssetSystem::transfer_to_reserved(&account, sale.external_id, amount_to_contribute)
+            T::AssetSystem::transfer_to_reserved(&account, sale.external_id, asset.id, amount_to_contribute)
                 .is_ok(),
             Error::<T>::InvestingNotEnoughFunds
         );
 
+        if FixedPriceMaxPerAccount::<T>::contains_key(sale_id) {
+            Self::buy_fixed_price_shares(&sale, &account, value_to_contribute)?;
+        }
+
         InvestmentMap::<T>::mutate_exists(sale_id, |contributions| {
             let mut_contributions = match contributions.as_mut() {
                 None => {
@@ -51,8 +70,9 @@ impl<T: Config> Module<T> {
                         Contribution {
                             sale_id: sale_id,
                             owner: account.clone(),
-                            amount: amount_to_contribute,
+                            amount: value_to_contribute,
                             time: pallet_timestamp::Module::<T>::get(),
+                            assets: vec![(asset.id, amount_to_contribute)],
                         },
                     )]);
                     return;
@@ -69,25 +89,32 @@ impl<T: Config> Module<T> {
                             Contribution {
                                 sale_id: sale_id,
                                 owner: account.clone(),
-                                amount: amount_to_contribute,
+                                amount: value_to_contribute,
                                 time: pallet_timestamp::Module::<T>::get(),
+                                assets: vec![(asset.id, amount_to_contribute)],
                             },
                         ),
                     );
                 }
                 Ok(i) => {
-                    mut_contributions[i].1.amount =
-                        amount_to_contribute.saturating_add(mut_contributions[i].1.amount);
+                    let contribution = &mut mut_contributions[i].1;
+                    contribution.amount = value_to_contribute.saturating_add(contribution.amount);
+                    match contribution.assets.iter_mut().find(|(id, _)| *id == asset.id) {
+                        Some((_, amount)) => *amount = amount_to_contribute.saturating_add(*amount),
+                        None => contribution.assets.push((asset.id, amount_to_contribute)),
+                    }
                 }
             };
         });
 
-        Self::collect_funds(sale_id, amount_to_contribute).expect("collect; already found");
+        InvestmentIdByInvestor::<T>::insert(&account, sale_id, ());
+
+        Self::collect_funds(sale_id, value_to_contribute)?;
 
         Self::deposit_event(RawEvent::Invested(sale_id, account.clone()));
 
         if is_hard_cap_reached {
-            Self::finish_crowdfunding_by_id(sale_id).expect("finish; already found");
+            Self::finish_crowdfunding_by_id(sale_id)?;
         }
 
         Ok(())
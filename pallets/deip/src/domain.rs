@@ -0,0 +1,217 @@
+use crate::*;
+
+/// Numbers of entities moved by `merge_domains`. The call is charged by them,
+/// so they shouldn't be less than the actual ones
+#[derive(Encode, Decode, Clone, Copy, Default, RuntimeDebug, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct MergeWitness {
+    /// Projects of the merged domain
+    pub projects: u32,
+    /// Reviews of the merged domain
+    pub reviews: u32,
+    /// Child domains of the merged domain
+    pub children: u32,
+}
+
+impl MergeWitness {
+    fn covers(&self, actual: &Self) -> bool {
+        self.projects >= actual.projects
+            && self.reviews >= actual.reviews
+            && self.children >= actual.children
+    }
+}
+
+impl<T: Config> Module<T> {
+    pub(super) fn add_domain_impl(account: Option<T::AccountId>, domain: Domain) -> DispatchResult {
+        let domain_count = DomainCount::get();
+        ensure!(domain_count < T::MaxDomains::get(), Error::<T>::DomianLimitReached);
+
+        let external_id = domain.external_id;
+
+        // We don't want to add duplicate domains, so we check whether the potential new
+        // domain is already present in the list. Because the domains is stored as a hash
+        // map this check is constant time O(1)
+        ensure!(!Domains::contains_key(&external_id), Error::<T>::DomainAlreadyExists);
+
+        if let Some(parent_id) = domain.parent_id {
+            ensure!(Domains::contains_key(&parent_id), Error::<T>::NoSuchParentDomain);
+            DomainChildren::append(parent_id, external_id);
+        }
+
+        // Insert the new domin and emit the event
+        Domains::insert(&external_id, domain);
+        DomainCount::put(domain_count + 1); // overflow check not necessary because of maximum
+
+        Self::deposit_event(RawEvent::DomainAdded(account, external_id));
+
+        Ok(())
+    }
+
+    pub(super) fn deprecate_domain_impl(domain_id: DomainId) -> DispatchResult {
+        ensure!(Domains::contains_key(&domain_id), Error::<T>::DomainNotExists);
+        ensure!(!DeprecatedDomains::contains_key(&domain_id), Error::<T>::DomainAlreadyDeprecated);
+
+        DeprecatedDomains::insert(domain_id, ());
+
+        Self::deposit_event(RawEvent::DomainDeprecated(domain_id));
+
+        Ok(())
+    }
+
+    pub(super) fn merge_domains_impl(from: DomainId, into: DomainId, witness: DomainMergeWitness) -> DispatchResult {
+        ensure!(Domains::contains_key(&from), Error::<T>::DomainNotExists);
+        ensure!(Domains::contains_key(&into), Error::<T>::DomainNotExists);
+        // merging a domain into its own descendant would make a cycle
+        ensure!(
+            !Self::is_domain_matched(&[into], &from),
+            Error::<T>::DomainMergeIntoDescendant
+        );
+
+        ensure!(
+            witness.covers(&Self::get_domain_merge_witness(&from)),
+            Error::<T>::DomainMergeWitnessInvalid
+        );
+
+        for (project_id, _) in ProjectIdByDomainId::iter_prefix(from) {
+            ProjectMap::<T>::mutate(project_id, |project| {
+                Self::replace_domain(&mut project.domains, &from, &into)
+            });
+            ProjectIdByDomainId::insert(into, project_id, ());
+        }
+        ProjectIdByDomainId::remove_prefix(from);
+
+        Self::merge_reviews_domain(&from, &into);
+
+        for child_id in DomainChildren::take(from) {
+            Domains::mutate(child_id, |child| child.parent_id = Some(into));
+            DomainChildren::append(into, child_id);
+        }
+
+        Self::detach_domain(&from);
+
+        Self::deposit_event(RawEvent::DomainsMerged(from, into));
+
+        Ok(())
+    }
+
+    pub(super) fn remove_domain_impl(domain_id: DomainId) -> DispatchResult {
+        ensure!(Domains::contains_key(&domain_id), Error::<T>::DomainNotExists);
+        ensure!(DomainChildren::get(domain_id).is_empty(), Error::<T>::DomainHasChildren);
+
+        let is_used_by_projects = ProjectIdByDomainId::iter_prefix(domain_id).next().is_some();
+        ensure!(
+            !is_used_by_projects && !Self::is_domain_used_by_reviews(&domain_id),
+            Error::<T>::DomainInUse
+        );
+
+        Self::detach_domain(&domain_id);
+
+        Self::deposit_event(RawEvent::DomainRemoved(domain_id));
+
+        Ok(())
+    }
+
+    /// Counts entities `merge_domains` moves from the domain
+    pub fn get_domain_merge_witness(domain_id: &DomainId) -> DomainMergeWitness {
+        DomainMergeWitness {
+            projects: ProjectIdByDomainId::iter_prefix(domain_id).count() as u32,
+            reviews: ReviewIdByDomainId::iter_prefix(domain_id).count() as u32,
+            children: DomainChildren::decode_len(domain_id).unwrap_or(0) as u32,
+        }
+    }
+
+    /// Weight of `merge_domains` that moves projects, reviews and child domains
+    /// of the merged domain
+    pub(super) fn merge_domains_weight(witness: &DomainMergeWitness) -> Weight {
+        let projects = witness.projects as Weight;
+        let reviews = witness.reviews as Weight;
+        let children = witness.children as Weight;
+        // the hierarchy walk of the descendant check is bounded by the number of domains
+        let depth = T::MaxDomains::get() as Weight;
+
+        // counting the actual entities reads them as well
+        let reads = 6 + depth + 2 * (projects + reviews + children);
+        let writes = 5 + 2 * projects + 2 * reviews + 2 * children;
+        T::DbWeight::get().reads_writes(reads, writes)
+    }
+
+    /// Weight of `remove_domain` that looks up the first project and review of the domain
+    pub(super) fn remove_domain_weight() -> Weight {
+        T::DbWeight::get().reads_writes(6, 4)
+    }
+
+    /// Replaces `from` with `into` keeping the domains unique
+    pub(super) fn replace_domain(domains: &mut Vec<DomainId>, from: &DomainId, into: &DomainId) {
+        if !domains.contains(from) {
+            return;
+        }
+
+        domains.retain(|domain_id| domain_id != from);
+        if !domains.contains(into) {
+            domains.push(*into);
+        }
+    }
+
+    fn detach_domain(domain_id: &DomainId) {
+        let domain = Domains::take(domain_id);
+        if let Some(parent_id) = domain.parent_id {
+            DomainChildren::mutate_exists(parent_id, |maybe_children| {
+                if let Some(children) = maybe_children {
+                    children.retain(|child_id| child_id != domain_id);
+                    if children.is_empty() {
+                        *maybe_children = None;
+                    }
+                }
+            });
+        }
+
+        DeprecatedDomains::remove(domain_id);
+        DomainCount::mutate(|count| *count = count.saturating_sub(1));
+    }
+
+    /// Checks that all `domains` exist and none of them is deprecated
+    pub(super) fn ensure_domains_are_usable(domains: &[DomainId]) -> DispatchResult {
+        for domain in domains {
+            ensure!(Domains::contains_key(&domain), Error::<T>::DomainNotExists);
+            ensure!(!DeprecatedDomains::contains_key(&domain), Error::<T>::DomainIsDeprecated);
+        }
+
+        Ok(())
+    }
+
+    /// Checks whether `domain_id` is one of `domains` or an ancestor of one of them,
+    /// i.e. a parent domain is matched through its children.
+    pub fn is_domain_matched(domains: &[DomainId], domain_id: &DomainId) -> bool {
+        domains.iter().any(|tagged| {
+            let mut current = Some(*tagged);
+            // the hierarchy can't be deeper than the number of domains
+            for _ in 0..=DomainCount::get() {
+                match current {
+                    Some(id) if id == *domain_id => return true,
+                    Some(id) => current = Domains::get(id).parent_id,
+                    None => break,
+                }
+            }
+            false
+        })
+    }
+
+    /// Returns the domain followed by all its descendants in breadth-first order.
+    pub fn get_domain_subtree(domain_id: &DomainId) -> Vec<Domain> {
+        let root = match Domains::try_get(domain_id) {
+            Ok(domain) => domain,
+            Err(_) => return Vec::new(),
+        };
+
+        let mut subtree = vec![root];
+        let mut index = 0;
+        while index < subtree.len() {
+            let children = DomainChildren::get(subtree[index].external_id);
+            subtree.extend(children.into_iter().map(Domains::get));
+            index += 1;
+        }
+
+        subtree
+    }
+}
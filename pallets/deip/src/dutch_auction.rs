@@ -0,0 +1,176 @@
+use crate::*;
+
+use sp_runtime::{
+    traits::{One, Saturating, Zero},
+    SaturatedConversion,
+};
+
+/// Prices of all offered shares at the start and at the end of the auction
+#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct Terms<Balance> {
+    pub start_price: Balance,
+    pub floor_price: Balance,
+}
+
+/// Funds locked by the bidder at the price of the moment the bid was placed
+#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct Bid<AccountId, Balance> {
+    pub bidder: AccountId,
+    pub amount: Balance,
+    pub price: Balance,
+}
+
+impl<T: Config> Module<T> {
+    #[allow(clippy::too_many_arguments)]
+    pub(super) fn create_dutch_auction(
+        account: T::AccountId,
+        external_id: InvestmentId,
+        start_time: T::Moment,
+        end_time: T::Moment,
+        start_price: DeipAssetOf<T>,
+        floor_price: DeipAssetOf<T>,
+        shares: Vec<DeipAssetOf<T>>,
+    ) -> DispatchResult {
+        ensure!(
+            start_price.id == floor_price.id,
+            Error::<T>::InvestmentOpportunityCapDifferentAssets
+        );
+        ensure!(
+            !floor_price.amount.is_zero(),
+            Error::<T>::DutchAuctionFloorPriceMustBePositive
+        );
+        ensure!(
+            start_price.amount >= floor_price.amount,
+            Error::<T>::DutchAuctionStartPriceMustBeGreaterOrEqualFloorPrice
+        );
+
+        let terms = Terms {
+            start_price: start_price.amount,
+            floor_price: floor_price.amount,
+        };
+
+        // the auction is settled if there is any bid
+        Self::create_simple_crowdfunding(
+            account,
+            external_id,
+            start_time,
+            end_time,
+            DeipAsset::new(start_price.id, One::one()),
+            start_price,
+            shares,
+            Vec::new(),
+        )?;
+
+        DutchAuctionMap::<T>::insert(external_id, terms);
+
+        Ok(())
+    }
+
+    /// Price of all offered shares falls linearly from the start price to the floor price
+    pub(super) fn dutch_auction_price(
+        sale: &SimpleCrowdfundingOf<T>,
+        terms: &DutchAuctionTermsOf<T>,
+    ) -> DeipAssetBalanceOf<T> {
+        let now = pallet_timestamp::Module::<T>::get();
+        if now <= sale.start_time {
+            return terms.start_price;
+        }
+        if now >= sale.end_time {
+            return terms.floor_price;
+        }
+
+        let elapsed: u128 = (now - sale.start_time).saturated_into();
+        let duration: u128 = (sale.end_time - sale.start_time).saturated_into();
+        let fall: u128 = terms.start_price.saturating_sub(terms.floor_price).saturated_into();
+
+        terms.start_price.saturating_sub((fall.saturating_mul(elapsed) / duration).saturated_into())
+    }
+
+    /// Fixes the price of the dutch auction at the moment it ended, so the settlement
+    /// retried later clears at the same price
+    pub(super) fn fix_dutch_auction_clearing_price(sale: &SimpleCrowdfundingOf<T>) {
+        if let Some(terms) = DutchAuctionMap::<T>::get(sale.external_id) {
+            DutchAuctionClearingPrice::<T>::insert(sale.external_id, Self::dutch_auction_price(sale, &terms));
+        }
+    }
+
+    /// Places the bid at the current price. Returns the amount to lock, which is limited by
+    /// shares left unclaimed by previous bids, and whether all shares are claimed.
+    pub(super) fn bid_to_dutch_auction(
+        sale: &SimpleCrowdfundingOf<T>,
+        account: &T::AccountId,
+        amount: DeipAssetBalanceOf<T>,
+    ) -> Result<(DeipAssetBalanceOf<T>, bool), DispatchError> {
+        let terms = DutchAuctionMap::<T>::get(sale.external_id).ok_or(Error::<T>::InvestingNotFound)?;
+        let price = Self::dutch_auction_price(sale, &terms);
+
+        let mut bids = DutchAuctionBids::<T>::get(sale.external_id);
+        let max_bids = T::MaxDutchAuctionBids::get();
+        ensure!((bids.len() as u32) < max_bids, Error::<T>::DutchAuctionBidsLimitReached);
+
+        // funds claimed shares would cost at the current price
+        let claimed = bids.iter().fold(Zero::zero(), |total: DeipAssetBalanceOf<T>, bid| {
+            total.saturating_add(Self::dutch_auction_payment(bid, price))
+        });
+
+        let unclaimed = price.saturating_sub(claimed);
+        ensure!(!unclaimed.is_zero(), Error::<T>::DutchAuctionSoldOut);
+
+        let amount_to_lock = amount.min(unclaimed);
+        // the bid claims at least its part of shares unless it takes the rest of them
+        ensure!(
+            amount_to_lock == unclaimed || amount_to_lock >= price / max_bids.into(),
+            Error::<T>::DutchAuctionBidTooSmall
+        );
+        bids.push(Bid {
+            bidder: account.clone(),
+            amount: amount_to_lock,
+            price,
+        });
+        DutchAuctionBids::<T>::insert(sale.external_id, bids);
+
+        Ok((amount_to_lock, amount_to_lock == unclaimed))
+    }
+
+    /// Funds the bid pays for claimed shares at `price`
+    pub(super) fn dutch_auction_payment(
+        bid: &DutchAuctionBidOf<T>,
+        price: DeipAssetBalanceOf<T>,
+    ) -> DeipAssetBalanceOf<T> {
+        (bid.amount
+            .saturated_into::<u128>()
+            .saturating_mul(price.saturated_into())
+            / bid.price.saturated_into::<u128>())
+        .saturated_into()
+    }
+
+    /// Weight of `invest` that places the bid among at most `MaxDutchAuctionBids` bids
+    /// and settles them once the bid claims the rest of shares
+    pub(super) fn invest_weight() -> Weight {
+        let bids = T::MaxDutchAuctionBids::get() as Weight;
+        // a settled bid gets claimed shares and the excess of locked funds transferred
+        T::DbWeight::get().reads_writes(12 + 7 * bids, 10 + 6 * bids)
+    }
+
+    pub fn get_dutch_auction(sale_id: &InvestmentId) -> Option<DutchAuctionTermsOf<T>> {
+        DutchAuctionMap::<T>::get(sale_id)
+    }
+
+    /// Returns the current price of all offered shares or the clearing price once the auction ended
+    pub fn get_dutch_auction_price(sale_id: &InvestmentId) -> Option<DeipAssetBalanceOf<T>> {
+        if let Some(clearing_price) = DutchAuctionClearingPrice::<T>::get(sale_id) {
+            return Some(clearing_price);
+        }
+        let sale = SimpleCrowdfundingMap::<T>::try_get(sale_id).ok()?;
+        let terms = DutchAuctionMap::<T>::get(sale_id)?;
+        Some(Self::dutch_auction_price(&sale, &terms))
+    }
+
+    pub fn get_dutch_auction_bids(sale_id: &InvestmentId) -> Vec<DutchAuctionBidOf<T>> {
+        DutchAuctionBids::<T>::get(sale_id)
+    }
+}
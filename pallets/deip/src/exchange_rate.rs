@@ -0,0 +1,84 @@
+use crate::*;
+
+use sp_runtime::{traits::Zero, SaturatedConversion};
+
+/// `amount` of the asset is worth `value` units of the asset the sale is capped in
+#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct ExchangeRate<AssetId, Balance> {
+    pub asset_id: AssetId,
+    pub amount: Balance,
+    pub value: Balance,
+}
+
+impl<T: Config> Module<T> {
+    pub(super) fn ensure_exchange_rates_valid(
+        rates: &[ExchangeRateOf<T>],
+        asset_id: DeipAssetIdOf<T>,
+        shares: &[DeipAssetOf<T>],
+    ) -> DispatchResult {
+        for (i, rate) in rates.iter().enumerate() {
+            ensure!(
+                !rate.amount.is_zero() && !rate.value.is_zero(),
+                Error::<T>::ExchangeRateMustBePositive
+            );
+            ensure!(
+                rate.asset_id != asset_id && shares.iter().all(|share| share.id != rate.asset_id),
+                Error::<T>::InvestmentOpportunityWrongAssetId
+            );
+            ensure!(
+                rates[..i].iter().all(|other| other.asset_id != rate.asset_id),
+                Error::<T>::ExchangeRateDuplicateAsset
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Returns the rate of `asset_id` or `None` if it's the reference asset of the sale
+    pub(super) fn exchange_rate(
+        sale: &SimpleCrowdfundingOf<T>,
+        asset_id: DeipAssetIdOf<T>,
+    ) -> Result<Option<ExchangeRateOf<T>>, DispatchError> {
+        if asset_id == sale.asset_id {
+            return Ok(None);
+        }
+
+        ExchangeRatesMap::<T>::get(sale.external_id)
+            .into_iter()
+            .find(|rate| rate.asset_id == asset_id)
+            .map(Some)
+            .ok_or_else(|| Error::<T>::InvestingWrongAsset.into())
+    }
+
+    /// Value of `amount` of the asset with the `rate` in the reference asset
+    pub(super) fn exchange_to_value(
+        rate: &Option<ExchangeRateOf<T>>,
+        amount: DeipAssetBalanceOf<T>,
+    ) -> DeipAssetBalanceOf<T> {
+        match rate {
+            None => amount,
+            Some(rate) => (amount.saturated_into::<u128>().saturating_mul(rate.value.saturated_into())
+                / rate.amount.saturated_into::<u128>())
+            .saturated_into(),
+        }
+    }
+
+    /// Amount of the asset with the `rate` that is worth `value` in the reference asset
+    pub(super) fn exchange_to_amount(
+        rate: &Option<ExchangeRateOf<T>>,
+        value: DeipAssetBalanceOf<T>,
+    ) -> DeipAssetBalanceOf<T> {
+        match rate {
+            None => value,
+            Some(rate) => (value.saturated_into::<u128>().saturating_mul(rate.amount.saturated_into())
+                / rate.value.saturated_into::<u128>())
+            .saturated_into(),
+        }
+    }
+
+    pub fn get_exchange_rates(sale_id: &InvestmentId) -> Vec<ExchangeRateOf<T>> {
+        ExchangeRatesMap::<T>::get(sale_id)
+    }
+}
@@ -0,0 +1,99 @@
+use crate::*;
+
+use sp_runtime::{
+    traits::{Saturating, Zero},
+    SaturatedConversion,
+};
+
+impl<T: Config> Module<T> {
+    #[allow(clippy::too_many_arguments)]
+    pub(super) fn create_fixed_price_sale(
+        account: T::AccountId,
+        external_id: InvestmentId,
+        start_time: T::Moment,
+        end_time: T::Moment,
+        price: DeipAssetOf<T>,
+        max_per_account: DeipAssetOf<T>,
+        shares: Vec<DeipAssetOf<T>>,
+    ) -> DispatchResult {
+        ensure!(
+            max_per_account.id == price.id,
+            Error::<T>::InvestmentOpportunityCapDifferentAssets
+        );
+        ensure!(
+            !max_per_account.amount.is_zero(),
+            Error::<T>::FixedPriceMaxPerAccountMustBePositive
+        );
+
+        // the sale succeeds once all shares are sold out, otherwise it expires
+        // and unsold shares return to the creator along with raised funds
+        Self::create_simple_crowdfunding(
+            account,
+            external_id,
+            start_time,
+            end_time,
+            price.clone(),
+            price,
+            shares,
+            Vec::new(),
+        )?;
+
+        FixedPriceMaxPerAccount::<T>::insert(external_id, max_per_account.amount);
+
+        Ok(())
+    }
+
+    /// Checks the account doesn't exceed the limit of the fixed price sale
+    pub(super) fn ensure_fixed_price_limit(
+        sale_id: InvestmentId,
+        account: &T::AccountId,
+        amount: DeipAssetBalanceOf<T>,
+    ) -> DispatchResult {
+        let max_per_account = match FixedPriceMaxPerAccount::<T>::get(sale_id) {
+            None => return Ok(()),
+            Some(max) => max,
+        };
+
+        let investments = InvestmentMap::<T>::get(sale_id);
+        let invested = investments
+            .binary_search_by_key(&account, |(investor, _)| investor)
+            .map_or(Zero::zero(), |i| investments[i].1.amount);
+        ensure!(
+            invested.saturating_add(amount) <= max_per_account,
+            Error::<T>::FixedPriceMaxPerAccountExceeded
+        );
+
+        Ok(())
+    }
+
+    /// Transfers shares bought for `amount` at the rate of `hard_cap` per all offered shares
+    pub(super) fn buy_fixed_price_shares(
+        sale: &SimpleCrowdfundingOf<T>,
+        account: &T::AccountId,
+        amount: DeipAssetBalanceOf<T>,
+    ) -> DispatchResult {
+        let mut bought = false;
+        for (asset_id, asset_amount) in &sale.shares {
+            let token_amount: DeipAssetBalanceOf<T> = (amount
+                .saturated_into::<u128>()
+                .saturating_mul((*asset_amount).saturated_into())
+                / sale.hard_cap.saturated_into::<u128>())
+            .saturated_into();
+            if token_amount.is_zero() {
+                continue;
+            }
+
+            Self::transfer_shares(sale.external_id, account, *asset_id, token_amount)
+                .map_err(|_| Error::<T>::FixedPriceSharesTransferFailed)?;
+            bought = true;
+        }
+
+        ensure!(bought, Error::<T>::FixedPriceAmountTooSmall);
+
+        Ok(())
+    }
+
+    pub fn get_fixed_price_max_per_account(sale_id: &InvestmentId) -> Option<DeipAssetBalanceOf<T>> {
+        FixedPriceMaxPerAccount::<T>::get(sale_id)
+    }
+}
@@ -2,6 +2,7 @@ use crate::traits::DeipAssetSystem;
 use crate::*;
 use deip_assets_error::*;
 
+use frame_support::transactional;
 use sp_runtime::{
     traits::{Saturating, Zero},
     SaturatedConversion,
@@ -22,6 +23,10 @@ pub enum Status {
     Finished,
     Expired,
     Inactive,
+    /// Canceled by the creator before it finished
+    Canceled,
+    /// Settlement failed and awaits retry
+    SettlementPending,
 }
 
 impl Default for Status {
@@ -44,6 +49,40 @@ pub enum FundingModel<Moment, Asset> {
         /// amount upper limit of units to raise. Must be greater or equal to `soft_cap`.
         hard_cap: Asset,
     },
+    /// Same as `SimpleCrowdfunding`, but raised funds stay locked and are released
+    /// to the team by tranches as milestones are approved by investors.
+    MilestoneTranches {
+        start_time: Moment,
+        end_time: Moment,
+        soft_cap: Asset,
+        hard_cap: Asset,
+        /// the project milestones are reported in. Must belong to the creator.
+        project_id: ProjectId,
+        /// tranches in order of release. Shares must sum to 100%.
+        tranches: Vec<TrancheTerms<Moment>>,
+    },
+    /// Shares are sold at the fixed rate and transferred to the investor immediately.
+    /// The sale finishes once all shares are sold out, otherwise unsold shares
+    /// return to the creator when it expires.
+    FixedPrice {
+        start_time: Moment,
+        end_time: Moment,
+        /// amount of units to pay for all offered shares.
+        price: Asset,
+        /// amount upper limit of units an account can pay. Must be of the same asset as `price`.
+        max_per_account: Asset,
+    },
+    /// Price of all offered shares falls linearly from `start_price` to `floor_price`
+    /// between `start_time` and `end_time`. Bids claim shares at the current price and lock
+    /// funds. The auction is settled once all shares are claimed or when it ends: every bidder
+    /// pays the price of that moment and gets the excess back, unclaimed shares return to the creator.
+    DutchAuction {
+        start_time: Moment,
+        end_time: Moment,
+        start_price: Asset,
+        /// Must be of the same asset as `start_price` and not greater than it.
+        floor_price: Asset,
+    },
 }
 
 /// The object represents a sale of tokens with various parameters.
@@ -68,14 +107,28 @@ pub struct Info<Moment, AssetId, AssetBalance> {
 }
 
 impl<T: Config> Module<T> {
+    #[allow(clippy::too_many_arguments)]
     pub(super) fn create_investment_opportunity_impl(
         account: AccountIdOf<T>,
         external_id: Id,
         creator: AccountIdOf<T>,
         shares: Vec<DeipAssetOf<T>>,
         funding_model: FundingModelOf<T>,
+        vesting: Option<VestingScheduleOf<T>>,
+        allowlist: Option<AllowlistOf<T>>,
+        contribution_limits: Option<ContributionLimitsOf<T>>,
+        withdrawal: Option<WithdrawalTermsOf<T>>,
+        exchange_rates: Vec<ExchangeRateOf<T>>,
     ) -> DispatchResult {
         ensure!(account == creator, Error::<T>::NoPermission);
+        ensure!(
+            exchange_rates.is_empty() || matches!(funding_model, FundingModel::SimpleCrowdfunding { .. }),
+            Error::<T>::ExchangeRatesNotSupported
+        );
+        if let Some(ref schedule) = vesting {
+            ensure!(schedule.is_valid(), Error::<T>::VestingScheduleInvalid);
+        }
+        Self::ensure_investor_terms_valid(&allowlist, &contribution_limits)?;
 
         match funding_model {
             FundingModel::SimpleCrowdfunding {
@@ -91,10 +144,68 @@ impl<T: Config> Module<T> {
                 soft_cap,
                 hard_cap,
                 shares,
+                exchange_rates,
+            ),
+            FundingModel::MilestoneTranches {
+                start_time,
+                end_time,
+                soft_cap,
+                hard_cap,
+                project_id,
+                tranches,
+            } => Self::create_milestone_tranches(
+                account,
+                external_id,
+                start_time,
+                end_time,
+                soft_cap,
+                hard_cap,
+                shares,
+                project_id,
+                tranches,
+            ),
+            FundingModel::FixedPrice {
+                start_time,
+                end_time,
+                price,
+                max_per_account,
+            } => Self::create_fixed_price_sale(
+                account,
+                external_id,
+                start_time,
+                end_time,
+                price,
+                max_per_account,
+                shares,
             ),
+            FundingModel::DutchAuction {
+                start_time,
+                end_time,
+                start_price,
+                floor_price,
+            } => Self::create_dutch_auction(
+                account,
+                external_id,
+                start_time,
+                end_time,
+                start_price,
+                floor_price,
+                shares,
+            ),
+        }?;
+
+        if let Some(schedule) = vesting {
+            VestingScheduleMap::<T>::insert(external_id, schedule);
         }
+        Self::set_investor_terms(&creator, external_id, allowlist, contribution_limits)?;
+        if let Some(terms) = withdrawal {
+            WithdrawalTermsMap::<T>::insert(external_id, terms);
+        }
+
+        Ok(())
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub(super) fn create_simple_crowdfunding(
         account: T::AccountId,
         external_id: Id,
@@ -103,6 +214,7 @@ impl<T: Config> Module<T> {
         soft_cap: DeipAssetOf<T>,
         hard_cap: DeipAssetOf<T>,
         shares: Vec<DeipAssetOf<T>>,
+        exchange_rates: Vec<ExchangeRateOf<T>>,
     ) -> DispatchResult {
         let timestamp = pallet_timestamp::Module::<T>::get();
         ensure!(
@@ -147,14 +259,21 @@ impl<T: Config> Module<T> {
             shares_to_reserve.push((security_token.id, security_token.amount));
         }
 
+        Self::ensure_exchange_rates_valid(&exchange_rates, asset_id, &shares)?;
+        let other_assets: Vec<_> = exchange_rates.iter().map(|rate| rate.asset_id).collect();
+
         ensure!(
             !SimpleCrowdfundingMap::<T>::contains_key(external_id),
             Error::<T>::InvestmentOpportunityAlreadyExists
         );
 
-        if let Err(e) =
-            T::AssetSystem::transactionally_reserve(&account, external_id, &shares_to_reserve, asset_id)
-        {
+        if let Err(e) = T::AssetSystem::transactionally_reserve(
+            &account,
+            external_id,
+            &shares_to_reserve,
+            asset_id,
+            &other_assets,
+        ) {
             match e {
                 ReserveError::<DeipAssetIdOf<T>>::NotEnoughBalance => {
                     return Err(Error::<T>::InvestmentOpportunityBalanceIsNotEnough.into())
@@ -180,31 +299,37 @@ impl<T: Config> Module<T> {
         };
 
         SimpleCrowdfundingMap::<T>::insert(external_id, new_token_sale.clone());
+        Self::schedule_transition(start_time, external_id);
+        if !exchange_rates.is_empty() {
+            ExchangeRatesMap::<T>::insert(external_id, exchange_rates);
+        }
 
         Self::deposit_event(RawEvent::SimpleCrowdfundingCreated(external_id));
 
         Ok(())
     }
 
-    pub(super) fn collect_funds(sale_id: Id, amount: DeipAssetBalanceOf<T>) -> Result<(), ()> {
-        SimpleCrowdfundingMap::<T>::mutate_exists(sale_id, |sale| -> Result<(), ()> {
+    pub(super) fn collect_funds(sale_id: Id, amount: DeipAssetBalanceOf<T>) -> DispatchResult {
+        SimpleCrowdfundingMap::<T>::mutate_exists(sale_id, |sale| -> DispatchResult {
             match sale.as_mut() {
                 Some(s) => s.total_amount = amount.saturating_add(s.total_amount),
-                None => return Err(()),
+                None => return Err(Error::<T>::InvestmentOpportunityNotFound.into()),
             }
             Ok(())
         })
     }
 
-    pub(super) fn finish_crowdfunding_by_id(sale_id: Id) -> Result<(), ()> {
-        match SimpleCrowdfundingMap::<T>::try_get(sale_id) {
-            Err(_) => Err(()),
-            Ok(sale) => {
-                Self::update_status(&sale, Status::Finished);
-                Self::process_investments(&sale);
-                Ok(())
-            }
-        }
+    pub(super) fn finish_crowdfunding_by_id(sale_id: Id) -> DispatchResult {
+        SimpleCrowdfundingMap::<T>::mutate_exists(sale_id, |maybe_sale| -> DispatchResult {
+            let sale = match maybe_sale.as_mut() {
+                None => return Err(Error::<T>::InvestmentOpportunityNotFound.into()),
+                Some(s) => s,
+            };
+
+            Self::settle(sale, Status::Finished);
+
+            Ok(())
+        })
     }
 
     pub(super) fn activate_crowdfunding_impl(sale_id: Id) -> DispatchResult {
@@ -246,9 +371,29 @@ impl<T: Config> Module<T> {
                 _ => return Err(Error::<T>::InvestmentOpportunityShouldBeActive.into()),
             };
 
-            sale.status = Status::Expired;
+            Self::settle(sale, Status::Expired);
 
-            Self::refund(sale);
+            Ok(())
+        })
+    }
+
+    pub(super) fn cancel_investment_opportunity_impl(account: T::AccountId, sale_id: Id) -> DispatchResult {
+        SimpleCrowdfundingMap::<T>::mutate_exists(sale_id, |maybe_sale| -> DispatchResult {
+            let sale = match maybe_sale.as_mut() {
+                None => return Err(Error::<T>::InvestmentOpportunityNotFound.into()),
+                Some(s) => s,
+            };
+
+            ensure!(
+                matches!(sale.status, Status::Inactive | Status::Active),
+                Error::<T>::InvestmentOpportunityShouldBeInactiveOrActive
+            );
+            ensure!(
+                T::AssetSystem::get_investment_creator(&sale_id).as_ref() == Some(&account),
+                Error::<T>::NoPermission
+            );
+
+            Self::settle(sale, Status::Canceled);
 
             Ok(())
         })
@@ -267,78 +412,123 @@ impl<T: Config> Module<T> {
                 _ => return Err(Error::<T>::InvestmentOpportunityShouldBeActive.into()),
             };
 
-            sale.status = Status::Finished;
-
-            Self::process_investments(sale);
+            Self::settle(sale, Status::Finished);
 
             Ok(())
         })
     }
 
-    pub(super) fn process_investment_opportunities_offchain() {
-        let now = pallet_timestamp::Module::<T>::get();
-        for (id, sale) in SimpleCrowdfundingMap::<T>::iter() {
-            if sale.end_time <= now && matches!(sale.status, Status::Active) {
-                if sale.total_amount < sale.soft_cap {
-                    let call = Call::expire_crowdfunding(id);
-                    let submit =
-                        SubmitTransaction::<T, Call<T>>::submit_unsigned_transaction(call.into());
-                    debug!("submit expire_crowdfunding: {}", submit.is_ok());
-                } else if sale.total_amount >= sale.soft_cap {
-                    let call = Call::finish_crowdfunding(id);
-                    let submit =
-                        SubmitTransaction::<T, Call<T>>::submit_unsigned_transaction(call.into());
-                    debug!("submit finish_crowdfunding: {}", submit.is_ok());
-                }
-            } else if sale.end_time > now {
-                if now >= sale.start_time && matches!(sale.status, Status::Inactive) {
-                    let call = Call::activate_crowdfunding(id);
-                    let submit =
-                        SubmitTransaction::<T, Call<T>>::submit_unsigned_transaction(call.into());
-                    debug!("submit activate_crowdfunding: {}", submit.is_ok());
-                }
-            }
+    pub(super) fn retry_settlement_impl(sale_id: Id) -> DispatchResult {
+        SimpleCrowdfundingMap::<T>::mutate_exists(sale_id, |maybe_sale| -> DispatchResult {
+            let sale = match maybe_sale.as_mut() {
+                None => return Err(Error::<T>::InvestmentOpportunityNotFound.into()),
+                Some(s) => s,
+            };
+
+            ensure!(
+                matches!(sale.status, Status::SettlementPending),
+                Error::<T>::SettlementNotPending
+            );
+            let status = PendingSettlements::get(sale_id).ok_or(Error::<T>::SettlementNotPending)?;
+
+            Self::try_settle(sale, status)
+        })
+    }
+
+    /// Settles the sale that got `status`. If settlement fails its changes are reverted
+    /// and the sale stays `SettlementPending` until the settlement is retried.
+    fn settle(sale: &mut SimpleCrowdfundingOf<T>, status: Status) {
+        if matches!(status, Status::Finished) {
+            Self::fix_dutch_auction_clearing_price(sale);
+        }
+
+        if let Err(e) = Self::try_settle(sale, status) {
+            sale.status = Status::SettlementPending;
+            PendingSettlements::insert(sale.external_id, status);
+            Self::deposit_event(RawEvent::SettlementFailed(sale.external_id, e));
         }
     }
 
-    fn update_status(sale: &SimpleCrowdfundingOf<T>, new_status: Status) {
-        SimpleCrowdfundingMap::<T>::mutate_exists(sale.external_id, |maybe_sale| -> () {
-            let sale = maybe_sale.as_mut().expect("we keep collections in sync");
-            sale.status = new_status;
-        });
+    /// Distributes shares of the finished sale, refunds contributions otherwise
+    fn try_settle(sale: &mut SimpleCrowdfundingOf<T>, status: Status) -> DispatchResult {
+        match status {
+            Status::Finished => Self::process_investments(sale)?,
+            _ => Self::refund(sale)?,
+        };
+
+        sale.status = status;
+        PendingSettlements::remove(sale.external_id);
+
+        let event = match status {
+            Status::Finished => RawEvent::SimpleCrowdfundingFinished(sale.external_id),
+            Status::Expired => RawEvent::SimpleCrowdfundingExpired(sale.external_id),
+            _ => RawEvent::SimpleCrowdfundingCanceled(sale.external_id),
+        };
+        Self::deposit_event(event);
+
+        Ok(())
     }
 
-    fn refund(sale: &SimpleCrowdfundingOf<T>) {
+    #[transactional]
+    fn refund(sale: &SimpleCrowdfundingOf<T>) -> DispatchResult {
         if let Ok(ref c) = InvestmentMap::<T>::try_get(sale.external_id) {
-            for (_, ref contribution) in c {
-                T::AssetSystem::transfer_from_reserved(
-                    sale.external_id,
-                    &contribution.owner,
-                    sale.asset_id,
-                    contribution.amount,
-                )
-                .unwrap_or_else(|_| panic!("user's asset should be reserved earlier"));
+            // investors of the fixed price sale already got their shares and paid funds
+            // return to the creator along with unsold shares
+            if !FixedPriceMaxPerAccount::<T>::contains_key(sale.external_id) {
+                for (_, ref contribution) in c {
+                    for (asset_id, amount) in &contribution.assets {
+                        T::AssetSystem::transfer_from_reserved(
+                            sale.external_id,
+                            &contribution.owner,
+                            *asset_id,
+                            *amount,
+                        )
+                        .map_err(|_| Error::<T>::SettlementRefundFailed)?;
+                    }
+                }
             }
             InvestmentMap::<T>::remove(sale.external_id);
         }
+        if let Some(tranches) = MilestoneTranchesMap::<T>::take(sale.external_id) {
+            MilestoneTranchesIdByProjectId::remove(tranches.project_id, sale.external_id);
+        }
+        DutchAuctionBids::<T>::remove(sale.external_id);
+        // shares bought from the fixed price sale stay locked under the vesting schedule
+        if !FixedPriceMaxPerAccount::<T>::contains_key(sale.external_id) {
+            VestingScheduleMap::<T>::remove(sale.external_id);
+        }
+        WithdrawalTermsMap::<T>::remove(sale.external_id);
 
         T::AssetSystem::transactionally_unreserve(sale.external_id)
-            .unwrap_or_else(|_| panic!("assets should be reserved earlier"));
+            .map_err(|_| Error::<T>::SettlementUnreserveFailed)?;
 
-        Self::deposit_event(RawEvent::SimpleCrowdfundingExpired(sale.external_id));
+        Ok(())
     }
 
-    fn process_investments(sale: &SimpleCrowdfundingOf<T>) {
+    #[transactional]
+    fn process_investments(sale: &SimpleCrowdfundingOf<T>) -> DispatchResult {
         let contributions = InvestmentMap::<T>::try_get(sale.external_id)
-            .expect("about to finish, but there are no contributions?");
+            .map_err(|_| Error::<T>::SettlementNoContributions)?;
+
+        WithdrawalTermsMap::<T>::remove(sale.external_id);
+
+        let shares_to_distribute = if FixedPriceMaxPerAccount::<T>::contains_key(sale.external_id) {
+            // shares of the fixed price sale are transferred on investing
+            &[][..]
+        } else if let Some(terms) = DutchAuctionMap::<T>::get(sale.external_id) {
+            Self::settle_dutch_auction(sale, &terms)?;
+            &[][..]
+        } else {
+            &sale.shares[..]
+        };
 
-        for (asset_id, asset_amount) in &sale.shares {
+        for (asset_id, asset_amount) in shares_to_distribute {
             let mut amount = asset_amount.clone();
 
             let mut iter = contributions.iter();
             let (_, ref first_contribution) = iter
                 .next()
-                .expect("about to finish, but there are no contributors?");
+                .ok_or(Error::<T>::SettlementNoContributions)?;
 
             for (_, ref contribution) in iter {
                 // similiar to frame_support::traits::Imbalance::ration
@@ -354,31 +544,98 @@ impl<T: Config> Module<T> {
 
                 amount -= token_amount;
 
-                T::AssetSystem::transfer_from_reserved(
+                Self::transfer_shares(
                     sale.external_id,
                     &contribution.owner,
                     *asset_id,
                     token_amount,
                 )
-                .unwrap_or_else(|_| panic!("Required token_amount should be reserved"));
+                .map_err(|_| Error::<T>::SettlementSharesTransferFailed)?;
             }
 
             if !amount.is_zero() {
-                T::AssetSystem::transfer_from_reserved(
+                Self::transfer_shares(
                     sale.external_id,
                     &first_contribution.owner,
                     *asset_id,
                     amount,
                 )
-                .unwrap_or_else(|_| panic!("Required token_amount should be reserved"));
+                .map_err(|_| Error::<T>::SettlementSharesTransferFailed)?;
             }
         }
 
-        T::AssetSystem::transactionally_unreserve(sale.external_id)
-            .unwrap_or_else(|_| panic!("remaining assets should be reserved earlier"));
+        if MilestoneTranchesMap::<T>::contains_key(sale.external_id) {
+            // raised funds are released to the team by milestones, contributions
+            // are kept to weigh votes of investors
+            Self::lock_tranches(sale);
+        } else {
+            T::AssetSystem::transactionally_unreserve(sale.external_id)
+                .map_err(|_| Error::<T>::SettlementUnreserveFailed)?;
+
+            InvestmentMap::<T>::remove(sale.external_id);
+        }
 
-        InvestmentMap::<T>::remove(sale.external_id);
+        Ok(())
+    }
 
-        Self::deposit_event(RawEvent::SimpleCrowdfundingFinished(sale.external_id));
+    /// Allocates claimed shares to bidders at the current price of the auction
+    /// and returns the excess of locked funds
+    fn settle_dutch_auction(
+        sale: &SimpleCrowdfundingOf<T>,
+        terms: &DutchAuctionTermsOf<T>,
+    ) -> DispatchResult {
+        let clearing_price = DutchAuctionClearingPrice::<T>::get(sale.external_id)
+            .unwrap_or_else(|| Self::dutch_auction_price(sale, terms));
+        Self::deposit_event(RawEvent::DutchAuctionCleared(
+            sale.external_id,
+            DeipAsset::new(sale.asset_id, clearing_price),
+        ));
+
+        let mut unclaimed = clearing_price;
+        for bid in DutchAuctionBids::<T>::take(sale.external_id) {
+            let paid = Self::dutch_auction_payment(&bid, clearing_price)
+                .min(bid.amount)
+                .min(unclaimed);
+            unclaimed -= paid;
+
+            for (asset_id, asset_amount) in &sale.shares {
+                let token_amount: DeipAssetBalanceOf<T> = (paid
+                    .saturated_into::<u128>()
+                    .saturating_mul(asset_amount.clone().saturated_into())
+                    / clearing_price.saturated_into::<u128>())
+                .saturated_into();
+                if token_amount.is_zero() {
+                    continue;
+                }
+
+                Self::transfer_shares(
+                    sale.external_id,
+                    &bid.bidder,
+                    *asset_id,
+                    token_amount,
+                )
+                .map_err(|_| Error::<T>::SettlementSharesTransferFailed)?;
+            }
+
+            let refunded = bid.amount - paid;
+            if !refunded.is_zero() {
+                T::AssetSystem::transfer_from_reserved(
+                    sale.external_id,
+                    &bid.bidder,
+                    sale.asset_id,
+                    refunded,
+                )
+                .map_err(|_| Error::<T>::SettlementRefundFailed)?;
+            }
+
+            Self::deposit_event(RawEvent::DutchAuctionAllocated(
+                sale.external_id,
+                bid.bidder,
+                DeipAsset::new(sale.asset_id, paid),
+                DeipAsset::new(sale.asset_id, refunded),
+            ));
+        }
+
+        Ok(())
     }
 }
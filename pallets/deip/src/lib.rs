@@ -21,11 +21,32 @@
 //! ### Dispatchable Functions
 //!
 //! * `add_domain` - Add cryptographic hash of DomainId
+//! * `deprecate_domain` - Forbid using Domain by new Projects and Reviews
+//! * `merge_domains` - Move Projects, Reviews and child Domains of one Domain to another
+//! * `remove_domain` - Remove unused Domain
 //! * `create_project` - Create Project belongs to Account (Team)
 //! * [`create_investment_opportunity`](./enum.Call.html#variant.create_investment_opportunity)
+//! * `cancel_investment_opportunity` - Creator cancels the investment opportunity and refunds contributions
+//! * `retry_settlement` - Retries settlement of the investment opportunity that failed
 //! * [`invest`](./enum.Call.html#variant.invest)
+//! * `withdraw_investment` - Investor takes back its contribution from the active investment opportunity
+//! * `claim_vested` - Unlock security tokens released by the vesting schedule of the investment opportunity
+//! * `create_allowlist` - Create a list of investors allowed to invest to investment opportunities
+//! * `update_allowlist` - Add and remove investors of the allowlist
+//! * `update_investment_allowlist` - Add and remove investors of the allowlist of the investment opportunity
+//! * `submit_milestone` - Report the milestone of the current tranche with Project Content
+//! * `refund_missed_milestone` - Return locked funds to investors once the milestone deadline passed
+//! * `vote_milestone` - Investor approves the reported milestone or takes back its part of locked funds
 //! * `update_project` - Update Project info
+//! * `remove_project` - Remove Project that has no security tokens and NDAs
+//! * `transfer_project` - Hand Project over to another Account (Team)
+//! * `accept_project_transfer` - New Team accepts the Project handed over to it
 //! * `create_project_content` - Create Project Content (Digital Asset)
+//! * `amend_project_content` - Amend Project Content keeping the previous version in history
+//! * `set_project_content_author_shares` - Set contribution shares of Project Content authors
+//! * `distribute_project_content_revenue` - Split a payment across Project Content authors by their shares
+//! * `create_licence_offer` - Offer licences to use Project Content for a price
+//! * `purchase_licence` - Purchase a licence to use Project Content paying the price to the Team
 //! * `create_project_nda` - Create NDA contract between sides
 //! * `create_nda_content_access_request` - Some side request access to the data of contract
 //! * `fulfill_nda_content_access_request` - Granter fulfill access request to the data
@@ -45,7 +66,7 @@ use frame_support::{
     StorageMap,
     dispatch::{ DispatchResult, Parameter },
     storage::{ IterableStorageMap, IterableStorageDoubleMap },
-    traits::{Currency, ReservableCurrency},
+    traits::{Currency, ReservableCurrency, EnsureOrigin},
     debug::debug,
     pallet_prelude::*,
 };
@@ -65,7 +86,7 @@ mod mock;
 mod tests;
 
 mod investment_opportunity;
-use investment_opportunity::{
+pub use investment_opportunity::{
     Status as SimpleCrowdfundingStatus,
     Info as SimpleCrowdfunding};
 pub use investment_opportunity::{
@@ -74,7 +95,73 @@ pub use investment_opportunity::{
 };
 
 mod contribution;
-use contribution::{Contribution as Investment};
+pub use contribution::{Contribution as Investment};
+
+mod tranche;
+pub use tranche::{
+    Terms as TrancheTerms,
+    Status as TrancheStatus,
+    Tranche,
+    Tranches as MilestoneTranches,
+};
+
+mod fixed_price;
+
+mod allowlist;
+pub use allowlist::{
+    Id as AllowlistId,
+    Allowlist as InvestmentAllowlist,
+    ContributionLimits,
+};
+
+mod vesting;
+pub use vesting::{
+    Schedule as VestingSchedule,
+    Lock as VestingLock,
+    Balance as VestingBalance,
+};
+
+mod schedule;
+pub use schedule::DueMoment;
+
+mod exchange_rate;
+pub use exchange_rate::ExchangeRate;
+
+mod withdrawal;
+pub use withdrawal::Terms as WithdrawalTerms;
+
+mod dutch_auction;
+pub use dutch_auction::{
+    Terms as DutchAuctionTerms,
+    Bid as DutchAuctionBid,
+};
+
+mod project;
+pub use project::RemovalWitness as ProjectRemovalWitness;
+
+mod project_content;
+pub use project_content::{
+    Version as ProjectContentVersion,
+    Revision as ProjectContentRevision,
+    AuthorShare, AUTHOR_SHARES_TOTAL,
+    MAX_CITATION_DEPTH,
+};
+
+mod licence;
+pub use licence::{
+    Id as LicenceOfferId,
+    Offer as LicenceOffer,
+    Licence,
+};
+
+mod domain;
+pub use domain::MergeWitness as DomainMergeWitness;
+
+mod migrations;
+pub use migrations::Releases;
+
+mod query;
+pub use query::{ProjectFilter, ProjectContentFilter, ReviewFilter, InvestmentOpportunityFilter, MAX_PAGE_SIZE};
 
 mod review;
 pub use review::{Id as ReviewId, Review as Review};
@@ -85,9 +172,6 @@ pub use asset::Asset as DeipAsset;
 
 pub mod traits;
 
-/// A maximum number of Domains. When domains reaches this number, no new domains can be added.
-pub const MAX_DOMAINS: u32 = 100;
-
 const NON_LOCAL: u8 = 100;
 
 /// Possible statuses of Project inherited from Project Content type
@@ -132,6 +216,24 @@ pub trait Config: frame_system::Config + pallet_timestamp::Config + SendTransact
     type Currency: ReservableCurrency<Self::AccountId>;
 
     type AssetSystem: traits::DeipAssetSystem<Self::AccountId>;
+
+    /// A maximum number of Domains. When domains reaches this number, no new domains can be added.
+    type MaxDomains: Get<u32>;
+
+    /// The origin which may add, deprecate, merge and remove domains.
+    type DomainAdminOrigin: EnsureOrigin<Self::Origin>;
+
+    /// A maximum number of scheduled transitions of investment opportunities performed in a block.
+    /// The rest are performed in next blocks.
+    type MaxTransitionsPerBlock: Get<u32>;
+
+    /// A maximum weight of scheduled transitions performed in a block.
+    /// Transitions that don't fit it are performed in next blocks.
+    type MaxScheduledWeight: Get<Weight>;
+
+    /// A maximum number of bids of a dutch auction. Every bid locks at least the matching
+    /// part of the current price, so the auction sells out by this number of bids.
+    type MaxDutchAuctionBids: Get<u32>;
 }
 
 /// Unique Project ID reference
@@ -152,9 +254,22 @@ pub type ReviewOf<T> = Review<<T as system::Config>::Hash, AccountIdOf<T>>;
 pub type NdaOf<T> = Nda<<T as system::Config>::Hash, AccountIdOf<T>, MomentOf<T>>;
 pub type NdaAccessRequestOf<T> = NdaAccessRequest<<T as system::Config>::Hash, AccountIdOf<T>>;
 pub type ProjectContentOf<T> = ProjectContent<<T as system::Config>::Hash, AccountIdOf<T>>;
+pub type ProjectContentRevisionOf<T> = ProjectContentRevision<<T as system::Config>::Hash, AccountIdOf<T>>;
+pub type LicenceOfferOf<T> = LicenceOffer<<T as system::Config>::Hash, AccountIdOf<T>, MomentOf<T>, DeipAssetOf<T>>;
+pub type LicenceOf<T> = Licence<AccountIdOf<T>, MomentOf<T>>;
 pub type SimpleCrowdfundingOf<T> = SimpleCrowdfunding<MomentOf<T>, DeipAssetIdOf<T>, DeipAssetBalanceOf<T>>;
 pub type BalanceOf<T> = <<T as Config>::Currency as Currency<AccountIdOf<T>>>::Balance;
-pub type InvestmentOf<T> = Investment<AccountIdOf<T>, DeipAssetBalanceOf<T>, MomentOf<T>>;
+pub type InvestmentOf<T> = Investment<AccountIdOf<T>, DeipAssetIdOf<T>, DeipAssetBalanceOf<T>, MomentOf<T>>;
+pub type TranchesOf<T> = MilestoneTranches<AccountIdOf<T>, DeipAssetBalanceOf<T>, MomentOf<T>>;
+pub type AllowlistOf<T> = InvestmentAllowlist<AccountIdOf<T>>;
+pub type ContributionLimitsOf<T> = ContributionLimits<DeipAssetBalanceOf<T>>;
+pub type VestingScheduleOf<T> = VestingSchedule<MomentOf<T>>;
+pub type WithdrawalTermsOf<T> = WithdrawalTerms<MomentOf<T>>;
+pub type ExchangeRateOf<T> = ExchangeRate<DeipAssetIdOf<T>, DeipAssetBalanceOf<T>>;
+pub type VestingLockOf<T> = VestingLock<DeipAssetIdOf<T>, DeipAssetBalanceOf<T>>;
+pub type VestingBalanceOf<T> = VestingBalance<DeipAssetIdOf<T>, DeipAssetBalanceOf<T>>;
+pub type DutchAuctionTermsOf<T> = DutchAuctionTerms<DeipAssetBalanceOf<T>>;
+pub type DutchAuctionBidOf<T> = DutchAuctionBid<AccountIdOf<T>, DeipAssetBalanceOf<T>>;
 pub type DeipAssetIdOf<T> = <<T as Config>::AssetSystem as traits::DeipAssetSystem<AccountIdOf<T>>>::AssetId;
 pub type DeipAssetBalanceOf<T> = <<T as Config>::AssetSystem as traits::DeipAssetSystem<AccountIdOf<T>>>::Balance;
 pub type DeipAssetOf<T> = DeipAsset<DeipAssetIdOf<T>, DeipAssetBalanceOf<T>>;
@@ -167,6 +282,12 @@ type DeipReviewVoteOf<T> = DeipReviewVote<AccountIdOf<T>, MomentOf<T>>;
 pub struct Domain {
     /// Reference for external world and uniques control 
     pub external_id: DomainId,
+    /// Reference to the parent Domain. Top level Domains have no parent
+    pub parent_id: Option<DomainId>,
+    /// Hash of the Domain name
+    pub name: H256,
+    /// Hash of the Domain metadata
+    pub metadata: H256,
 }
 
 /// Core entity of pallet. Everything connected to Project. 
@@ -276,6 +397,7 @@ decl_event! {
         AccountId = <T as frame_system::Config>::AccountId,
         Project = ProjectOf<T>,
         Review = ReviewOf<T>,
+        DeipAsset = DeipAssetOf<T>,
     {
         // ==== Projects ====
 
@@ -285,11 +407,30 @@ decl_event! {
         ProjectRemoved(AccountId, Project),
         /// Event emitted when a project is removed by the owner. [BelongsTo, ProjectId]
         ProjectUpdated(AccountId, ProjectId),
+        /// Event emitted when a project handover awaits acceptance by the new team. [BelongsTo, ProjectId, NewTeam]
+        ProjectTransferRequested(AccountId, ProjectId, AccountId),
+        /// Event emitted when a project has been handed over to the new team. [OldTeam, ProjectId, NewTeam]
+        ProjectTransferred(AccountId, ProjectId, AccountId),
 
         // ==== Project Content ====
        
         /// Event emitted when a project contnet has been created. [BelongsTo, ProjectContentId]
         ProjectContnetCreated(AccountId, ProjectContentId),
+        /// Event emitted when a project content has been amended. [BelongsTo, ProjectContentId, ProjectContentVersion]
+        ProjectContentAmended(AccountId, ProjectContentId, ProjectContentVersion),
+        /// Event emitted when contribution shares of the content authors have been set. [BelongsTo, ProjectContentId]
+        ProjectContentAuthorSharesSet(AccountId, ProjectContentId),
+        /// Event emitted when an amendment changed the content authors and their shares were dropped. [BelongsTo, ProjectContentId]
+        ProjectContentAuthorSharesReset(AccountId, ProjectContentId),
+        /// Event emitted when a payment has been split across the content authors. [Payer, ProjectContentId, DeipAsset]
+        ProjectContentRevenueDistributed(AccountId, ProjectContentId, DeipAsset),
+
+        // ==== Licences ====
+
+        /// Event emitted when a licence offer has been created. [BelongsTo, LicenceOfferId]
+        LicenceOfferCreated(AccountId, LicenceOfferId),
+        /// Event emitted when a licence has been purchased. [Holder, LicenceOfferId]
+        LicencePurchased(AccountId, LicenceOfferId),
 
         // ==== NDA ====
        
@@ -302,8 +443,14 @@ decl_event! {
         //  /// Event emitted when a NDA Access request has been rejected. [BelongsTo, NdaAccessRequestId]
         NdaAccessRequestRejected(AccountId, NdaAccessRequestId),
 
-        /// Added a domain. [Creator, DomainId]
-        DomainAdded(AccountId, DomainId),
+        /// Added a domain. The creator is known if the domain admin is a signed origin. [Creator, DomainId]
+        DomainAdded(Option<AccountId>, DomainId),
+        /// A domain has been deprecated and can't be used by new projects and reviews. [DomainId]
+        DomainDeprecated(DomainId),
+        /// A domain has been merged into another one and removed. [From, Into]
+        DomainsMerged(DomainId, DomainId),
+        /// A domain has been removed. [DomainId]
+        DomainRemoved(DomainId),
 
         /// Event emitted when a review has been created. [BelongsTo, Review]
         ReviewCreated(AccountId, Review),
@@ -318,8 +465,35 @@ decl_event! {
         SimpleCrowdfundingFinished(InvestmentId),
         /// Event emitted when a simple crowd funding has expired.
         SimpleCrowdfundingExpired(InvestmentId),
+        /// Event emitted when a simple crowd funding has been canceled by its creator.
+        SimpleCrowdfundingCanceled(InvestmentId),
+        /// Event emitted when a simple crowd funding couldn't be settled and awaits retry. [InvestmentId, Error]
+        SettlementFailed(InvestmentId, DispatchError),
         /// Event emitted when DAO invested to an opportunity
         Invested(InvestmentId, AccountId),
+        /// Event emitted when the investor withdrew its contribution from the active opportunity. [InvestmentId, AccountId, Withdrawn]
+        InvestmentWithdrawn(InvestmentId, AccountId, DeipAsset),
+        /// Event emitted when the team reported the milestone of the current tranche. [InvestmentId, ProjectContentId]
+        MilestoneSubmitted(InvestmentId, ProjectContentId),
+        /// Event emitted when investors approved the milestone and the tranche went to the team. [InvestmentId, Released]
+        MilestoneTrancheReleased(InvestmentId, DeipAsset),
+        /// Event emitted when an investor voted to stop funding and got its part of locked funds back. [InvestmentId, Investor, Refunded]
+        MilestoneTrancheRefunded(InvestmentId, AccountId, DeipAsset),
+        /// Event emitted when the team missed the milestone deadline and locked funds were returned to investors. [InvestmentId]
+        MilestoneDeadlineMissed(InvestmentId),
+        /// Event emitted when the dutch auction was settled at the price of all offered shares. [InvestmentId, ClearingPrice]
+        DutchAuctionCleared(InvestmentId, DeipAsset),
+        /// Event emitted when the bidder paid for allocated shares at the clearing price. [InvestmentId, Bidder, Paid, Refunded]
+        DutchAuctionAllocated(InvestmentId, AccountId, DeipAsset, DeipAsset),
+        /// Event emitted when the investor unlocked security tokens released by the vesting schedule. [InvestmentId, Investor, Unlocked]
+        VestedClaimed(InvestmentId, AccountId, DeipAsset),
+        /// Event emitted when an allowlist of investors has been created. [AllowlistId, Owner]
+        AllowlistCreated(AllowlistId, AccountId),
+        /// Event emitted when investors were added to or removed from the allowlist. [AllowlistId]
+        AllowlistUpdated(AllowlistId),
+        /// Event emitted when investors were added to or removed from the allowlist
+        /// of the investment opportunity. [InvestmentId]
+        InvestmentAllowlistUpdated(InvestmentId),
     }
 }
 
@@ -336,6 +510,16 @@ decl_error! {
         DomainNotExists,
         /// Cannot add a project because a project with this ID is already a exists
         ProjectAlreadyExists,
+        /// Cannot remove a project because security tokens are issued for it
+        ProjectHasSecurityTokens,
+        /// Cannot remove a project because it is involved in a NDA
+        ProjectInvolvedInNda,
+        /// Numbers of entities removed along with the project are less than the actual ones
+        ProjectRemovalWitnessInvalid,
+        /// The project already belongs to the team
+        ProjectTransferToSameTeam,
+        /// There is no pending handover of the project
+        NoSuchProjectTransferRequest,
 
         // ==== Project Content ====
        
@@ -349,7 +533,29 @@ decl_error! {
         NoSuchReference, 
         /// Cannot add a project content because a project with this ID is already a finished
         ProjectAlreadyFinished,
-
+        /// The project content can't be amended anymore
+        ProjectContentVersionLimitReached,
+        /// Author shares should be specified once for every author of the content
+        ProjectContentAuthorSharesMustMatchAuthors,
+        /// Author shares should sum to 100% (10000 basis points)
+        ProjectContentAuthorSharesMustSumToTotal,
+        /// The project content has no authors to distribute revenue to
+        ProjectContentHasNoAuthors,
+        /// Distributed revenue should be greater than zero
+        ProjectContentRevenueMustBePositive,
+        /// Failed to transfer revenue to the content authors
+        ProjectContentRevenueTransferFailed,
+
+        // ==== Licences ====
+
+        /// Cannot add a licence offer because a licence offer with this ID already exists
+        LicenceOfferAlreadyExists,
+        /// The licence offer does not exist
+        NoSuchLicenceOffer,
+        /// Licence duration should be greater than zero
+        LicenceOfferDurationMustBePositive,
+        /// Failed to pay for the licence
+        LicencePaymentFailed,
 
         // ==== Domains ====
         
@@ -357,6 +563,20 @@ decl_error! {
         DomianLimitReached,
         /// Cannot add domain because this domain is already a exists
         DomainAlreadyExists,
+        /// Cannot add domain because its parent domain does not exist
+        NoSuchParentDomain,
+        /// The domain is deprecated and can't be used anymore
+        DomainIsDeprecated,
+        /// The domain is already deprecated
+        DomainAlreadyDeprecated,
+        /// Cannot merge a domain into itself or its descendant
+        DomainMergeIntoDescendant,
+        /// Numbers of entities moved from the merged domain are less than the actual ones
+        DomainMergeWitnessInvalid,
+        /// Cannot remove a domain because it has child domains
+        DomainHasChildren,
+        /// Cannot remove a domain because projects or reviews are tagged with it
+        DomainInUse,
 
         // ==== NDA ====
         
@@ -409,6 +629,7 @@ decl_error! {
         InvestmentOpportunityShouldBeInactive,
         InvestmentOpportunityShouldBeStarted,
         InvestmentOpportunityShouldBeActive,
+        InvestmentOpportunityShouldBeInactiveOrActive,
         InvestmentOpportunityExpirationWrongState,
         InvestmentOpportunityWrongAssetId,
         InvestmentOpportunityCapDifferentAssets,
@@ -418,6 +639,124 @@ decl_error! {
         InvestingNotActive,
         InvestingNotEnoughFunds,
         InvestingWrongAsset,
+
+        // ==== Settlement ====
+
+        /// The finished investment opportunity has no contributions to distribute shares by
+        SettlementNoContributions,
+        /// Failed to return reserved contributions to investors
+        SettlementRefundFailed,
+        /// Failed to transfer reserved shares to investors
+        SettlementSharesTransferFailed,
+        /// Failed to return remaining reserved assets to the creator
+        SettlementUnreserveFailed,
+        /// The investment opportunity has no failed settlement to retry
+        SettlementNotPending,
+
+        // ==== Milestone tranches ====
+
+        /// Milestone tranches funding model requires at least one tranche
+        MilestoneTranchesNotSpecified,
+        /// Every tranche should release some part of funds
+        MilestoneTrancheShareMustBePositive,
+        /// Tranche shares should sum to 100%
+        MilestoneTranchesMustSumToWhole,
+        /// The investment opportunity isn't funded by milestone tranches
+        NoSuchMilestoneTranches,
+        /// Milestones can be reported only after the sale has finished
+        MilestoneTranchesNotFunded,
+        /// All tranches are already released
+        MilestoneTranchesAlreadyReleased,
+        /// The milestone of the current tranche is already reported
+        MilestoneAlreadySubmitted,
+        /// Project Content type doesn't match the milestone of the current tranche
+        MilestoneTypeMismatch,
+        /// There is no reported milestone to vote for
+        MilestoneNotUnderReview,
+        /// Only investors of the sale can vote for milestones
+        MilestoneVoterNotInvestor,
+        /// The investor already approved the milestone
+        MilestoneAlreadyApproved,
+        /// Failed to transfer locked funds
+        MilestoneTrancheTransferFailed,
+        /// Milestone deadlines should follow the end of the sale in order of tranches
+        MilestoneDeadlinesInvalid,
+        /// Locked funds can be refunded only after the milestone deadline
+        MilestoneDeadlineNotPassed,
+        /// The number of investors to be refunded is less than the actual one
+        MilestoneInvestorsWitnessInvalid,
+
+        // ==== Fixed price ====
+
+        /// Fixed price sale should allow an account to buy some shares
+        FixedPriceMaxPerAccountMustBePositive,
+        /// The account can't buy more shares of the fixed price sale
+        FixedPriceMaxPerAccountExceeded,
+        /// The amount doesn't buy any shares at the fixed price
+        FixedPriceAmountTooSmall,
+        /// Failed to transfer bought shares
+        FixedPriceSharesTransferFailed,
+
+        // ==== Dutch auction ====
+
+        /// The auction can't end at zero price
+        DutchAuctionFloorPriceMustBePositive,
+        /// The price can only fall during the auction
+        DutchAuctionStartPriceMustBeGreaterOrEqualFloorPrice,
+        /// All shares are claimed by previous bids
+        DutchAuctionSoldOut,
+        /// The bid locks less than the part of the current price per the maximum number of bids
+        DutchAuctionBidTooSmall,
+        /// The auction reached the maximum number of bids
+        DutchAuctionBidsLimitReached,
+
+        // ==== Vesting ====
+
+        /// Vesting should end after it starts and the cliff should be between them
+        VestingScheduleInvalid,
+        /// The investment opportunity has no vesting schedule
+        VestingScheduleNotFound,
+        /// No locked tokens are released since the last claim
+        VestingNothingToClaim,
+
+        // ==== Exchange rates ====
+
+        /// Only simple crowdfunding accepts several assets
+        ExchangeRatesNotSupported,
+        /// Amount and value of the exchange rate should be positive
+        ExchangeRateMustBePositive,
+        /// Exchange rate of the asset is specified more than once
+        ExchangeRateDuplicateAsset,
+
+        // ==== Withdrawals ====
+
+        /// Contributions to fixed price sales, dutch auctions and sales accepting several assets can't be withdrawn
+        WithdrawalNotSupported,
+        /// Amount to withdraw should be positive
+        WithdrawalAmountMustBePositive,
+        /// The account has no contribution to the investment opportunity
+        WithdrawalNoContribution,
+        /// Amount to withdraw is greater than the contribution
+        WithdrawalAmountExceedsContribution,
+        /// The contribution can't be withdrawn until the cooldown of the investment opportunity passes
+        WithdrawalCooldownNotPassed,
+        /// Failed to transfer withdrawn funds
+        WithdrawalTransferFailed,
+
+        // ==== Allowlists ====
+
+        /// Allowlist with the id already exists
+        AllowlistAlreadyExists,
+        /// Allowlist not found
+        NoSuchAllowlist,
+        /// Maximum contribution should be positive and not less than minimum
+        ContributionLimitsInvalid,
+        /// The investor isn't in the allowlist of the investment opportunity
+        InvestorNotAllowlisted,
+        /// The contribution is less than the minimum of the investment opportunity
+        ContributionBelowMinimum,
+        /// Total contribution of the investor would exceed the maximum of the investment opportunity
+        ContributionAboveMaximum,
     }
 }
 
@@ -425,33 +764,101 @@ decl_storage! {
     trait Store for Module<T: Config> as Deip {
         /// Map from ProjectID to Project Info
         ProjectMap get(fn project): map hasher(identity) ProjectId => ProjectOf<T>;
-        /// Project list, guarantees uniquest and provides Project listing
-        Projects get(fn projects): Vec<(ProjectId, T::AccountId)>;
+        /// Projects of the Team
+        ProjectIdByTeamId: double_map hasher(blake2_128_concat) T::AccountId, hasher(identity) ProjectId => ();
+        /// Projects tagged with the Domain
+        ProjectIdByDomainId: double_map hasher(blake2_128_concat) DomainId, hasher(identity) ProjectId => ();
+        /// Pending project handovers that await acceptance by the new team
+        ProjectTransferRequests get(fn project_transfer_request): map hasher(identity) ProjectId => Option<T::AccountId>;
 
         SimpleCrowdfundingMap: map hasher(identity) InvestmentId => SimpleCrowdfundingOf<T>;
 
+        /// Status the investment opportunity gets once its failed settlement succeeds
+        PendingSettlements: map hasher(identity) InvestmentId => Option<SimpleCrowdfundingStatus>;
+
         /// Contains various contributions from DAOs
         InvestmentMap: map hasher(identity) InvestmentId => Vec<(T::AccountId, InvestmentOf<T>)>;
+        /// Sales the account has ever invested in
+        InvestmentIdByInvestor: double_map hasher(blake2_128_concat) T::AccountId, hasher(identity) InvestmentId => ();
+        /// Raised funds released to the team by milestones
+        MilestoneTranchesMap: map hasher(identity) InvestmentId => Option<TranchesOf<T>>;
+        /// Sales funded by milestone tranches of the project
+        MilestoneTranchesIdByProjectId: double_map hasher(identity) ProjectId, hasher(identity) InvestmentId => ();
+        /// Investors approved the milestone of the current tranche
+        MilestoneApprovals: double_map hasher(identity) InvestmentId, hasher(blake2_128_concat) T::AccountId => ();
+        /// Amount an account can pay to the fixed price sale
+        FixedPriceMaxPerAccount: map hasher(identity) InvestmentId => Option<DeipAssetBalanceOf<T>>;
+        /// Prices of the dutch auction
+        DutchAuctionMap: map hasher(identity) InvestmentId => Option<DutchAuctionTermsOf<T>>;
+        /// Bids of the dutch auction in order of placing. They are kept until the auction is settled
+        DutchAuctionBids: map hasher(identity) InvestmentId => Vec<DutchAuctionBidOf<T>>;
+        /// Price of all offered shares fixed when the dutch auction ended
+        DutchAuctionClearingPrice: map hasher(identity) InvestmentId => Option<DeipAssetBalanceOf<T>>;
+        /// Release of security tokens distributed by the investment opportunity
+        VestingScheduleMap: map hasher(identity) InvestmentId => Option<VestingScheduleOf<T>>;
+        /// Activation, expiration and finishing of investment opportunities by the moment they are due
+        ScheduledTransitions: double_map hasher(identity) DueMoment, hasher(identity) InvestmentId => ();
+        /// Other assets the investment opportunity accepts along with the one it's capped in
+        ExchangeRatesMap: map hasher(identity) InvestmentId => Vec<ExchangeRateOf<T>>;
+        /// Conditions of withdrawal of contributions from the active investment opportunity
+        WithdrawalTermsMap: map hasher(identity) InvestmentId => Option<WithdrawalTermsOf<T>>;
+        /// Security tokens the account got from the investment opportunity that aren't claimed yet
+        VestingLocks: double_map hasher(blake2_128_concat) T::AccountId, hasher(identity) InvestmentId => Vec<VestingLockOf<T>>;
+        /// Allowlists of investors and their owners
+        Allowlists: map hasher(identity) AllowlistId => Option<T::AccountId>;
+        AllowlistMembers: double_map hasher(identity) AllowlistId, hasher(blake2_128_concat) T::AccountId => ();
+        /// Shared allowlist of investors the investment opportunity refers to
+        InvestmentAllowlistMap: map hasher(identity) InvestmentId => Option<AllowlistId>;
+        /// Owners of allowlists listed for investment opportunities
+        InvestmentAllowlistOwners: map hasher(identity) InvestmentId => Option<T::AccountId>;
+        InvestmentAllowlistMembers: double_map hasher(identity) InvestmentId, hasher(blake2_128_concat) T::AccountId => ();
+        /// Bounds of the total contribution of an investor of the investment opportunity
+        InvestmentContributionLimits: map hasher(identity) InvestmentId => Option<ContributionLimitsOf<T>>;
 
         /// Map to Project Content Info
         ProjectContentMap get(fn project_content_entity): double_map hasher(identity) ProjectId, hasher(identity) ProjectContentId => ProjectContentOf<T>;
-        /// Project Content list, guarantees uniquest and provides Project Conent listing
-        ProjectsContent get(fn project_content_list): Vec<(ProjectContentId, ProjectId, T::AccountId)>;
+        /// Project the Project Content belongs to
+        ProjectIdByProjectContentId: map hasher(identity) ProjectContentId => Option<ProjectId>;
+        /// Project Content created by the Team
+        ProjectContentIdByTeamId: double_map hasher(blake2_128_concat) T::AccountId, hasher(identity) ProjectContentId => ();
+        /// Project Content of the type
+        ProjectContentIdByType: double_map hasher(blake2_128_concat) ProjectContentType, hasher(identity) ProjectContentId => ();
+        /// Project Content citing the key content, i.e. the reverse of `references`
+        ProjectContentCitedBy get(fn project_content_cited_by): map hasher(identity) ProjectContentId => Vec<ProjectContentId>;
+        /// Current version of Project Content
+        ProjectContentVersionMap get(fn project_content_version): map hasher(identity) ProjectContentId => ProjectContentVersion;
+        /// Contribution shares of Project Content authors in basis points
+        ProjectContentAuthorShares: map hasher(identity) ProjectContentId => Vec<(T::AccountId, AuthorShare)>;
+        /// Previous versions of amended Project Content
+        ProjectContentRevisionMap: double_map hasher(identity) ProjectContentId, hasher(twox_64_concat) ProjectContentVersion => ProjectContentRevisionOf<T>;
+
+        /// Licence offers of Project Content
+        LicenceOfferMap get(fn licence_offer): map hasher(identity) LicenceOfferId => Option<LicenceOfferOf<T>>;
+        /// Licence offers of Project Content of the Project
+        LicenceOfferIdByProjectId: double_map hasher(identity) ProjectId, hasher(identity) LicenceOfferId => ();
+        /// Licences to use Project Content by holder
+        LicenceMap: double_map hasher(identity) ProjectContentId, hasher(blake2_128_concat) T::AccountId => Option<LicenceOf<T>>;
 
-        /// NDA list, guarantees uniquest and provides NDA listing
-        Ndas get(fn nda_list): Vec<(ProjectId, T::AccountId)>;
         /// Map to NDA Info
         NdaMap get(fn nda): map hasher(identity) NdaId => NdaOf<T>;
+        /// NDAs created by the account
+        NdaIdByCreator: double_map hasher(blake2_128_concat) T::AccountId, hasher(identity) NdaId => ();
+        /// NDAs the Project is involved in
+        NdaIdByProjectId: double_map hasher(identity) ProjectId, hasher(identity) NdaId => ();
         
-        /// NDA Access Requests list, guarantees uniquest and provides NDA Access Requests listing
-        NdaAccessRequests get(fn nda_requests): Vec<(NdaAccessRequestId, NdaId, T::AccountId)>;
         /// Map to NDA Access Requests Info
         NdaAccessRequestMap get(fn nda_request): map hasher(identity) NdaAccessRequestId => NdaAccessRequestOf<T>;
+        /// Access Requests to the NDA content
+        NdaAccessRequestIdByNdaId: double_map hasher(identity) NdaId, hasher(identity) NdaAccessRequestId => ();
 
         /// Map from ReviewID to Review Info
         ReviewMap get(fn review): map hasher(identity) ReviewId => ReviewOf<T>;
-        /// Review list, guarantees uniquest and provides Review listing
-        Reviews get(fn reviews): Vec<(ReviewId, T::AccountId)>;
+        /// Reviews by author
+        ReviewIdByAuthor: double_map hasher(blake2_128_concat) T::AccountId, hasher(identity) ReviewId => ();
+        /// Reviews of Project Content
+        ReviewIdByProjectContentId: double_map hasher(identity) ProjectContentId, hasher(identity) ReviewId => ();
+        /// Reviews tagged with the Domain
+        ReviewIdByDomainId: double_map hasher(blake2_128_concat) DomainId, hasher(identity) ReviewId => ();
 
         ReviewVoteMap: map hasher(blake2_128_concat) (ReviewId, AccountIdOf<T>, DomainId) => DeipReviewVoteOf<T>;
 
@@ -460,6 +867,22 @@ decl_storage! {
         // The total number of domains stored in the map.
         // Because the map does not store its size, we must store it separately
         DomainCount get(fn domain_count) config(): u32;
+        // Direct children of a Domain
+        DomainChildren get(fn domain_children): map hasher(blake2_128_concat) DomainId => Vec<DomainId>;
+        // Domains that can't be used by new projects and reviews
+        DeprecatedDomains: map hasher(blake2_128_concat) DomainId => ();
+
+        /// Storage layout version, used to run migrations on runtime upgrade
+        StorageVersion get(fn storage_version) build(|_| Releases::V7): Releases;
+    }
+    add_extra_genesis {
+        build(|config: &GenesisConfig| {
+            for (external_id, domain) in &config.domains {
+                if let Some(parent_id) = domain.parent_id {
+                    DomainChildren::append(parent_id, *external_id);
+                }
+            }
+        })
     }
 }
 
@@ -473,6 +896,15 @@ decl_module! {
 
         // Events must be initialized if they are used by the pallet.
         fn deposit_event() = default;
+
+        /// A maximum number of Domains.
+        const MaxDomains: u32 = T::MaxDomains::get();
+
+        const MaxTransitionsPerBlock: u32 = T::MaxTransitionsPerBlock::get();
+
+        const MaxScheduledWeight: Weight = T::MaxScheduledWeight::get();
+
+        const MaxDutchAuctionBids: u32 = T::MaxDutchAuctionBids::get();
        
         /// Allow a user to create project.
         ///
@@ -502,28 +934,18 @@ decl_module! {
 
             ensure!(account == project.team_id, Error::<T>::NoPermission);
 
-            for domain in &project.domains {
-                ensure!(Domains::contains_key(&domain), Error::<T>::DomainNotExists);
-            }
-
-            let mut projects = Projects::<T>::get();
+            Self::ensure_domains_are_usable(&project.domains)?;
 
             // We don't want to add duplicate projects, so we check whether the potential new
-            // project is already present in the list. Because the list is always ordered, we can
-            // leverage the binary search which makes this check O(log n).
-            match projects.binary_search_by_key(&project.external_id, |&(a,_)| a) {
-                // If the search succeeds, the project is already a exists, so just return
-                Ok(_) => return Err(Error::<T>::ProjectAlreadyExists.into()),
-                // If the search fails, the project is not a exists and we learned the index where
-                // they should be inserted
-                Err(index) => {
-                    projects.insert(index, (project.external_id, project.team_id.clone()));
-                    Projects::<T>::put(projects);
-                }
-            };
+            // project is already present in the map. This check is constant time O(1)
+            ensure!(!ProjectMap::<T>::contains_key(project.external_id), Error::<T>::ProjectAlreadyExists);
 
             // Store the projects related to account
             ProjectMap::<T>::insert(project.external_id, project.clone());
+            ProjectIdByTeamId::<T>::insert(project.team_id.clone(), project.external_id, ());
+            for domain_id in &project.domains {
+                ProjectIdByDomainId::insert(domain_id, project.external_id, ());
+            }
 
             // Emit an event that the project was created.
             Self::deposit_event(RawEvent::ProjectCreated(account, project));
@@ -537,15 +959,39 @@ decl_module! {
         /// - `project_id`: id of the project which tokens are intended to sale.
         /// - `investment_type`: specifies type of created investment opportunity. For possible
         /// variants and details see [`FundingModel`].
+        /// - `vesting`: optional schedule of release of distributed shares. Shares are locked
+        ///     on investors' balances until they are released and claimed with `claim_vested`.
+        /// - `allowlist`: optional investors allowed to invest. Either accounts listed for the opportunity
+        ///     or a reference to an existing allowlist.
+        /// - `contribution_limits`: optional bounds of the total contribution of an investor.
+        /// - `withdrawal`: optional cooldown and fee of withdrawal of contributions while the opportunity is active.
+        /// - `exchange_rates`: other assets accepted by simple crowdfunding. Caps are evaluated and shares
+        ///     are distributed by the value of contributions in the asset of caps.
         #[weight = 10_000]
         fn create_investment_opportunity(origin,
             external_id: InvestmentId,
             creator: T::DeipAccountId,
             shares: Vec<DeipAssetOf<T>>,
             funding_model: FundingModelOf<T>,
+            vesting: Option<VestingScheduleOf<T>>,
+            allowlist: Option<InvestmentAllowlist<T::DeipAccountId>>,
+            contribution_limits: Option<ContributionLimitsOf<T>>,
+            withdrawal: Option<WithdrawalTermsOf<T>>,
+            exchange_rates: Vec<ExchangeRateOf<T>>,
         ) -> DispatchResult {
             let account = ensure_signed(origin)?;
-            Self::create_investment_opportunity_impl(account, external_id, creator.into(), shares, funding_model)
+            Self::create_investment_opportunity_impl(
+                account,
+                external_id,
+                creator.into(),
+                shares,
+                funding_model,
+                vesting,
+                allowlist.map(InvestmentAllowlist::into_accounts),
+                contribution_limits,
+                withdrawal,
+                exchange_rates,
+            )
         }
 
         #[weight = 10_000]
@@ -566,6 +1012,30 @@ decl_module! {
             Self::finish_crowdfunding_impl(sale_id)
         }
 
+        /// Allows the creator to cancel the investment opportunity before it finished.
+        /// Contributions are refunded, reserved shares return to the creator.
+        ///
+        /// The origin for this call must be _Signed_.
+        ///
+        /// - `sale_id`: identifier of the investment opportunity. It must be `Inactive` or `Active`.
+        #[weight = 10_000]
+        fn cancel_investment_opportunity(origin, sale_id: InvestmentId) -> DispatchResult {
+            let account = ensure_signed(origin)?;
+            Self::cancel_investment_opportunity_impl(account, sale_id)
+        }
+
+        /// Retries settlement of the investment opportunity that failed. Anyone can retry
+        /// it, e.g. once the reserved asset that failed to transfer is unfrozen.
+        ///
+        /// The origin for this call must be _Signed_.
+        ///
+        /// - `sale_id`: identifier of the investment opportunity. It must be `SettlementPending`.
+        #[weight = 10_000]
+        fn retry_settlement(origin, sale_id: InvestmentId) -> DispatchResult {
+            ensure_signed(origin)?;
+            Self::retry_settlement_impl(sale_id)
+        }
+
         /// Allows DAO to invest to an opportunity.
         ///
         /// The origin for this call must be _Signed_.
@@ -573,7 +1043,7 @@ decl_module! {
         /// - `id`: identifier of the investment opportunity
         /// - `amount`: amount of units to invest. The account should have enough funds on
         ///     the balance. This amount is reserved until the investment finished or expired
-        #[weight = 10_000]
+        #[weight = Module::<T>::invest_weight()]
         fn invest(origin,
             id: InvestmentId,
             asset: DeipAssetOf<T>
@@ -582,6 +1052,131 @@ decl_module! {
             Self::invest_to_crowdfunding_impl(account, id, asset)
         }
 
+        /// Allows the investor to take back all or part of its contribution while
+        /// the investment opportunity is active. The cooldown and the fee of withdrawal
+        /// are set by the creator of the opportunity.
+        ///
+        /// The origin for this call must be _Signed_.
+        ///
+        /// - `sale_id`: identifier of the investment opportunity
+        /// - `amount`: amount of units to withdraw. Must not exceed the contribution
+        #[weight = 10_000]
+        fn withdraw_investment(origin,
+            sale_id: InvestmentId,
+            amount: DeipAssetBalanceOf<T>,
+        ) -> DispatchResult {
+            let account = ensure_signed(origin)?;
+            Self::withdraw_investment_impl(account, sale_id, amount)
+        }
+
+        /// Allows the team to report the milestone of the current tranche.
+        ///
+        /// The origin for this call must be _Signed_.
+        ///
+        /// - `sale_id`: identifier of the finished investment opportunity funded by milestone tranches
+        /// - `content_id`: Project Content of the type required by the tranche
+        #[weight = 10_000]
+        fn submit_milestone(origin, sale_id: InvestmentId, content_id: ProjectContentId) -> DispatchResult {
+            let account = ensure_signed(origin)?;
+            Self::submit_milestone_impl(account, sale_id, content_id)
+        }
+
+        /// Allows an investor to vote for the reported milestone.
+        ///
+        /// The origin for this call must be _Signed_.
+        ///
+        /// - `sale_id`: identifier of the investment opportunity funded by milestone tranches
+        /// - `approve`: the tranche is released once investors with the majority of contributions
+        ///     approve it. An investor voting against gets the pro rata part of locked funds back
+        ///     and stops taking part in voting
+        #[weight = 10_000]
+        fn vote_milestone(origin, sale_id: InvestmentId, approve: bool) -> DispatchResult {
+            let account = ensure_signed(origin)?;
+            Self::vote_milestone_impl(account, sale_id, approve)
+        }
+
+        /// Allows anyone to return funds that are still locked to investors once the milestone
+        /// of the current tranche wasn't approved before its deadline.
+        ///
+        /// The origin for this call must be _Signed_.
+        ///
+        /// - `sale_id`: identifier of the investment opportunity funded by milestone tranches
+        /// - `investors`: number of investors to be refunded, see `get_investments`
+        #[weight = Module::<T>::refund_missed_milestone_weight(*investors)]
+        fn refund_missed_milestone(origin, sale_id: InvestmentId, investors: u32) -> DispatchResult {
+            ensure_signed(origin)?;
+            Self::refund_missed_milestone_impl(sale_id, investors)
+        }
+
+        /// Allows an investor to unlock shares released by the vesting schedule.
+        ///
+        /// The origin for this call must be _Signed_.
+        ///
+        /// - `sale_id`: identifier of the investment opportunity the shares were distributed by
+        #[weight = 10_000]
+        fn claim_vested(origin, sale_id: InvestmentId) -> DispatchResult {
+            let account = ensure_signed(origin)?;
+            Self::claim_vested_impl(account, sale_id)
+        }
+
+        /// Allows an account, e.g. an org, to create a list of investors that
+        /// investment opportunities can refer to.
+        ///
+        /// The origin for this call must be _Signed_.
+        ///
+        /// - `id`: id of the allowlist. Must be unique.
+        /// - `members`: investors allowed to invest
+        #[weight = 10_000]
+        fn create_allowlist(origin, id: AllowlistId, members: Vec<T::DeipAccountId>) -> DispatchResult {
+            let account = ensure_signed(origin)?;
+            Self::create_allowlist_impl(account, id, members.into_iter().map(Into::into).collect())
+        }
+
+        /// Allows the owner of the allowlist to add and remove investors.
+        ///
+        /// The origin for this call must be _Signed_.
+        ///
+        /// - `id`: id of the allowlist
+        /// - `to_add`: investors to allow
+        /// - `to_remove`: investors not allowed to invest anymore
+        #[weight = 10_000]
+        fn update_allowlist(origin,
+            id: AllowlistId,
+            to_add: Vec<T::DeipAccountId>,
+            to_remove: Vec<T::DeipAccountId>,
+        ) -> DispatchResult {
+            let account = ensure_signed(origin)?;
+            Self::update_allowlist_impl(
+                account,
+                id,
+                to_add.into_iter().map(Into::into).collect(),
+                to_remove.into_iter().map(Into::into).collect(),
+            )
+        }
+
+        /// Allows the creator of the investment opportunity to add and remove investors
+        /// of the allowlist listed for it.
+        ///
+        /// The origin for this call must be _Signed_.
+        ///
+        /// - `id`: id of the investment opportunity
+        /// - `to_add`: investors to allow
+        /// - `to_remove`: investors not allowed to invest anymore
+        #[weight = 10_000]
+        fn update_investment_allowlist(origin,
+            id: InvestmentId,
+            to_add: Vec<T::DeipAccountId>,
+            to_remove: Vec<T::DeipAccountId>,
+        ) -> DispatchResult {
+            let account = ensure_signed(origin)?;
+            Self::update_investment_allowlist_impl(
+                account,
+                id,
+                to_add.into_iter().map(Into::into).collect(),
+                to_remove.into_iter().map(Into::into).collect(),
+            )
+        }
+
         /// Allow a user to update project.
         ///
         /// The origin for this call must be _Signed_. 
@@ -619,6 +1214,47 @@ decl_module! {
             Ok(())
         }
 
+        /// Allow a team to remove project.
+        ///
+        /// The origin for this call must be _Signed_ by the project team.
+        ///
+        /// - `project_id`: [Project]((./struct.Project.html)) identifier (external_id) to be removed
+        /// - `witness`: numbers of entities removed along with the project, see `get_project_removal_witness`
+        #[weight = Module::<T>::remove_project_weight(witness)]
+        fn remove_project(origin, project_id: ProjectId, witness: ProjectRemovalWitness) -> DispatchResult {
+            let account = ensure_signed(origin)?;
+            Self::remove_project_impl(account, project_id, witness)
+        }
+
+        /// Allow a team to hand project over to another team.
+        ///
+        /// The origin for this call must be _Signed_ by the project team.
+        ///
+        /// - `project_id`: [Project]((./struct.Project.html)) identifier (external_id) to be transferred
+        /// - `new_team_id`: Account (Team) the project is handed over to
+        /// - `with_acceptance`: the handover takes effect only when the new team calls
+        ///     `accept_project_transfer`
+        #[weight = 10_000]
+        fn transfer_project(origin,
+            project_id: ProjectId,
+            new_team_id: T::DeipAccountId,
+            with_acceptance: bool,
+        ) -> DispatchResult {
+            let account = ensure_signed(origin)?;
+            Self::transfer_project_impl(account, project_id, new_team_id.into(), with_acceptance)
+        }
+
+        /// Allow a team to accept the project handed over to it.
+        ///
+        /// The origin for this call must be _Signed_ by the new team.
+        ///
+        /// - `project_id`: [Project]((./struct.Project.html)) identifier (external_id) to be accepted
+        #[weight = 10_000]
+        fn accept_project_transfer(origin, project_id: ProjectId) -> DispatchResult {
+            let account = ensure_signed(origin)?;
+            Self::accept_project_transfer_impl(account, project_id)
+        }
+
         /// Allow a user to create project content.
         ///
         /// The origin for this call must be _Signed_. 
@@ -648,10 +1284,10 @@ decl_module! {
                 references
             };
 
-            let mut project_content = ProjectsContent::<T>::get();
-
-            let index_to_insert_content = project_content.binary_search_by_key(&content.external_id, |&(a,_, _)| a)
-                .err().ok_or(Error::<T>::ProjectContentAlreadyExists)?;
+            ensure!(
+                !ProjectIdByProjectContentId::contains_key(content.external_id),
+                Error::<T>::ProjectContentAlreadyExists
+            );
 
             let project = ProjectMap::<T>::get(content.project_external_id);
 
@@ -663,13 +1299,18 @@ decl_module! {
             if let Some(references) = &content.references {
                 let is_all_references_exists = references
                     .iter()
-                    .all(|&reference| project_content.binary_search_by_key(&reference,|&(id,_, _)| id).is_ok());
+                    .all(|reference| ProjectIdByProjectContentId::contains_key(reference));
 
                 ensure!(is_all_references_exists, Error::<T>::NoSuchReference);
             }
 
-            project_content.insert(index_to_insert_content, (content.external_id, content.project_external_id,  content.team_id.clone()));
-            ProjectsContent::<T>::put(project_content);
+            ProjectIdByProjectContentId::insert(content.external_id, content.project_external_id);
+            ProjectContentIdByTeamId::<T>::insert(content.team_id.clone(), content.external_id, ());
+            ProjectContentIdByType::insert(&content.content_type, content.external_id, ());
+
+            if let Some(references) = &content.references {
+                Self::add_project_content_citations(content.external_id, references);
+            }
 
             // Store the content
             ProjectContentMap::<T>::insert(project.external_id, content.external_id, content.clone());
@@ -678,6 +1319,99 @@ decl_module! {
             Self::deposit_event(RawEvent::ProjectContnetCreated(account, content.external_id));
         }
 
+        /// Allow a team to amend project content.
+        ///
+        /// The origin for this call must be _Signed_ by the project team.
+        /// The replaced state is kept as a [revision](./struct.ProjectContentRevision.html).
+        ///
+        /// - `project_id`: Reference to the Project
+        /// - `content_id`: Reference to the Project Content to be amended
+        /// - `description`: Hash of the new content description
+        /// - `content`: Hash of the new digital asset
+        /// - `authors`: Authors of the new digital asset
+        #[weight = 10_000]
+        fn amend_project_content(origin,
+            project_id: ProjectId,
+            content_id: ProjectContentId,
+            description: T::Hash,
+            content: T::Hash,
+            authors: Vec<T::DeipAccountId>,
+        ) -> DispatchResult {
+            let account = ensure_signed(origin)?;
+            Self::amend_project_content_impl(account, project_id, content_id, description, content, authors.into_iter().map(Into::into).collect())
+        }
+
+        /// Allow a team to set contribution shares of project content authors.
+        ///
+        /// The origin for this call must be _Signed_ by the project team.
+        ///
+        /// - `project_id`: Reference to the Project
+        /// - `content_id`: Reference to the Project Content
+        /// - `shares`: share of every author in basis points. Shares must sum to 10000 (100%)
+        #[weight = 10_000]
+        fn set_project_content_author_shares(origin,
+            project_id: ProjectId,
+            content_id: ProjectContentId,
+            shares: Vec<(T::DeipAccountId, AuthorShare)>,
+        ) -> DispatchResult {
+            let account = ensure_signed(origin)?;
+            let shares = shares.into_iter().map(|(author, share)| (author.into(), share)).collect();
+            Self::set_project_content_author_shares_impl(account, project_id, content_id, shares)
+        }
+
+        /// Allow a user to pay to the authors of project content.
+        ///
+        /// The origin for this call must be _Signed_.
+        ///
+        /// - `project_id`: Reference to the Project
+        /// - `content_id`: Reference to the Project Content
+        /// - `asset`: payment. It is split across the authors by their shares
+        #[weight = 10_000]
+        fn distribute_project_content_revenue(origin,
+            project_id: ProjectId,
+            content_id: ProjectContentId,
+            asset: DeipAssetOf<T>,
+        ) -> DispatchResult {
+            let account = ensure_signed(origin)?;
+            Self::distribute_project_content_revenue_impl(account, project_id, content_id, asset)
+        }
+
+        /// Allow a team to offer licences to use project content.
+        ///
+        /// The origin for this call must be _Signed_ by the project team.
+        ///
+        /// - `external_id`: Licence Offer identifier
+        /// - `project_id`: Reference to the Project
+        /// - `content_id`: Reference to the licensed Project Content
+        /// - `price`: Price of a licence
+        /// - `duration`: Optional. How long a licence is valid. Licences are perpetual if not specified
+        /// - `terms`: Hash of the licence terms
+        #[weight = 10_000]
+        fn create_licence_offer(origin,
+            external_id: LicenceOfferId,
+            project_id: ProjectId,
+            content_id: ProjectContentId,
+            price: DeipAssetOf<T>,
+            duration: Option<T::Moment>,
+            terms: T::Hash,
+        ) -> DispatchResult {
+            let account = ensure_signed(origin)?;
+            Self::create_licence_offer_impl(account, external_id, project_id, content_id, price, duration, terms)
+        }
+
+        /// Allow a user to purchase a licence to use project content.
+        /// The price is paid to the project team. Purchasing a licence
+        /// that is still valid extends it.
+        ///
+        /// The origin for this call must be _Signed_.
+        ///
+        /// - `offer_id`: Reference to the Licence Offer
+        #[weight = 10_000]
+        fn purchase_licence(origin, offer_id: LicenceOfferId) -> DispatchResult {
+            let account = ensure_signed(origin)?;
+            Self::purchase_licence_impl(account, offer_id)
+        }
+
         /// Allow a user to create [NDA](./struct.Nda.html).
         ///
         /// The origin for this call must be _Signed_. 
@@ -720,10 +1454,7 @@ decl_module! {
                     Ok(())
                 })?;
 
-            let mut nda_list = Ndas::<T>::get();
-
-            let index_to_insert_nda = nda_list.binary_search_by_key(&external_id, |&(external_id, ..)| external_id)
-                .err().ok_or(Error::<T>::NdaAlreadyExists)?;
+            ensure!(!NdaMap::<T>::contains_key(external_id), Error::<T>::NdaAlreadyExists);
             
                
             let nda = Nda {
@@ -736,8 +1467,10 @@ decl_module! {
                 projects
             };
             
-            nda_list.insert(index_to_insert_nda, (nda.external_id, contract_creator.clone()));
-            Ndas::<T>::put(nda_list);
+            NdaIdByCreator::<T>::insert(contract_creator.clone(), nda.external_id, ());
+            for project_id in &nda.projects {
+                NdaIdByProjectId::insert(project_id, nda.external_id, ());
+            }
 
             NdaMap::<T>::insert(nda.external_id, nda);
 
@@ -770,10 +1503,10 @@ decl_module! {
             ensure!(!nda.external_id.is_zero(), Error::<T>::NoSuchNda);
             ensure!(nda.start_date <= Some(timestamp), Error::<T>::NdaContractIsNotActiveYet);
 
-            let mut nda_requests = NdaAccessRequests::<T>::get();
-
-            let index_to_insert_nda_request = nda_requests.binary_search_by_key(&external_id, |&(external_id, ..)| external_id)
-                .err().ok_or(Error::<T>::NdaAccessRequestAlreadyExists)?;
+            ensure!(
+                !NdaAccessRequestMap::<T>::contains_key(external_id),
+                Error::<T>::NdaAccessRequestAlreadyExists
+            );
             
             let nda_request = NdaAccessRequest {
                 external_id,
@@ -787,8 +1520,7 @@ decl_module! {
                 encrypted_payload_encryption_key: None,
                 proof_of_encrypted_payload_encryption_key: None,
             };
-            nda_requests.insert(index_to_insert_nda_request, (external_id, nda_external_id, account.clone()));
-            NdaAccessRequests::<T>::put(nda_requests);
+            NdaAccessRequestIdByNdaId::insert(nda_external_id, external_id, ());
 
             NdaAccessRequestMap::<T>::insert(nda_request.external_id, nda_request);
 
@@ -893,40 +1625,73 @@ decl_module! {
             Self::upvote_review_impl(account, review_id, domain_id)
         }
 
-        /// Allow a user to create domains.
+        /// Allow the domain admin to create domains.
         ///
-        /// The origin for this call must be _Signed_. 
+        /// The origin for this call must be `DomainAdminOrigin`.
         ///
-        /// - `project`: [Domain](./struct.Domain.html) to be created.
+        /// - `domain`: [Domain](./struct.Domain.html) to be created. The parent domain, if specified, must exist.
         #[weight = 10_000]
-        fn add_domain(origin, domain: Domain) {
-            let account = ensure_signed(origin)?;
-        
-            let domain_count = DomainCount::get();
-            ensure!(domain_count < MAX_DOMAINS, Error::<T>::DomianLimitReached);
+        fn add_domain(origin, domain: Domain) -> DispatchResult {
+            let account = ensure_signed(origin.clone()).ok();
+            T::DomainAdminOrigin::ensure_origin(origin)?;
+            Self::add_domain_impl(account, domain)
+        }
 
-            let external_id = domain.external_id;
-        
-            // We don't want to add duplicate domains, so we check whether the potential new
-            // domain is already present in the list. Because the domains is stored as a hash
-            // map this check is constant time O(1)
-            ensure!(!Domains::contains_key(&external_id), Error::<T>::DomainAlreadyExists);
+        /// Allow the domain admin to deprecate a domain.
+        /// Deprecated domain can't be used by new projects and reviews.
+        ///
+        /// The origin for this call must be `DomainAdminOrigin`.
+        ///
+        /// - `domain_id`: Domain to be deprecated
+        #[weight = 10_000]
+        fn deprecate_domain(origin, domain_id: DomainId) -> DispatchResult {
+            T::DomainAdminOrigin::ensure_origin(origin)?;
+            Self::deprecate_domain_impl(domain_id)
+        }
 
-           
-            
-            // Insert the new domin and emit the event
-            Domains::insert(&external_id, domain);
-            DomainCount::put(domain_count + 1); // overflow check not necessary because of maximum
-            
-            Self::deposit_event(RawEvent::DomainAdded(account, external_id));
+        /// Allow the domain admin to merge a domain into another one.
+        /// Projects, reviews and child domains of the merged domain are moved
+        /// to the target domain, then the merged domain is removed.
+        ///
+        /// The origin for this call must be `DomainAdminOrigin`.
+        ///
+        /// - `from`: Domain to be merged and removed
+        /// - `into`: Target domain. Must not be a descendant of `from`
+        /// - `witness`: numbers of entities moved from `from`, see `get_domain_merge_witness`
+        #[weight = Module::<T>::merge_domains_weight(witness)]
+        fn merge_domains(origin, from: DomainId, into: DomainId, witness: DomainMergeWitness) -> DispatchResult {
+            T::DomainAdminOrigin::ensure_origin(origin)?;
+            Self::merge_domains_impl(from, into, witness)
         }
 
+        /// Allow the domain admin to remove a domain that has no children
+        /// and isn't used by any project or review.
+        ///
+        /// The origin for this call must be `DomainAdminOrigin`.
+        ///
+        /// - `domain_id`: Domain to be removed
+        #[weight = Module::<T>::remove_domain_weight()]
+        fn remove_domain(origin, domain_id: DomainId) -> DispatchResult {
+            T::DomainAdminOrigin::ensure_origin(origin)?;
+            Self::remove_domain_impl(domain_id)
+        }
+
+        fn on_initialize(_n: T::BlockNumber) -> Weight {
+            Self::process_scheduled_transitions()
+        }
+
+        fn on_runtime_upgrade() -> Weight {
+            migrations::migrate::<T>()
+        }
+
+        /// Submits transitions of investment opportunities that are due, but weren't
+        /// performed by the schedule, e.g. because of the limit of transitions per block
         fn offchain_worker(_n: T::BlockNumber) {
             if !sp_io::offchain::is_validator() {
                 return;
             }
 
-            Self::process_investment_opportunities_offchain();
+            Self::process_scheduled_transitions_offchain();
         }
     }
 }
@@ -998,7 +1763,9 @@ impl<T: Config> Module<T> {
             .any(|x| x.content_type == ProjectContentType::FinalResult)
     }
     pub fn get_projects() -> Vec<(ProjectId, T::AccountId)>{
-        Self::projects()
+        ProjectMap::<T>::iter()
+            .map(|(id, project)| (id, project.team_id))
+            .collect()
     }
     pub fn get_project(project_id: &ProjectId) -> ProjectOf<T> {
         ProjectMap::<T>::get(project_id)
@@ -1018,15 +1785,17 @@ impl<T: Config> Module<T> {
         Domains::get(domain_id)
     }
     pub fn get_project_content_list(content_ids: &Option<Vec<ProjectContentId>>) -> Vec<ProjectContentOf<T>>{
-        <ProjectContentMap<T> as IterableStorageDoubleMap<ProjectId, ProjectContentId, ProjectContentOf<T>>>::iter()
-            .filter(|(_project_id, project_content_id, ..)| {                
-                match content_ids {
-                    Some(ids) => ids.contains(&project_content_id),
-                    _ => true
-                }
-            })
-            .map(|(_project_id, _project_content_id, content)| content)
-            .collect()
+        match content_ids {
+            Some(ids) => ids.iter()
+                .filter_map(|id| {
+                    let project_id = ProjectIdByProjectContentId::get(id)?;
+                    Some(ProjectContentMap::<T>::get(project_id, id))
+                })
+                .collect(),
+            None => <ProjectContentMap<T> as IterableStorageDoubleMap<ProjectId, ProjectContentId, ProjectContentOf<T>>>::iter()
+                .map(|(_project_id, _project_content_id, content)| content)
+                .collect(),
+        }
     }
     pub fn get_project_content(project_id: &ProjectId, project_content_id: &ProjectContentId) -> ProjectContentOf<T> {
         ProjectContentMap::<T>::get(project_id, project_content_id)
@@ -0,0 +1,148 @@
+use crate::*;
+
+use crate::traits::DeipAssetSystem;
+use sp_runtime::traits::{Saturating, Zero};
+
+/// Unique Licence Offer reference
+pub type Id = H160;
+
+/// Terms on which the project team sells usage licences for Project Content
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct Offer<Hash, AccountId, Moment, Asset> {
+    /// Reference for external world and uniques control
+    pub external_id: Id,
+    /// Reference to the Project
+    pub project_external_id: ProjectId,
+    /// Reference to the licensed Project Content
+    pub project_content_external_id: ProjectContentId,
+    /// Reference to the Team
+    pub team_id: AccountId,
+    /// Price of a licence
+    pub price: Asset,
+    /// How long a licence is valid. `None` stands for a perpetual licence
+    pub duration: Option<Moment>,
+    /// Hash of the licence terms
+    pub terms: Hash,
+}
+
+/// Licence to use Project Content held by an account
+#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct Licence<AccountId, Moment> {
+    /// Reference to the Licence Offer the licence was purchased last time by
+    pub offer_external_id: Id,
+    /// Licence holder
+    pub holder: AccountId,
+    /// When the licence came into force
+    pub start_time: Moment,
+    /// When the licence expires. `None` stands for a perpetual licence
+    pub end_time: Option<Moment>,
+}
+
+impl<AccountId, Moment: PartialOrd> Licence<AccountId, Moment> {
+    pub fn is_valid_at(&self, moment: &Moment) -> bool {
+        self.start_time <= *moment && self.end_time.as_ref().map_or(true, |end_time| moment < end_time)
+    }
+}
+
+impl<T: Config> Module<T> {
+    pub(super) fn create_licence_offer_impl(
+        account: T::AccountId,
+        external_id: Id,
+        project_id: ProjectId,
+        content_id: ProjectContentId,
+        price: DeipAssetOf<T>,
+        duration: Option<T::Moment>,
+        terms: T::Hash,
+    ) -> DispatchResult {
+        let project = ProjectMap::<T>::try_get(project_id).map_err(|_| Error::<T>::NoSuchProject)?;
+        ensure!(project.team_id == account, Error::<T>::ProjectNotBelongToTeam);
+
+        ensure!(
+            ProjectContentMap::<T>::contains_key(project_id, content_id),
+            Error::<T>::NoSuchProjectContent
+        );
+
+        if let Some(ref d) = duration {
+            ensure!(!d.is_zero(), Error::<T>::LicenceOfferDurationMustBePositive);
+        }
+
+        ensure!(
+            !LicenceOfferMap::<T>::contains_key(external_id),
+            Error::<T>::LicenceOfferAlreadyExists
+        );
+
+        let offer = Offer {
+            external_id,
+            project_external_id: project_id,
+            project_content_external_id: content_id,
+            team_id: account.clone(),
+            price,
+            duration,
+            terms,
+        };
+
+        LicenceOfferMap::<T>::insert(external_id, offer);
+        LicenceOfferIdByProjectId::insert(project_id, external_id, ());
+
+        Self::deposit_event(RawEvent::LicenceOfferCreated(account, external_id));
+
+        Ok(())
+    }
+
+    pub(super) fn purchase_licence_impl(account: T::AccountId, offer_id: Id) -> DispatchResult {
+        let offer = LicenceOfferMap::<T>::get(offer_id).ok_or(Error::<T>::NoSuchLicenceOffer)?;
+
+        let project = ProjectMap::<T>::try_get(offer.project_external_id)
+            .map_err(|_| Error::<T>::NoSuchProject)?;
+
+        if !offer.price.amount.is_zero() {
+            T::AssetSystem::transactionally_transfer(
+                &account,
+                offer.price.id,
+                &[(project.team_id, offer.price.amount)],
+            )
+            .map_err(|_| Error::<T>::LicencePaymentFailed)?;
+        }
+
+        let now = pallet_timestamp::Module::<T>::get();
+        LicenceMap::<T>::mutate(offer.project_content_external_id, &account, |maybe_licence| {
+            match maybe_licence.as_mut() {
+                Some(licence) if licence.is_valid_at(&now) => {
+                    licence.offer_external_id = offer_id;
+                    licence.end_time = match (licence.end_time, offer.duration) {
+                        (Some(end_time), Some(duration)) => Some(end_time.saturating_add(duration)),
+                        _ => None,
+                    };
+                }
+                _ => {
+                    *maybe_licence = Some(Licence {
+                        offer_external_id: offer_id,
+                        holder: account.clone(),
+                        start_time: now,
+                        end_time: offer.duration.map(|duration| now.saturating_add(duration)),
+                    });
+                }
+            };
+        });
+
+        Self::deposit_event(RawEvent::LicencePurchased(account, offer_id));
+
+        Ok(())
+    }
+
+    pub fn get_licence_offer(offer_id: &Id) -> Option<LicenceOfferOf<T>> {
+        LicenceOfferMap::<T>::get(offer_id)
+    }
+
+    pub fn get_licence(account: &T::AccountId, content_id: &ProjectContentId) -> Option<LicenceOf<T>> {
+        LicenceMap::<T>::get(content_id, account)
+    }
+
+    pub fn has_valid_licence(account: &T::AccountId, content_id: &ProjectContentId, moment: &T::Moment) -> bool {
+        LicenceMap::<T>::get(content_id, account).map_or(false, |licence| licence.is_valid_at(moment))
+    }
+}
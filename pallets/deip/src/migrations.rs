@@ -0,0 +1,293 @@
+use crate::*;
+
+use frame_support::storage::migration::remove_storage_prefix;
+
+/// Storage layout versions of the pallet
+#[derive(Encode, Decode, Clone, Copy, RuntimeDebug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Releases {
+    /// Entities listed in `Vec` values
+    V1,
+    /// Entities listed in keyed indexes
+    V2,
+    /// Contributions record paid assets
+    V3,
+    /// Transitions of investment opportunities are scheduled
+    V4,
+    /// Projects and Reviews are indexed by Domain
+    V5,
+    /// Project Content is indexed by type
+    V6,
+    /// Milestone tranches are indexed by Project
+    V7,
+}
+
+impl Default for Releases {
+    fn default() -> Self {
+        Releases::V1
+    }
+}
+
+/// Brings the storage to the latest layout, returns consumed weight
+pub(super) fn migrate<T: Config>() -> Weight {
+    let mut weight = T::DbWeight::get().reads(1);
+
+    if StorageVersion::get() < Releases::V2 {
+        weight = weight.saturating_add(v2::migrate::<T>());
+        StorageVersion::put(Releases::V2);
+        weight = weight.saturating_add(T::DbWeight::get().writes(1));
+    }
+
+    if StorageVersion::get() < Releases::V3 {
+        weight = weight.saturating_add(v3::migrate::<T>());
+        StorageVersion::put(Releases::V3);
+        weight = weight.saturating_add(T::DbWeight::get().writes(1));
+    }
+
+    if StorageVersion::get() < Releases::V4 {
+        weight = weight.saturating_add(v4::migrate::<T>());
+        StorageVersion::put(Releases::V4);
+        weight = weight.saturating_add(T::DbWeight::get().writes(1));
+    }
+
+    if StorageVersion::get() < Releases::V5 {
+        weight = weight.saturating_add(v5::migrate::<T>());
+        StorageVersion::put(Releases::V5);
+        weight = weight.saturating_add(T::DbWeight::get().writes(1));
+    }
+
+    if StorageVersion::get() < Releases::V6 {
+        weight = weight.saturating_add(v6::migrate::<T>());
+        StorageVersion::put(Releases::V6);
+        weight = weight.saturating_add(T::DbWeight::get().writes(1));
+    }
+
+    if StorageVersion::get() < Releases::V7 {
+        weight = weight.saturating_add(v7::migrate::<T>());
+        StorageVersion::put(Releases::V7);
+        weight = weight.saturating_add(T::DbWeight::get().writes(1));
+    }
+
+    weight
+}
+
+mod v2 {
+    use super::*;
+
+    #[derive(Decode)]
+    struct OldDomain {
+        external_id: DomainId,
+    }
+
+    #[derive(Decode)]
+    struct OldReview<Hash, AccountId> {
+        external_id: ReviewId,
+        author: AccountId,
+        content: Hash,
+        domains: Vec<DomainId>,
+        assessment_model: u32,
+        weight: Vec<u8>,
+        project_content_external_id: ProjectContentId,
+    }
+
+    pub(super) fn migrate<T: Config>() -> Weight {
+        let mut reads: Weight = 0;
+        let mut writes: Weight = 0;
+
+        Domains::translate::<OldDomain, _>(|_, old| {
+            reads += 1;
+            writes += 1;
+            Some(Domain { external_id: old.external_id, ..Default::default() })
+        });
+
+        ReviewMap::<T>::translate::<OldReview<T::Hash, T::AccountId>, _>(|_, old| {
+            reads += 1;
+            writes += 3;
+            ReviewIdByAuthor::<T>::insert(&old.author, old.external_id, ());
+            ReviewIdByProjectContentId::insert(old.project_content_external_id, old.external_id, ());
+            Some(Review {
+                external_id: old.external_id,
+                author: old.author,
+                content: old.content,
+                domains: old.domains,
+                assessment_model: old.assessment_model,
+                weight: old.weight,
+                project_content_external_id: old.project_content_external_id,
+                // the version wasn't tracked before, so the review is bound to the initial one
+                project_content_version: 0,
+            })
+        });
+
+        for (project_id, project) in ProjectMap::<T>::iter() {
+            reads += 1;
+            writes += 1;
+            ProjectIdByTeamId::<T>::insert(&project.team_id, project_id, ());
+        }
+
+        for (project_id, content_id, content) in ProjectContentMap::<T>::iter() {
+            let references = content.references.unwrap_or_default();
+            reads += 1 + references.len() as Weight;
+            writes += 2 + references.len() as Weight;
+            ProjectIdByProjectContentId::insert(content_id, project_id);
+            ProjectContentIdByTeamId::<T>::insert(&content.team_id, content_id, ());
+            Module::<T>::add_project_content_citations(content_id, &references);
+        }
+
+        for (nda_id, nda) in NdaMap::<T>::iter() {
+            reads += 1;
+            writes += 1 + nda.projects.len() as Weight;
+            NdaIdByCreator::<T>::insert(&nda.contract_creator, nda_id, ());
+            for project_id in &nda.projects {
+                NdaIdByProjectId::insert(project_id, nda_id, ());
+            }
+        }
+
+        for (request_id, request) in NdaAccessRequestMap::<T>::iter() {
+            reads += 1;
+            writes += 1;
+            NdaAccessRequestIdByNdaId::insert(request.nda_external_id, request_id, ());
+        }
+
+        for (sale_id, contributions) in InvestmentMap::<T>::iter() {
+            reads += 1;
+            writes += contributions.len() as Weight;
+            for (investor, _) in contributions {
+                InvestmentIdByInvestor::<T>::insert(&investor, sale_id, ());
+            }
+        }
+
+        for item in [&b"Projects"[..], b"ProjectsContent", b"Ndas", b"NdaAccessRequests", b"Reviews"].iter() {
+            writes += 1;
+            remove_storage_prefix(b"Deip", item, &[]);
+        }
+
+        T::DbWeight::get().reads_writes(reads, writes)
+    }
+}
+
+mod v3 {
+    use super::*;
+    use sp_std::vec;
+
+    #[derive(Decode)]
+    struct OldContribution<AccountId, Balance, Moment> {
+        sale_id: InvestmentId,
+        owner: AccountId,
+        amount: Balance,
+        time: Moment,
+    }
+
+    type OldContributions<T> =
+        Vec<(AccountIdOf<T>, OldContribution<AccountIdOf<T>, DeipAssetBalanceOf<T>, MomentOf<T>>)>;
+
+    pub(super) fn migrate<T: Config>() -> Weight {
+        let mut reads: Weight = 0;
+        let mut writes: Weight = 0;
+
+        InvestmentMap::<T>::translate::<OldContributions<T>, _>(|sale_id, old| {
+            reads += 2;
+            writes += 1;
+            // sales accepted the only asset before
+            let asset_id = SimpleCrowdfundingMap::<T>::get(sale_id).asset_id;
+            let contributions = old
+                .into_iter()
+                .map(|(investor, old)| {
+                    let contribution = Investment {
+                        sale_id: old.sale_id,
+                        owner: old.owner,
+                        amount: old.amount,
+                        time: old.time,
+                        assets: vec![(asset_id, old.amount)],
+                    };
+                    (investor, contribution)
+                })
+                .collect();
+            Some(contributions)
+        });
+
+        T::DbWeight::get().reads_writes(reads, writes)
+    }
+}
+
+mod v4 {
+    use super::*;
+
+    pub(super) fn migrate<T: Config>() -> Weight {
+        let mut reads: Weight = 0;
+        let mut writes: Weight = 0;
+
+        for (sale_id, sale) in SimpleCrowdfundingMap::<T>::iter() {
+            reads += 1;
+            let due = match sale.status {
+                SimpleCrowdfundingStatus::Inactive => sale.start_time,
+                SimpleCrowdfundingStatus::Active => sale.end_time,
+                _ => continue,
+            };
+            Module::<T>::schedule_transition(due, sale_id);
+            reads += 1;
+            writes += 1;
+        }
+
+        T::DbWeight::get().reads_writes(reads, writes)
+    }
+}
+
+mod v5 {
+    use super::*;
+
+    pub(super) fn migrate<T: Config>() -> Weight {
+        let mut reads: Weight = 0;
+        let mut writes: Weight = 0;
+
+        for (project_id, project) in ProjectMap::<T>::iter() {
+            reads += 1;
+            writes += project.domains.len() as Weight;
+            for domain_id in &project.domains {
+                ProjectIdByDomainId::insert(domain_id, project_id, ());
+            }
+        }
+
+        for (review_id, review) in ReviewMap::<T>::iter() {
+            reads += 1;
+            writes += review.domains.len() as Weight;
+            for domain_id in &review.domains {
+                ReviewIdByDomainId::insert(domain_id, review_id, ());
+            }
+        }
+
+        T::DbWeight::get().reads_writes(reads, writes)
+    }
+}
+
+mod v6 {
+    use super::*;
+
+    pub(super) fn migrate<T: Config>() -> Weight {
+        let mut reads: Weight = 0;
+        let mut writes: Weight = 0;
+
+        for (_, content_id, content) in ProjectContentMap::<T>::iter() {
+            reads += 1;
+            writes += 1;
+            ProjectContentIdByType::insert(&content.content_type, content_id, ());
+        }
+
+        T::DbWeight::get().reads_writes(reads, writes)
+    }
+}
+
+mod v7 {
+    use super::*;
+
+    pub(super) fn migrate<T: Config>() -> Weight {
+        let mut reads: Weight = 0;
+        let mut writes: Weight = 0;
+
+        for (sale_id, tranches) in MilestoneTranchesMap::<T>::iter() {
+            reads += 1;
+            writes += 1;
+            MilestoneTranchesIdByProjectId::insert(tranches.project_id, sale_id, ());
+        }
+
+        T::DbWeight::get().reads_writes(reads, writes)
+    }
+}
@@ -1,5 +1,5 @@
 use crate as pallet_deip;
-use frame_support::{parameter_types, traits::Get};
+use frame_support::{parameter_types, traits::Get, weights::{RuntimeDbWeight, Weight}};
 use frame_system as system;
 use sp_core::H256;
 use sp_runtime::{
@@ -35,20 +35,21 @@ frame_support::construct_runtime!(
         Timestamp: pallet_timestamp::{Module, Call, Storage, Inherent},
         Deip: pallet_deip::{Module, Call, Storage, Event<T>, Config},
         Assets: pallet_assets::{Module, Storage, Event<T>},
-        DeipAssets: pallet_deip_assets::{Module, Storage, Call},
+        DeipAssets: pallet_deip_assets::{Module, Storage, Call, Event<T>},
     }
 );
 
 parameter_types! {
     pub const BlockHashCount: u64 = 250;
     pub const SS58Prefix: u8 = 42;
+    pub const DbWeight: RuntimeDbWeight = RuntimeDbWeight { read: 1, write: 1 };
 }
 
 impl system::Config for Test {
     type BaseCallFilter = ();
     type BlockWeights = ();
     type BlockLength = ();
-    type DbWeight = ();
+    type DbWeight = DbWeight;
     type Origin = Origin;
     type Call = Call;
     type Index = u64;
@@ -93,8 +94,9 @@ impl pallet_deip::traits::DeipAssetSystem<u64> for Test {
         id: super::InvestmentId,
         security_tokens_on_sale: &[(Self::AssetId, Self::Balance)],
         asset: Self::AssetId,
+        other_assets: &[Self::AssetId],
     ) -> Result<(), ReserveError<Self::AssetId>> {
-        DeipAssets::transactionally_reserve(account, id, security_tokens_on_sale, asset)
+        DeipAssets::transactionally_reserve(account, id, security_tokens_on_sale, asset, other_assets)
     }
 
     fn transactionally_unreserve(id: super::InvestmentId) -> Result<(), UnreserveError<Self::AssetId>> {
@@ -113,10 +115,42 @@ impl pallet_deip::traits::DeipAssetSystem<u64> for Test {
     fn transfer_to_reserved(
         who: &AccountId,
         id: super::InvestmentId,
+        asset: Self::AssetId,
         amount: Self::Balance,
     ) -> Result<(), UnreserveError<Self::AssetId>> {
-        DeipAssets::transfer_to_reserved(who, id, amount)
+        DeipAssets::transfer_to_reserved(who, id, asset, amount)
+    }
+
+    fn transactionally_transfer(
+        from: &AccountId,
+        asset: Self::AssetId,
+        transfers: &[(AccountId, Self::Balance)],
+    ) -> Result<(), ()> {
+        DeipAssets::transactionally_transfer(from, asset, transfers)
+    }
+
+    fn lock(who: &AccountId, asset: Self::AssetId, amount: Self::Balance) -> Result<(), ()> {
+        DeipAssets::lock(who, asset, amount)
+    }
+
+    fn unlock(who: &AccountId, asset: Self::AssetId, amount: Self::Balance) {
+        DeipAssets::unlock(who, asset, amount)
+    }
+
+    fn get_investment_creator(id: &super::InvestmentId) -> Option<AccountId> {
+        DeipAssets::investment_creator(id)
     }
+
+    fn get_project_security_tokens(id: &super::ProjectId) -> Vec<Self::AssetId> {
+        DeipAssets::project_security_tokens(id)
+    }
+}
+
+parameter_types! {
+    pub const MaxDomains: u32 = 100;
+    pub const MaxTransitionsPerBlock: u32 = 10;
+    pub const MaxScheduledWeight: Weight = 100;
+    pub const MaxDutchAuctionBids: u32 = 10;
 }
 
 impl pallet_deip::Config for Test {
@@ -124,6 +158,11 @@ impl pallet_deip::Config for Test {
     type DeipAccountId = Self::AccountId;
     type Currency = Balances;
     type AssetSystem = Self;
+    type MaxDomains = MaxDomains;
+    type MaxTransitionsPerBlock = MaxTransitionsPerBlock;
+    type MaxScheduledWeight = MaxScheduledWeight;
+    type MaxDutchAuctionBids = MaxDutchAuctionBids;
+    type DomainAdminOrigin = frame_system::EnsureRoot<Self::AccountId>;
 }
 
 parameter_types! {
@@ -169,9 +208,17 @@ impl pallet_deip_assets::traits::DeipProjectsInfo<AccountId> for Test {
     }
 }
 
+parameter_types! {
+    pub const DividendClaimPeriod: u64 = 10;
+}
+
 impl pallet_deip_assets::Config for Test {
+    type Event = Event;
     type ProjectsInfo = Self;
     type DeipAccountId = Self::AccountId;
+    type DividendClaimPeriod = DividendClaimPeriod;
+    type UnixTime = Timestamp;
+    type JurisdictionOrigin = frame_system::EnsureRoot<Self::AccountId>;
 }
 
 impl<LocalCall> system::offchain::SendTransactionTypes<LocalCall> for Test
@@ -0,0 +1,177 @@
+use crate::*;
+
+use crate::traits::DeipAssetSystem;
+
+/// Numbers of entities removed along with the project. `remove_project` is charged by them,
+/// so they shouldn't be less than the actual ones
+#[derive(Encode, Decode, Clone, Copy, Default, RuntimeDebug, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct RemovalWitness {
+    /// Domains of the project
+    pub domains: u32,
+    /// Project Content of the project
+    pub contents: u32,
+    /// References and revisions of all Project Content
+    pub references: u32,
+    pub revisions: u32,
+    /// Reviews of all Project Content and their domains
+    pub reviews: u32,
+    pub review_domains: u32,
+    /// Licence offers of the project
+    pub offers: u32,
+}
+
+impl RemovalWitness {
+    fn covers(&self, actual: &Self) -> bool {
+        self.domains >= actual.domains
+            && self.contents >= actual.contents
+            && self.references >= actual.references
+            && self.revisions >= actual.revisions
+            && self.reviews >= actual.reviews
+            && self.review_domains >= actual.review_domains
+            && self.offers >= actual.offers
+    }
+}
+
+impl<T: Config> Module<T> {
+    pub(super) fn remove_project_impl(
+        account: T::AccountId,
+        project_id: ProjectId,
+        witness: ProjectRemovalWitness,
+    ) -> DispatchResult {
+        let project = ProjectMap::<T>::try_get(project_id).map_err(|_| Error::<T>::NoSuchProject)?;
+
+        ensure!(project.team_id == account, Error::<T>::NotProjectOwner);
+
+        // sales of the project offer its security tokens, so there are no sales either
+        let security_tokens = T::AssetSystem::get_project_security_tokens(&project_id);
+        ensure!(security_tokens.is_empty(), Error::<T>::ProjectHasSecurityTokens);
+
+        let is_involved_in_nda = NdaIdByProjectId::iter_prefix(project_id).next().is_some();
+        ensure!(!is_involved_in_nda, Error::<T>::ProjectInvolvedInNda);
+
+        ensure!(
+            witness.covers(&Self::get_project_removal_witness(&project_id)),
+            Error::<T>::ProjectRemovalWitnessInvalid
+        );
+
+        ProjectIdByTeamId::<T>::remove(&project.team_id, project_id);
+        for domain_id in &project.domains {
+            ProjectIdByDomainId::remove(domain_id, project_id);
+        }
+
+        for (content_id, content) in ProjectContentMap::<T>::iter_prefix(project_id) {
+            let references = content.references.unwrap_or_default();
+            Self::remove_project_content_citations(content_id, &references);
+            Self::remove_project_content_history(&content_id);
+            ProjectContentAuthorShares::<T>::remove(content_id);
+            LicenceMap::<T>::remove_prefix(content_id);
+            ProjectIdByProjectContentId::remove(content_id);
+            ProjectContentIdByTeamId::<T>::remove(&content.team_id, content_id);
+            ProjectContentIdByType::remove(&content.content_type, content_id);
+            Self::remove_project_content_reviews(content_id);
+        }
+
+        for (offer_id, _) in LicenceOfferIdByProjectId::iter_prefix(project_id) {
+            LicenceOfferMap::<T>::remove(offer_id);
+        }
+        LicenceOfferIdByProjectId::remove_prefix(project_id);
+
+        ProjectContentMap::<T>::remove_prefix(project_id);
+        ProjectMap::<T>::remove(project_id);
+        ProjectTransferRequests::<T>::remove(project_id);
+
+        Self::deposit_event(RawEvent::ProjectRemoved(account, project));
+
+        Ok(())
+    }
+
+    /// Counts entities `remove_project` removes along with the project
+    pub fn get_project_removal_witness(project_id: &ProjectId) -> ProjectRemovalWitness {
+        let mut witness = ProjectRemovalWitness {
+            domains: ProjectMap::<T>::get(project_id).domains.len() as u32,
+            ..Default::default()
+        };
+
+        for (content_id, content) in ProjectContentMap::<T>::iter_prefix(project_id) {
+            witness.contents += 1;
+            witness.references += content.references.map_or(0, |references| references.len()) as u32;
+            for (review_id, _) in ReviewIdByProjectContentId::iter_prefix(content_id) {
+                witness.reviews += 1;
+                witness.review_domains += ReviewMap::<T>::get(review_id).domains.len() as u32;
+            }
+            witness.revisions += ProjectContentVersionMap::get(content_id);
+        }
+
+        witness.offers = LicenceOfferIdByProjectId::iter_prefix(project_id).count() as u32;
+
+        witness
+    }
+
+    /// Weight of `remove_project` that removes Project Content of the project,
+    /// its reviews and revisions, and licence offers of the project
+    pub(super) fn remove_project_weight(witness: &ProjectRemovalWitness) -> Weight {
+        let contents = witness.contents as Weight;
+        let references = witness.references as Weight;
+        let revisions = witness.revisions as Weight;
+        let reviews = witness.reviews as Weight;
+        let offers = witness.offers as Weight;
+
+        // counting the actual entities reads them as well
+        let reads = 2 * (4 + 3 * contents + references + 2 * reviews + revisions + offers);
+        let writes = 6 + witness.domains as Weight + 8 * contents + references + 3 * reviews
+            + witness.review_domains as Weight + revisions + offers;
+
+        T::DbWeight::get().reads_writes(reads, writes)
+    }
+
+    pub(super) fn transfer_project_impl(
+        account: T::AccountId,
+        project_id: ProjectId,
+        new_team_id: T::AccountId,
+        with_acceptance: bool,
+    ) -> DispatchResult {
+        let project = ProjectMap::<T>::try_get(project_id).map_err(|_| Error::<T>::NoSuchProject)?;
+
+        ensure!(project.team_id == account, Error::<T>::NotProjectOwner);
+        ensure!(project.team_id != new_team_id, Error::<T>::ProjectTransferToSameTeam);
+
+        if with_acceptance {
+            ProjectTransferRequests::<T>::insert(project_id, new_team_id.clone());
+            Self::deposit_event(RawEvent::ProjectTransferRequested(account, project_id, new_team_id));
+            return Ok(());
+        }
+
+        ProjectTransferRequests::<T>::remove(project_id);
+        Self::move_project_to_team(project, new_team_id);
+
+        Ok(())
+    }
+
+    pub(super) fn accept_project_transfer_impl(account: T::AccountId, project_id: ProjectId) -> DispatchResult {
+        let new_team_id = ProjectTransferRequests::<T>::get(project_id)
+            .ok_or(Error::<T>::NoSuchProjectTransferRequest)?;
+
+        ensure!(new_team_id == account, Error::<T>::NoPermission);
+
+        let project = ProjectMap::<T>::try_get(project_id).map_err(|_| Error::<T>::NoSuchProject)?;
+
+        ProjectTransferRequests::<T>::remove(project_id);
+        Self::move_project_to_team(project, new_team_id);
+
+        Ok(())
+    }
+
+    fn move_project_to_team(mut project: ProjectOf<T>, new_team_id: T::AccountId) {
+        let old_team_id = sp_std::mem::replace(&mut project.team_id, new_team_id.clone());
+
+        ProjectIdByTeamId::<T>::remove(&old_team_id, project.external_id);
+        ProjectIdByTeamId::<T>::insert(&new_team_id, project.external_id, ());
+        Self::move_tranches_to_team(project.external_id, &new_team_id);
+
+        ProjectMap::<T>::insert(project.external_id, project.clone());
+
+        Self::deposit_event(RawEvent::ProjectTransferred(old_team_id, project.external_id, new_team_id));
+    }
+}
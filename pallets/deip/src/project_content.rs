@@ -0,0 +1,327 @@
+use crate::*;
+
+use crate::traits::DeipAssetSystem;
+use sp_runtime::{
+    traits::{Saturating, Zero},
+    SaturatedConversion,
+};
+use sp_std::collections::btree_set::BTreeSet;
+
+/// Contribution share of an author in basis points
+pub type AuthorShare = u16;
+
+/// Sum of all author shares of Project Content, i.e. 100%
+pub const AUTHOR_SHARES_TOTAL: AuthorShare = 10_000;
+
+/// Maximum depth the citation graph is traversed to
+pub const MAX_CITATION_DEPTH: u32 = 16;
+
+/// Version of Project Content. Content is created with version 0
+/// and every amendment increments it.
+pub type Version = u32;
+
+/// Previous state of Project Content replaced by an amendment
+#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct Revision<Hash, AccountId> {
+    /// Version the revision was valid for
+    pub version: Version,
+    /// Hash of the content description
+    pub description: Hash,
+    /// Hash of digital asset
+    pub content: Hash,
+    /// Authors of Digital asset
+    pub authors: Vec<AccountId>,
+}
+
+impl<T: Config> Module<T> {
+    pub(super) fn amend_project_content_impl(
+        account: T::AccountId,
+        project_id: ProjectId,
+        content_id: ProjectContentId,
+        description: T::Hash,
+        content: T::Hash,
+        authors: Vec<T::AccountId>,
+    ) -> DispatchResult {
+        let project = ProjectMap::<T>::try_get(project_id).map_err(|_| Error::<T>::NoSuchProject)?;
+        ensure!(project.team_id == account, Error::<T>::ProjectNotBelongToTeam);
+
+        let version = ProjectContentVersionMap::get(content_id);
+        let new_version = version
+            .checked_add(1)
+            .ok_or(Error::<T>::ProjectContentVersionLimitReached)?;
+
+        let authors_changed = ProjectContentMap::<T>::mutate_exists(project_id, content_id, |maybe_content| -> Result<bool, DispatchError> {
+            let project_content = maybe_content.as_mut().ok_or(Error::<T>::NoSuchProjectContent)?;
+
+            // shares stay valid while the set of authors is the same
+            let mut old_authors = project_content.authors.clone();
+            old_authors.sort();
+            old_authors.dedup();
+            let mut new_authors = authors.clone();
+            new_authors.sort();
+            new_authors.dedup();
+            let authors_changed = old_authors != new_authors;
+
+            let revision = Revision {
+                version,
+                description: sp_std::mem::replace(&mut project_content.description, description),
+                content: sp_std::mem::replace(&mut project_content.content, content),
+                authors: sp_std::mem::replace(&mut project_content.authors, authors),
+            };
+            ProjectContentRevisionMap::<T>::insert(content_id, version, revision);
+
+            Ok(authors_changed)
+        })?;
+
+        ProjectContentVersionMap::insert(content_id, new_version);
+
+        if authors_changed && ProjectContentAuthorShares::<T>::contains_key(content_id) {
+            ProjectContentAuthorShares::<T>::remove(content_id);
+            Self::deposit_event(RawEvent::ProjectContentAuthorSharesReset(account.clone(), content_id));
+        }
+
+        Self::deposit_event(RawEvent::ProjectContentAmended(account, content_id, new_version));
+
+        Ok(())
+    }
+
+    pub fn get_project_content_version(
+        project_id: &ProjectId,
+        project_content_id: &ProjectContentId,
+        version: Version,
+    ) -> Option<ProjectContentOf<T>> {
+        if !ProjectContentMap::<T>::contains_key(project_id, project_content_id) {
+            return None;
+        }
+        let mut project_content = ProjectContentMap::<T>::get(project_id, project_content_id);
+
+        if version == ProjectContentVersionMap::get(project_content_id) {
+            return Some(project_content);
+        }
+
+        if !ProjectContentRevisionMap::<T>::contains_key(project_content_id, version) {
+            return None;
+        }
+        let revision = ProjectContentRevisionMap::<T>::get(project_content_id, version);
+        project_content.description = revision.description;
+        project_content.content = revision.content;
+        project_content.authors = revision.authors;
+
+        Some(project_content)
+    }
+
+    pub(super) fn remove_project_content_history(project_content_id: &ProjectContentId) {
+        ProjectContentRevisionMap::<T>::remove_prefix(project_content_id);
+        ProjectContentVersionMap::remove(project_content_id);
+    }
+
+    pub(super) fn set_project_content_author_shares_impl(
+        account: T::AccountId,
+        project_id: ProjectId,
+        content_id: ProjectContentId,
+        shares: Vec<(T::AccountId, AuthorShare)>,
+    ) -> DispatchResult {
+        let project = ProjectMap::<T>::try_get(project_id).map_err(|_| Error::<T>::NoSuchProject)?;
+        ensure!(project.team_id == account, Error::<T>::ProjectNotBelongToTeam);
+
+        ensure!(
+            ProjectContentMap::<T>::contains_key(project_id, content_id),
+            Error::<T>::NoSuchProjectContent
+        );
+        let project_content = ProjectContentMap::<T>::get(project_id, content_id);
+
+        let mut authors = project_content.authors;
+        authors.sort();
+        authors.dedup();
+        let mut share_holders: Vec<_> = shares.iter().map(|(author, _)| author.clone()).collect();
+        share_holders.sort();
+        ensure!(
+            share_holders == authors,
+            Error::<T>::ProjectContentAuthorSharesMustMatchAuthors
+        );
+
+        let total = shares
+            .iter()
+            .fold(0u32, |total, (_, share)| total.saturating_add((*share).into()));
+        ensure!(
+            total == AUTHOR_SHARES_TOTAL.into(),
+            Error::<T>::ProjectContentAuthorSharesMustSumToTotal
+        );
+
+        ProjectContentAuthorShares::<T>::insert(content_id, shares);
+
+        Self::deposit_event(RawEvent::ProjectContentAuthorSharesSet(account, content_id));
+
+        Ok(())
+    }
+
+    pub(super) fn distribute_project_content_revenue_impl(
+        account: T::AccountId,
+        project_id: ProjectId,
+        content_id: ProjectContentId,
+        asset: DeipAssetOf<T>,
+    ) -> DispatchResult {
+        ensure!(
+            ProjectContentMap::<T>::contains_key(project_id, content_id),
+            Error::<T>::NoSuchProjectContent
+        );
+        ensure!(!asset.amount.is_zero(), Error::<T>::ProjectContentRevenueMustBePositive);
+
+        let shares = Self::get_project_content_author_shares(&project_id, &content_id);
+
+        // the rounding remainder goes to the author with the largest share
+        ensure!(!shares.is_empty(), Error::<T>::ProjectContentHasNoAuthors);
+        let mut largest = 0;
+        for (i, (_, share)) in shares.iter().enumerate() {
+            if *share > shares[largest].1 {
+                largest = i;
+            }
+        }
+
+        let mut transfers = Vec::with_capacity(shares.len());
+        let mut remainder = asset.amount;
+        for (i, (author, share)) in shares.iter().enumerate() {
+            if i == largest {
+                continue;
+            }
+            // similiar to frame_support::traits::Imbalance::ration
+            let amount = asset
+                .amount
+                .saturated_into::<u128>()
+                .saturating_mul((*share).into())
+                / u128::from(AUTHOR_SHARES_TOTAL);
+            let amount: DeipAssetBalanceOf<T> = amount.saturated_into();
+            if amount.is_zero() {
+                continue;
+            }
+
+            remainder = remainder.saturating_sub(amount);
+            transfers.push((author.clone(), amount));
+        }
+
+        if !remainder.is_zero() {
+            transfers.push((shares[largest].0.clone(), remainder));
+        }
+
+        T::AssetSystem::transactionally_transfer(&account, asset.id, &transfers)
+            .map_err(|_| Error::<T>::ProjectContentRevenueTransferFailed)?;
+
+        Self::deposit_event(RawEvent::ProjectContentRevenueDistributed(account, content_id, asset));
+
+        Ok(())
+    }
+
+    /// Returns contribution shares of the content authors. Authors share equally
+    /// unless the project team set the shares explicitly.
+    pub fn get_project_content_author_shares(
+        project_id: &ProjectId,
+        project_content_id: &ProjectContentId,
+    ) -> Vec<(T::AccountId, AuthorShare)> {
+        let shares = ProjectContentAuthorShares::<T>::get(project_content_id);
+        if !shares.is_empty() {
+            return shares;
+        }
+
+        let mut authors = Vec::new();
+        for author in ProjectContentMap::<T>::get(project_id, project_content_id).authors {
+            if !authors.contains(&author) {
+                authors.push(author);
+            }
+        }
+        if authors.is_empty() {
+            return Vec::new();
+        }
+
+        let total = u32::from(AUTHOR_SHARES_TOTAL);
+        let share = total / authors.len() as u32;
+        let first_share = total - share * (authors.len() as u32 - 1);
+
+        authors
+            .into_iter()
+            .enumerate()
+            .map(|(i, author)| {
+                let author_share = if i == 0 { first_share } else { share };
+                (author, author_share as AuthorShare)
+            })
+            .collect()
+    }
+
+    pub(super) fn add_project_content_citations(content_id: ProjectContentId, references: &[ProjectContentId]) {
+        for reference in references {
+            ProjectContentCitedBy::mutate(reference, |cited_by| {
+                if let Err(index) = cited_by.binary_search(&content_id) {
+                    cited_by.insert(index, content_id);
+                }
+            });
+        }
+    }
+
+    pub(super) fn remove_project_content_citations(content_id: ProjectContentId, references: &[ProjectContentId]) {
+        for reference in references {
+            ProjectContentCitedBy::mutate_exists(reference, |maybe_cited_by| {
+                if let Some(cited_by) = maybe_cited_by {
+                    if let Ok(index) = cited_by.binary_search(&content_id) {
+                        cited_by.remove(index);
+                    }
+                    if cited_by.is_empty() {
+                        *maybe_cited_by = None;
+                    }
+                }
+            });
+        }
+        ProjectContentCitedBy::remove(content_id);
+    }
+
+    /// Returns Project Content referenced by `project_content_id` directly or
+    /// through other content up to `depth` levels deep.
+    pub fn get_references_of(project_content_id: &ProjectContentId, depth: u32) -> Vec<ProjectContentId> {
+        Self::traverse_citation_graph(project_content_id, depth, |content_id| {
+            ProjectIdByProjectContentId::get(content_id)
+                .and_then(|project_id| ProjectContentMap::<T>::get(project_id, content_id).references)
+                .unwrap_or_default()
+        })
+    }
+
+    /// Returns Project Content that cites `project_content_id` directly or
+    /// through other content up to `depth` levels deep.
+    pub fn get_cited_by(project_content_id: &ProjectContentId, depth: u32) -> Vec<ProjectContentId> {
+        Self::traverse_citation_graph(project_content_id, depth, |content_id| {
+            ProjectContentCitedBy::get(content_id)
+        })
+    }
+
+    fn traverse_citation_graph<F>(
+        project_content_id: &ProjectContentId,
+        depth: u32,
+        neighbours: F,
+    ) -> Vec<ProjectContentId>
+    where
+        F: Fn(&ProjectContentId) -> Vec<ProjectContentId>,
+    {
+        let mut visited = BTreeSet::new();
+        visited.insert(*project_content_id);
+
+        let mut result = Vec::new();
+        let mut level = vec![*project_content_id];
+        for _ in 0..depth.min(MAX_CITATION_DEPTH) {
+            let mut next_level = Vec::new();
+            for content_id in &level {
+                for neighbour in neighbours(content_id) {
+                    if visited.insert(neighbour) {
+                        result.push(neighbour);
+                        next_level.push(neighbour);
+                    }
+                }
+            }
+
+            if next_level.is_empty() {
+                break;
+            }
+            level = next_level;
+        }
+
+        result
+    }
+}
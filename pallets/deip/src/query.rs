@@ -0,0 +1,229 @@
+use crate::*;
+
+use frame_support::storage::unhashed;
+use sp_std::collections::btree_set::BTreeSet;
+
+/// Upper bound of entities returned by a single page request.
+/// Greater `limit` of page requests is capped at this value.
+pub const MAX_PAGE_SIZE: u32 = 100;
+
+/// Criteria to select Projects by
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub enum ProjectFilter<AccountId> {
+    All,
+    ByTeam(AccountId),
+    /// Projects tagged with the Domain or any of its descendants
+    ByDomain(DomainId),
+}
+
+/// Criteria to select Project Content by
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub enum ProjectContentFilter {
+    All,
+    ByProject(ProjectId),
+    ByType(ProjectContentType),
+}
+
+/// Criteria to select Reviews by
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub enum ReviewFilter<AccountId> {
+    All,
+    ByAuthor(AccountId),
+    ByProjectContent(ProjectContentId),
+    /// Reviews tagged with the Domain or any of its descendants
+    ByDomain(DomainId),
+}
+
+/// Criteria to select Investment Opportunities by
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub enum InvestmentOpportunityFilter<AssetId> {
+    All,
+    ByStatus(SimpleCrowdfundingStatus),
+    /// Opportunities raising the asset or offering it as a share
+    ByAsset(AssetId),
+}
+
+/// Iterates over entries of a map in order of ids following `start_id`. The id has to be
+/// the last key of the map stored with the identity hasher, `key_of` gives the storage key
+/// of the entry with the id. The iteration seeks right to `start_id` in the storage
+/// instead of walking through the preceding entries.
+fn iter_from<Id, V>(key_of: impl Fn(&Id) -> Vec<u8>, start_id: &Option<Id>) -> impl Iterator<Item = (Id, V)>
+where
+    Id: Encode + Decode + Default,
+    V: Decode,
+{
+    let default_id = Id::default();
+    let mut prefix = key_of(&default_id);
+    prefix.truncate(prefix.len() - default_id.encoded_size());
+
+    let mut previous_key = start_id.as_ref().map_or_else(|| prefix.clone(), key_of);
+    sp_std::iter::from_fn(move || {
+        let key = sp_io::storage::next_key(&previous_key).filter(|key| key.starts_with(&prefix))?;
+        let id = Id::decode(&mut &key[prefix.len()..]).ok()?;
+        let value = unhashed::get(&key)?;
+        previous_key = key;
+        Some((id, value))
+    })
+}
+
+/// Takes up to `limit` entities from `iter`, `limit` is capped at `MAX_PAGE_SIZE`
+fn page<Id, V>(iter: impl Iterator<Item = (Id, V)>, limit: u32) -> Vec<V> {
+    iter.take(page_size(limit)).map(|(_, value)| value).collect()
+}
+
+fn page_size(limit: u32) -> usize {
+    limit.min(MAX_PAGE_SIZE) as usize
+}
+
+/// Merges pages of ids of several indexes into the first `limit` unique ids in ascending order
+fn merge_pages<Id: Ord>(pages: impl Iterator<Item = Vec<Id>>, limit: u32) -> Vec<Id> {
+    pages
+        .flatten()
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .take(page_size(limit))
+        .collect()
+}
+
+impl<T: Config> Module<T> {
+    /// Returns Projects matching `filter` with ids greater than `start_id`.
+    /// `limit` is capped at `MAX_PAGE_SIZE`
+    pub fn get_projects_page(
+        filter: &ProjectFilter<T::AccountId>,
+        start_id: &Option<ProjectId>,
+        limit: u32,
+    ) -> Vec<ProjectOf<T>> {
+        let project_of = |(project_id, _): (ProjectId, ())| (project_id, ProjectMap::<T>::get(project_id));
+
+        match filter {
+            ProjectFilter::All => page(iter_from(|id| ProjectMap::<T>::hashed_key_for(id), start_id), limit),
+            ProjectFilter::ByTeam(team_id) => page(
+                iter_from(|id| ProjectIdByTeamId::<T>::hashed_key_for(team_id, id), start_id).map(project_of),
+                limit,
+            ),
+            ProjectFilter::ByDomain(domain_id) => {
+                let pages = Self::get_domain_subtree(domain_id).into_iter().map(|domain| {
+                    iter_from(|id| ProjectIdByDomainId::hashed_key_for(domain.external_id, id), start_id)
+                        .map(|(project_id, ())| project_id)
+                        .take(page_size(limit))
+                        .collect::<Vec<_>>()
+                });
+                merge_pages(pages, limit).into_iter().map(ProjectMap::<T>::get).collect()
+            }
+        }
+    }
+
+    /// Returns Project Content matching `filter` with ids greater than `start_id`.
+    /// `limit` is capped at `MAX_PAGE_SIZE`
+    pub fn get_project_content_page(
+        filter: &ProjectContentFilter,
+        start_id: &Option<ProjectContentId>,
+        limit: u32,
+    ) -> Vec<ProjectContentOf<T>> {
+        let content_of = |(content_id, project_id): (ProjectContentId, ProjectId)| {
+            (content_id, ProjectContentMap::<T>::get(project_id, content_id))
+        };
+        let project_content_of = |(content_id, _): (ProjectContentId, ())| {
+            content_of((content_id, ProjectIdByProjectContentId::get(content_id).unwrap_or_default()))
+        };
+
+        match filter {
+            ProjectContentFilter::All => page(
+                iter_from(|id| ProjectIdByProjectContentId::hashed_key_for(id), start_id).map(content_of),
+                limit,
+            ),
+            ProjectContentFilter::ByProject(project_id) => page(
+                iter_from(|id| ProjectContentMap::<T>::hashed_key_for(project_id, id), start_id),
+                limit,
+            ),
+            ProjectContentFilter::ByType(content_type) => page(
+                iter_from(|id| ProjectContentIdByType::hashed_key_for(content_type, id), start_id)
+                    .map(project_content_of),
+                limit,
+            ),
+        }
+    }
+
+    /// Returns NDAs with ids greater than `start_id`. `limit` is capped at `MAX_PAGE_SIZE`
+    pub fn get_nda_page(start_id: &Option<NdaId>, limit: u32) -> Vec<NdaOf<T>> {
+        page(iter_from(|id| NdaMap::<T>::hashed_key_for(id), start_id), limit)
+    }
+
+    pub fn get_investment_opportunity(id: &InvestmentId) -> Option<SimpleCrowdfundingOf<T>> {
+        SimpleCrowdfundingMap::<T>::try_get(id).ok()
+    }
+
+    /// Returns Investment Opportunities matching `filter` with ids greater than `start_id`.
+    /// `limit` is capped at `MAX_PAGE_SIZE`
+    pub fn get_investment_opportunities_page(
+        filter: &InvestmentOpportunityFilter<DeipAssetIdOf<T>>,
+        start_id: &Option<InvestmentId>,
+        limit: u32,
+    ) -> Vec<SimpleCrowdfundingOf<T>> {
+        page(
+            iter_from(|id| SimpleCrowdfundingMap::<T>::hashed_key_for(id), start_id).filter(
+                |(_, sale): &(InvestmentId, SimpleCrowdfundingOf<T>)| match filter {
+                    InvestmentOpportunityFilter::All => true,
+                    InvestmentOpportunityFilter::ByStatus(status) => sale.status == *status,
+                    InvestmentOpportunityFilter::ByAsset(asset_id) => {
+                        sale.asset_id == *asset_id || sale.shares.iter().any(|(id, _)| id == asset_id)
+                    }
+                },
+            ),
+            limit,
+        )
+    }
+    /// Returns contributions to the sale. They are kept until the sale is settled
+    pub fn get_investments(sale_id: &InvestmentId) -> Vec<InvestmentOf<T>> {
+        InvestmentMap::<T>::get(sale_id)
+            .into_iter()
+            .map(|(_, investment)| investment)
+            .collect()
+    }
+
+    /// Returns Investment Opportunities the account has invested in
+    pub fn get_invested_opportunities(investor: &T::AccountId) -> Vec<SimpleCrowdfundingOf<T>> {
+        InvestmentIdByInvestor::<T>::iter_prefix(investor)
+            .filter_map(|(sale_id, _)| SimpleCrowdfundingMap::<T>::try_get(sale_id).ok())
+            .collect()
+    }
+
+    /// Returns Reviews matching `filter` with ids greater than `start_id`.
+    /// `limit` is capped at `MAX_PAGE_SIZE`
+    pub fn get_reviews_page(
+        filter: &ReviewFilter<T::AccountId>,
+        start_id: &Option<ReviewId>,
+        limit: u32,
+    ) -> Vec<ReviewOf<T>> {
+        let review_of = |(review_id, _): (ReviewId, ())| (review_id, ReviewMap::<T>::get(review_id));
+
+        match filter {
+            ReviewFilter::All => page(iter_from(|id| ReviewMap::<T>::hashed_key_for(id), start_id), limit),
+            ReviewFilter::ByAuthor(author) => page(
+                iter_from(|id| ReviewIdByAuthor::<T>::hashed_key_for(author, id), start_id).map(review_of),
+                limit,
+            ),
+            ReviewFilter::ByProjectContent(content_id) => page(
+                iter_from(|id| ReviewIdByProjectContentId::hashed_key_for(content_id, id), start_id).map(review_of),
+                limit,
+            ),
+            ReviewFilter::ByDomain(domain_id) => {
+                let pages = Self::get_domain_subtree(domain_id).into_iter().map(|domain| {
+                    iter_from(|id| ReviewIdByDomainId::hashed_key_for(domain.external_id, id), start_id)
+                        .map(|(review_id, ())| review_id)
+                        .take(page_size(limit))
+                        .collect::<Vec<_>>()
+                });
+                merge_pages(pages, limit).into_iter().map(ReviewMap::<T>::get).collect()
+            }
+        }
+    }
+}
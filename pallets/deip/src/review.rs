@@ -18,19 +18,21 @@ pub struct Vote<AccountId, Moment> {
 #[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
 pub struct Review<Hash, AccountId> {
     /// Reference for external world and uniques control
-    external_id: Id,
+    pub external_id: Id,
     /// Reference to the Team
-    author: AccountId,
+    pub author: AccountId,
     /// Hash of content
-    content: Hash,
+    pub content: Hash,
     /// List of Domains aka tags Project matches
-    domains: Vec<DomainId>,
+    pub domains: Vec<DomainId>,
     /// Model number by which the evaluation is carried out
-    assessment_model: u32,
+    pub assessment_model: u32,
     /// percent in "50.00 %" format
-    weight: Vec<u8>,
+    pub weight: Vec<u8>,
     /// Reference to Project Content
-    project_content_external_id: ProjectContentId,
+    pub project_content_external_id: ProjectContentId,
+    /// Version of Project Content the review was made for
+    pub project_content_version: ProjectContentVersion,
 }
 
 impl<T: Config> Module<T> {
@@ -46,9 +48,7 @@ impl<T: Config> Module<T> {
     ) -> DispatchResult {
         ensure!(!domains.is_empty(), Error::<T>::ReviewNoDomainSpecified);
 
-        for domain in &domains {
-            ensure!(Domains::contains_key(&domain), Error::<T>::DomainNotExists);
-        }
+        Self::ensure_domains_are_usable(&domains)?;
 
         let review = Review {
             external_id,
@@ -58,25 +58,24 @@ impl<T: Config> Module<T> {
             assessment_model,
             weight,
             project_content_external_id,
+            project_content_version: ProjectContentVersionMap::get(project_content_external_id),
         };
 
-        let mut reviews = Reviews::<T>::get();
-        let index_to_insert_review = reviews
-            .binary_search_by_key(&review.external_id, |&(a, _)| a)
-            .err()
-            .ok_or(Error::<T>::ReviewAlreadyExists)?;
-
-        ProjectsContent::<T>::get()
-            .iter()
-            .find(|(id, ..)| id == &review.project_content_external_id)
-            .ok_or(Error::<T>::NoSuchProjectContent)?;
+        ensure!(
+            !ReviewMap::<T>::contains_key(review.external_id),
+            Error::<T>::ReviewAlreadyExists
+        );
 
-        reviews.insert(
-            index_to_insert_review,
-            (review.external_id, review.author.clone()),
+        ensure!(
+            ProjectIdByProjectContentId::contains_key(review.project_content_external_id),
+            Error::<T>::NoSuchProjectContent
         );
-        Reviews::<T>::put(reviews);
 
+        ReviewIdByAuthor::<T>::insert(&review.author, review.external_id, ());
+        ReviewIdByProjectContentId::insert(review.project_content_external_id, review.external_id, ());
+        for domain_id in &review.domains {
+            ReviewIdByDomainId::insert(domain_id, review.external_id, ());
+        }
         ReviewMap::<T>::insert(review.external_id, review.clone());
 
         Self::deposit_event(RawEvent::ReviewCreated(account, review));
@@ -97,7 +96,7 @@ impl<T: Config> Module<T> {
         let review =
             ReviewMap::<T>::try_get(review_id).map_err(|_| Error::<T>::ReviewVoteNoSuchReview)?;
         ensure!(
-            review.domains.contains(&domain_id),
+            Self::is_domain_matched(&review.domains, &domain_id),
             Error::<T>::ReviewVoteUnrelatedDomain
         );
 
@@ -118,4 +117,29 @@ impl<T: Config> Module<T> {
 
         Ok(())
     }
+
+    pub(super) fn remove_project_content_reviews(content_id: ProjectContentId) {
+        for (review_id, _) in ReviewIdByProjectContentId::iter_prefix(content_id) {
+            let review = ReviewMap::<T>::take(review_id);
+            ReviewIdByAuthor::<T>::remove(&review.author, review_id);
+            for domain_id in &review.domains {
+                ReviewIdByDomainId::remove(domain_id, review_id);
+            }
+        }
+        ReviewIdByProjectContentId::remove_prefix(content_id);
+    }
+
+    pub(super) fn merge_reviews_domain(from: &DomainId, into: &DomainId) {
+        for (review_id, _) in ReviewIdByDomainId::iter_prefix(from) {
+            ReviewMap::<T>::mutate(review_id, |review| {
+                Self::replace_domain(&mut review.domains, from, into)
+            });
+            ReviewIdByDomainId::insert(into, review_id, ());
+        }
+        ReviewIdByDomainId::remove_prefix(from);
+    }
+
+    pub(super) fn is_domain_used_by_reviews(domain_id: &DomainId) -> bool {
+        ReviewIdByDomainId::iter_prefix(domain_id).next().is_some()
+    }
 }
@@ -0,0 +1,137 @@
+use crate::*;
+
+use sp_runtime::SaturatedConversion;
+use sp_std::collections::btree_set::BTreeSet;
+
+/// Moment a transition is due encoded big-endian, so the identity hasher
+/// keeps scheduled transitions in storage ordered by it
+pub type DueMoment = [u8; 8];
+
+impl<T: Config> Module<T> {
+    /// Schedules the transition of the sale due at `time`
+    pub(super) fn schedule_transition(time: T::Moment, sale_id: InvestmentId) {
+        ScheduledTransitions::insert(Self::due_moment(time), sale_id, ());
+    }
+
+    fn due_moment(time: T::Moment) -> DueMoment {
+        time.saturated_into::<u64>().to_be_bytes()
+    }
+
+    /// Performs transitions that are due by the timestamp of the previous block.
+    /// At most `MaxTransitionsPerBlock` are performed within `MaxScheduledWeight`,
+    /// the rest wait for next blocks. A settlement that doesn't fit the budget at all
+    /// is left to the call submitted by the offchain worker.
+    pub(super) fn process_scheduled_transitions() -> Weight {
+        let now = pallet_timestamp::Module::<T>::get();
+        let now_moment = Self::due_moment(now);
+        let max = T::MaxTransitionsPerBlock::get() as usize;
+        let budget = T::MaxScheduledWeight::get();
+
+        let transitions: Vec<_> = ScheduledTransitions::iter()
+            .take(max)
+            .take_while(|(due, ..)| *due <= now_moment)
+            .collect();
+
+        // the timestamp and the first key that isn't due
+        let mut weight = T::DbWeight::get().reads(2);
+        for (moment, sale_id, _) in transitions {
+            let sale = SimpleCrowdfundingMap::<T>::try_get(sale_id).ok();
+            // the key of the transition and the sale
+            let cost = T::DbWeight::get().reads_writes(2, 1)
+                .saturating_add(Self::transition_weight(sale.as_ref(), now));
+            if weight.saturating_add(cost) > budget {
+                break;
+            }
+
+            ScheduledTransitions::remove(moment, sale_id);
+            if let Some(sale) = sale {
+                Self::process_transition(sale, now);
+            }
+            weight = weight.saturating_add(cost);
+        }
+
+        weight
+    }
+
+    /// Submits calls performing transitions at the head of the schedule. At most
+    /// `MaxTransitionsPerBlock` of them are looked at.
+    pub(super) fn process_scheduled_transitions_offchain() {
+        let now = pallet_timestamp::Module::<T>::get();
+        let now_moment = Self::due_moment(now);
+        let max = T::MaxTransitionsPerBlock::get() as usize;
+
+        let mut submitted = BTreeSet::new();
+        let transitions = ScheduledTransitions::iter()
+            .take(max)
+            .take_while(|(due, ..)| *due <= now_moment);
+        for (_, sale_id, _) in transitions {
+            if !submitted.insert(sale_id) {
+                continue;
+            }
+            let sale = match SimpleCrowdfundingMap::<T>::try_get(sale_id) {
+                Ok(sale) => sale,
+                Err(_) => continue,
+            };
+
+            let call = match sale.status {
+                SimpleCrowdfundingStatus::Inactive if now >= sale.start_time => Call::activate_crowdfunding(sale_id),
+                SimpleCrowdfundingStatus::Active if now < sale.end_time => continue,
+                SimpleCrowdfundingStatus::Active if sale.total_amount < sale.soft_cap => Call::expire_crowdfunding(sale_id),
+                SimpleCrowdfundingStatus::Active => Call::finish_crowdfunding(sale_id),
+                _ => continue,
+            };
+            let submit = SubmitTransaction::<T, Call<T>>::submit_unsigned_transaction(call.into());
+            debug!("submit scheduled transition: {}", submit.is_ok());
+        }
+    }
+
+    fn process_transition(sale: SimpleCrowdfundingOf<T>, now: T::Moment) {
+        let sale_id = sale.external_id;
+        match sale.status {
+            SimpleCrowdfundingStatus::Inactive => {
+                let result = Self::activate_crowdfunding_impl(sale_id);
+                debug!("scheduled activate_crowdfunding: {}", result.is_ok());
+                if result.is_ok() {
+                    Self::schedule_transition(sale.end_time, sale_id);
+                }
+            }
+            // the sale was activated by the call submitted by the offchain worker
+            SimpleCrowdfundingStatus::Active if now < sale.end_time => {
+                Self::schedule_transition(sale.end_time, sale_id);
+            }
+            SimpleCrowdfundingStatus::Active => {
+                let result = if sale.total_amount < sale.soft_cap {
+                    Self::expire_crowdfunding_impl(sale_id)
+                } else {
+                    Self::finish_crowdfunding_impl(sale_id)
+                };
+                debug!("scheduled settlement: {}", result.is_ok());
+            }
+            // finished by reaching the hard cap, canceled or already processed
+            _ => (),
+        }
+    }
+
+    /// Weight of the transition of the sale besides reading it
+    fn transition_weight(sale: Option<&SimpleCrowdfundingOf<T>>, now: T::Moment) -> Weight {
+        match sale {
+            Some(sale) => match sale.status {
+                SimpleCrowdfundingStatus::Inactive => T::DbWeight::get().reads_writes(2, 2),
+                SimpleCrowdfundingStatus::Active if now < sale.end_time => T::DbWeight::get().writes(1),
+                SimpleCrowdfundingStatus::Active => Self::settlement_weight(sale),
+                _ => 0,
+            },
+            None => 0,
+        }
+    }
+
+    /// Weight of the settlement of the sale: every contribution or bid of the auction
+    /// gets its shares or funds transferred from the reserve
+    fn settlement_weight(sale: &SimpleCrowdfundingOf<T>) -> Weight {
+        let contributions = InvestmentMap::<T>::decode_len(sale.external_id).unwrap_or_default()
+            + DutchAuctionBids::<T>::decode_len(sale.external_id).unwrap_or_default();
+        let transfers = (contributions * (sale.shares.len() + 1)) as Weight;
+        // a transfer reads and updates balances of both accounts and the asset
+        T::DbWeight::get().reads_writes(4 + 3 * transfers, 3 + 3 * transfers)
+    }
+}
@@ -18,7 +18,7 @@ fn create_ok_project(maybe_account_id: Option<<Test as system::Config>::AccountI
     let account_id: <Test as system::Config>::AccountId = maybe_account_id.unwrap_or(DEFAULT_ACCOUNT_ID);
     let project_id = ProjectId::random();
 
-    assert_ok!(Deip::add_domain(Origin::signed(account_id), Domain { external_id: domain_id.clone() }));
+    assert_ok!(Deip::add_domain(Origin::root(), Domain { external_id: domain_id.clone(), ..Default::default() }));
 
     let project = ProjectOf::<Test> {
         is_private: false,
@@ -170,12 +170,17 @@ fn decode_validate_deip_call(encoded: &[u8]) -> crate::Call<Test> {
 #[test]
 fn add_domain() {
     new_test_ext().execute_with(|| {
+        // events aren't deposited at the genesis block
+        System::set_block_number(1);
         let domain_id = DomainId::random();
         // Dispatch a signed add domian extrinsic.
-        assert_ok!(Deip::add_domain(Origin::signed(DEFAULT_ACCOUNT_ID), Domain { external_id: domain_id.clone() }));
+        assert_ok!(Deip::add_domain(Origin::root(), Domain { external_id: domain_id.clone(), ..Default::default() }));
         
         // Read pallet storage and assert an expected result.
         assert_eq!(Deip::domain_count(), 1);
+        // the root origin has no account
+        let expected_event = mock::Event::pallet_deip(RawEvent::DomainAdded(None, domain_id));
+        assert!(System::events().iter().any(|record| record.event == expected_event));
         assert!(
             <Domains>::contains_key(domain_id),
             "Domains did not contain domain, value was `{}`",
@@ -189,15 +194,203 @@ fn cant_add_duplicate_domain() {
     new_test_ext().execute_with(|| {
         let domain_id = DomainId::random();
         
-        assert_ok!(Deip::add_domain(Origin::signed(DEFAULT_ACCOUNT_ID), Domain { external_id: domain_id.clone() }));
+        assert_ok!(Deip::add_domain(Origin::root(), Domain { external_id: domain_id.clone(), ..Default::default() }));
 
         assert_noop!(
-            Deip::add_domain(Origin::signed(DEFAULT_ACCOUNT_ID), Domain { external_id: domain_id.clone() }),
+            Deip::add_domain(Origin::root(), Domain { external_id: domain_id.clone(), ..Default::default() }),
             Error::<Test>::DomainAlreadyExists
         );
     })
 }
 
+#[test]
+fn hierarchical_domains() {
+    new_test_ext().execute_with(|| {
+        let physics = DomainId::random();
+        let quantum_physics = DomainId::random();
+        let optics = DomainId::random();
+        let biology = DomainId::random();
+
+        for (external_id, parent_id) in vec![
+            (physics, None),
+            (quantum_physics, Some(physics)),
+            (optics, Some(physics)),
+            (biology, None),
+        ] {
+            assert_ok!(Deip::add_domain(Origin::root(), Domain {
+                external_id,
+                parent_id,
+                name: H256::random(),
+                metadata: H256::random(),
+            }));
+        }
+
+        assert_noop!(
+            Deip::add_domain(Origin::root(), Domain {
+                external_id: DomainId::random(),
+                parent_id: Some(DomainId::random()),
+                ..Default::default()
+            }),
+            Error::<Test>::NoSuchParentDomain
+        );
+
+        let subtree: Vec<_> = Deip::get_domain_subtree(&physics).into_iter().map(|d| d.external_id).collect();
+        assert_eq!(subtree, vec![physics, quantum_physics, optics]);
+        assert!(Deip::get_domain_subtree(&DomainId::random()).is_empty());
+
+        let project_id = ProjectId::random();
+        assert_ok!(Deip::create_project(Origin::signed(DEFAULT_ACCOUNT_ID),
+            false,
+            project_id,
+            DEFAULT_ACCOUNT_ID,
+            H256::random(),
+            vec![quantum_physics]));
+        let project_content_id = create_ok_project_content(project_id, vec![DEFAULT_ACCOUNT_ID]);
+
+        // the parent domain is matched through the project child domain
+        let review_id = ReviewId::random();
+        assert_ok!(Deip::create_review(Origin::signed(ALICE_ACCOUNT_ID),
+            review_id,
+            ALICE_ACCOUNT_ID,
+            H256::random(),
+            vec![physics],
+            0,
+            vec![],
+            project_content_id));
+
+        assert_noop!(
+            Deip::upvote_review(Origin::signed(BOB_ACCOUNT_ID), review_id, quantum_physics),
+            Error::<Test>::ReviewVoteUnrelatedDomain
+        );
+        assert_noop!(
+            Deip::upvote_review(Origin::signed(BOB_ACCOUNT_ID), review_id, biology),
+            Error::<Test>::ReviewVoteUnrelatedDomain
+        );
+        assert_ok!(Deip::upvote_review(Origin::signed(BOB_ACCOUNT_ID), review_id, physics));
+    })
+}
+
+#[test]
+fn cant_manage_domains_without_admin_origin() {
+    new_test_ext().execute_with(|| {
+        let domain_id = DomainId::random();
+
+        assert_noop!(
+            Deip::add_domain(Origin::signed(DEFAULT_ACCOUNT_ID), Domain { external_id: domain_id, ..Default::default() }),
+            sp_runtime::DispatchError::BadOrigin
+        );
+
+        assert_ok!(Deip::add_domain(Origin::root(), Domain { external_id: domain_id, ..Default::default() }));
+
+        assert_noop!(
+            Deip::deprecate_domain(Origin::signed(DEFAULT_ACCOUNT_ID), domain_id),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_noop!(
+            Deip::remove_domain(Origin::signed(DEFAULT_ACCOUNT_ID), domain_id),
+            sp_runtime::DispatchError::BadOrigin
+        );
+    })
+}
+
+#[test]
+fn deprecate_domain() {
+    new_test_ext().execute_with(|| {
+        let (project_id, _, domain_id, ..) = create_ok_project(None);
+        let project_content_id = create_ok_project_content(project_id, vec![DEFAULT_ACCOUNT_ID]);
+
+        assert_ok!(Deip::deprecate_domain(Origin::root(), domain_id));
+        assert_noop!(
+            Deip::deprecate_domain(Origin::root(), domain_id),
+            Error::<Test>::DomainAlreadyDeprecated
+        );
+
+        assert_noop!(
+            Deip::create_project(Origin::signed(DEFAULT_ACCOUNT_ID),
+                false,
+                ProjectId::random(),
+                DEFAULT_ACCOUNT_ID,
+                H256::random(),
+                vec![domain_id]),
+            Error::<Test>::DomainIsDeprecated
+        );
+
+        assert_noop!(
+            Deip::create_review(Origin::signed(ALICE_ACCOUNT_ID),
+                ReviewId::random(),
+                ALICE_ACCOUNT_ID,
+                H256::random(),
+                vec![domain_id],
+                0,
+                vec![],
+                project_content_id),
+            Error::<Test>::DomainIsDeprecated
+        );
+    })
+}
+
+#[test]
+fn merge_domains() {
+    new_test_ext().execute_with(|| {
+        let (project_id, _, from, ..) = create_ok_project(None);
+        let project_content_id = create_ok_project_content(project_id, vec![DEFAULT_ACCOUNT_ID]);
+
+        let review_id = ReviewId::random();
+        assert_ok!(Deip::create_review(Origin::signed(ALICE_ACCOUNT_ID),
+            review_id,
+            ALICE_ACCOUNT_ID,
+            H256::random(),
+            vec![from],
+            0,
+            vec![],
+            project_content_id));
+
+        let child = DomainId::random();
+        assert_ok!(Deip::add_domain(Origin::root(), Domain { external_id: child, parent_id: Some(from), ..Default::default() }));
+
+        let into = DomainId::random();
+        assert_ok!(Deip::add_domain(Origin::root(), Domain { external_id: into, ..Default::default() }));
+
+        assert_noop!(
+            Deip::merge_domains(Origin::root(), from, child, Default::default()),
+            Error::<Test>::DomainMergeIntoDescendant
+        );
+        assert_noop!(
+            Deip::remove_domain(Origin::root(), from),
+            Error::<Test>::DomainHasChildren
+        );
+
+        let witness = Deip::get_domain_merge_witness(&from);
+        assert_eq!(witness, DomainMergeWitness { projects: 1, reviews: 1, children: 1 });
+        assert_noop!(
+            Deip::merge_domains(Origin::root(), from, into, DomainMergeWitness { children: 0, ..witness }),
+            Error::<Test>::DomainMergeWitnessInvalid
+        );
+
+        assert_ok!(Deip::merge_domains(Origin::root(), from, into, witness));
+
+        assert!(!Domains::contains_key(from));
+        assert_eq!(Deip::domain_count(), 3 - 1);
+        assert_eq!(ProjectMap::<Test>::get(project_id).domains, vec![into]);
+        assert_eq!(ReviewMap::<Test>::get(review_id).domains, vec![into]);
+        assert!(ProjectIdByDomainId::contains_key(into, project_id));
+        assert!(ReviewIdByDomainId::contains_key(into, review_id));
+        assert!(ProjectIdByDomainId::iter_prefix(from).next().is_none());
+        assert_eq!(Deip::get_domain(&child).parent_id, Some(into));
+        assert_eq!(Deip::domain_children(into), vec![child]);
+
+        assert_noop!(
+            Deip::remove_domain(Origin::root(), into),
+            Error::<Test>::DomainHasChildren
+        );
+        assert_ok!(Deip::remove_domain(Origin::root(), child));
+        assert_noop!(
+            Deip::remove_domain(Origin::root(), into),
+            Error::<Test>::DomainInUse
+        );
+    })
+}
+
 #[test]
 fn add_project() {
     new_test_ext().execute_with(|| {
@@ -212,7 +405,6 @@ fn add_project() {
         //     expected_event,
         // );
 
-        let projects = Projects::<Test>::get();
         let project_stored = ProjectMap::<Test>::get(project_id);
 
         assert!(
@@ -224,8 +416,8 @@ fn add_project() {
         assert_eq!(project, project_stored);
 
         assert!(
-            projects.binary_search_by_key(&project_id, |&(external_id, ..)| external_id).is_ok(),
-            "Projects did not contain project, value was `{}`",
+            ProjectIdByTeamId::<Test>::contains_key(project.team_id, project_id),
+            "Projects of the team did not contain project, value was `{}`",
             project_id
         );
 
@@ -312,6 +504,159 @@ fn cant_update_not_existed_project() {
     })
 }
 
+#[test]
+fn remove_project() {
+    new_test_ext().execute_with(|| {
+        let (project_id, _, domain_id, _) = create_ok_project(None);
+        let project_content_id = ProjectContentId::random();
+
+        assert_ok!(Deip::create_project_content(Origin::signed(DEFAULT_ACCOUNT_ID),
+            project_content_id,
+            project_id,
+            DEFAULT_ACCOUNT_ID,
+            ProjectContentType::Announcement,
+            H256::random(),
+            H256::random(),
+            vec![DEFAULT_ACCOUNT_ID],
+            None));
+
+        let review_id = ReviewId::random();
+        assert_ok!(Deip::create_review(Origin::signed(ALICE_ACCOUNT_ID),
+            review_id,
+            ALICE_ACCOUNT_ID,
+            H256::random(),
+            vec![domain_id],
+            0,
+            vec![],
+            project_content_id));
+
+        let offer_id = LicenceOfferId::random();
+        assert_ok!(Deip::create_licence_offer(Origin::signed(DEFAULT_ACCOUNT_ID),
+            offer_id,
+            project_id,
+            project_content_id,
+            DeipAsset::new(0u32, 100u64),
+            None,
+            H256::random()));
+
+        let witness = Deip::get_project_removal_witness(&project_id);
+        assert_eq!(witness, ProjectRemovalWitness {
+            domains: 1,
+            contents: 1,
+            reviews: 1,
+            review_domains: 1,
+            offers: 1,
+            ..Default::default()
+        });
+
+        assert_noop!(
+            Deip::remove_project(
+                Origin::signed(DEFAULT_ACCOUNT_ID),
+                project_id,
+                ProjectRemovalWitness { reviews: 0, ..witness }
+            ),
+            Error::<Test>::ProjectRemovalWitnessInvalid
+        );
+
+        assert_ok!(Deip::remove_project(Origin::signed(DEFAULT_ACCOUNT_ID), project_id, witness));
+
+        assert!(!<ProjectMap<Test>>::contains_key(project_id));
+        assert!(!<ProjectContentMap<Test>>::contains_key(project_id, project_content_id));
+        assert!(!ProjectIdByTeamId::<Test>::contains_key(DEFAULT_ACCOUNT_ID, project_id));
+        assert!(!ProjectIdByProjectContentId::contains_key(project_content_id));
+        assert!(!ProjectContentIdByTeamId::<Test>::contains_key(DEFAULT_ACCOUNT_ID, project_content_id));
+        assert!(!ReviewMap::<Test>::contains_key(review_id));
+        assert!(!ReviewIdByAuthor::<Test>::contains_key(ALICE_ACCOUNT_ID, review_id));
+        assert!(!ReviewIdByProjectContentId::contains_key(project_content_id, review_id));
+        assert!(LicenceOfferMap::<Test>::get(offer_id).is_none());
+        assert!(!LicenceOfferIdByProjectId::contains_key(project_id, offer_id));
+    })
+}
+
+#[test]
+fn cant_remove_project_not_belonged_to_your_signature() {
+    new_test_ext().execute_with(|| {
+        let (project_id, ..) = create_ok_project(None);
+
+        assert_noop!(
+            Deip::remove_project(Origin::signed(ALICE_ACCOUNT_ID), project_id, Default::default()),
+            Error::<Test>::NotProjectOwner
+        );
+    })
+}
+
+#[test]
+fn cant_remove_project_with_security_tokens() {
+    new_test_ext2().execute_with(|| {
+        let (project_id, .., account_id) = create_ok_project(None);
+
+        create_issue_asset(account_id, 0u32, 1_000u64, Some(project_id));
+
+        assert_noop!(
+            Deip::remove_project(Origin::signed(account_id), project_id, Default::default()),
+            Error::<Test>::ProjectHasSecurityTokens
+        );
+    })
+}
+
+#[test]
+fn cant_remove_project_involved_in_nda() {
+    new_test_ext().execute_with(|| {
+        let (project_nda_id, ..) = create_ok_nda();
+        let project_id = NdaMap::<Test>::get(project_nda_id).projects[0];
+
+        assert_noop!(
+            Deip::remove_project(Origin::signed(DEFAULT_ACCOUNT_ID), project_id, Default::default()),
+            Error::<Test>::ProjectInvolvedInNda
+        );
+    })
+}
+
+#[test]
+fn transfer_project() {
+    new_test_ext2().execute_with(|| {
+        let (project_id, .., account_id) = create_ok_project(None);
+
+        assert_ok!(Deip::transfer_project(Origin::signed(account_id), project_id, ALICE_ACCOUNT_ID, false));
+
+        assert_eq!(ProjectMap::<Test>::get(project_id).team_id, ALICE_ACCOUNT_ID);
+        assert!(ProjectIdByTeamId::<Test>::contains_key(ALICE_ACCOUNT_ID, project_id));
+        assert!(!ProjectIdByTeamId::<Test>::contains_key(account_id, project_id));
+
+        // security tokens can be issued only by the new team
+        let call = pallet_deip_assets::Call::<Test>::create_asset(0u32, account_id, 100u32, 1u32.into(), Some(project_id));
+        assert_noop!(
+            call.dispatch_bypass_filter(Origin::signed(account_id)),
+            pallet_deip_assets::Error::<Test>::ProjectDoesNotBelongToTeam
+        );
+        create_issue_asset(ALICE_ACCOUNT_ID, 0u32, 1_000u64, Some(project_id));
+    })
+}
+
+#[test]
+fn transfer_project_with_acceptance() {
+    new_test_ext().execute_with(|| {
+        let (project_id, .., account_id) = create_ok_project(None);
+
+        assert_ok!(Deip::transfer_project(Origin::signed(account_id), project_id, ALICE_ACCOUNT_ID, true));
+        assert_eq!(ProjectMap::<Test>::get(project_id).team_id, account_id);
+
+        assert_noop!(
+            Deip::accept_project_transfer(Origin::signed(BOB_ACCOUNT_ID), project_id),
+            Error::<Test>::NoPermission
+        );
+
+        assert_ok!(Deip::accept_project_transfer(Origin::signed(ALICE_ACCOUNT_ID), project_id));
+        assert_eq!(ProjectMap::<Test>::get(project_id).team_id, ALICE_ACCOUNT_ID);
+        assert_eq!(Deip::project_transfer_request(project_id), None);
+
+        assert_noop!(
+            Deip::accept_project_transfer(Origin::signed(ALICE_ACCOUNT_ID), project_id),
+            Error::<Test>::NoSuchProjectTransferRequest
+        );
+    })
+}
+
 #[test]
 fn create_project_content() {
     new_test_ext().execute_with(|| {
@@ -328,8 +673,6 @@ fn create_project_content() {
             vec![DEFAULT_ACCOUNT_ID],
             None));
 
-        let project_content_list = ProjectsContent::<Test>::get();
-
         assert!(
             <ProjectContentMap<Test>>::contains_key(project_id, project_content_id),
             "Project Content Map did not contain key, value was `{}{}`",
@@ -337,9 +680,10 @@ fn create_project_content() {
             project_content_id
         );
 
-        assert!(
-            project_content_list.binary_search_by_key(&project_content_id, |&(external_id, ..)| external_id).is_ok(),
-            "Projects Contntent List did not contain the content, value was `{}`",
+        assert_eq!(
+            ProjectIdByProjectContentId::get(project_content_id),
+            Some(project_id),
+            "Project Content index did not contain the content, value was `{}`",
             project_content_id
         );
     })
@@ -376,8 +720,6 @@ fn create_project_content_with_references() {
             vec![DEFAULT_ACCOUNT_ID],
             Some(vec![project_content_id])));
 
-        let project_content_list = ProjectsContent::<Test>::get();
-
         assert!(
             <ProjectContentMap<Test>>::contains_key(project_id, project_content_with_reference_id),
             "Project Content Map did not contain key, value was `{}{}`",
@@ -385,14 +727,65 @@ fn create_project_content_with_references() {
             project_content_with_reference_id
         );
 
-        assert!(
-            project_content_list.binary_search_by_key(&project_content_with_reference_id, |&(external_id, ..)| external_id).is_ok(),
-            "Projects Contntent List did not contain the content, value was `{}`",
+        assert_eq!(
+            ProjectIdByProjectContentId::get(project_content_with_reference_id),
+            Some(project_id),
+            "Project Content index did not contain the content, value was `{}`",
             project_content_with_reference_id
         );
     })
 }
 
+#[test]
+fn project_content_citation_graph() {
+    new_test_ext().execute_with(|| {
+        let (project_id, ..) = create_ok_project(None);
+
+        // article <- review <- [summary, digest]
+        let article = ProjectContentId::random();
+        let review = ProjectContentId::random();
+        let summary = ProjectContentId::random();
+        let digest = ProjectContentId::random();
+
+        for (content_id, references) in vec![
+            (article, None),
+            (review, Some(vec![article])),
+            (summary, Some(vec![review, article])),
+            (digest, Some(vec![review])),
+        ] {
+            assert_ok!(Deip::create_project_content(Origin::signed(DEFAULT_ACCOUNT_ID),
+                content_id,
+                project_id,
+                DEFAULT_ACCOUNT_ID,
+                ProjectContentType::Announcement,
+                H256::random(),
+                H256::random(),
+                vec![DEFAULT_ACCOUNT_ID],
+                references));
+        }
+
+        let mut cited_by = vec![review, summary];
+        cited_by.sort();
+        assert_eq!(ProjectContentCitedBy::get(article), cited_by);
+
+        assert_eq!(Deip::get_references_of(&digest, 1), vec![review]);
+        assert_eq!(Deip::get_references_of(&digest, 2), vec![review, article]);
+        assert!(Deip::get_references_of(&article, 2).is_empty());
+
+        let mut direct = Deip::get_cited_by(&article, 1);
+        direct.sort();
+        assert_eq!(direct, cited_by);
+
+        let mut all = Deip::get_cited_by(&article, MAX_CITATION_DEPTH + 1);
+        all.sort();
+        let mut expected = vec![review, summary, digest];
+        expected.sort();
+        assert_eq!(all, expected);
+
+        assert!(Deip::get_cited_by(&article, 0).is_empty());
+    })
+}
+
 #[test]
 fn cant_add_duplicated_project_content() {
     new_test_ext().execute_with(|| {
@@ -520,52 +913,360 @@ fn cant_add_project_content_with_wrong_references() {
 }
 
 #[test]
-fn create_project_nda() {
+fn amend_project_content() {
     new_test_ext().execute_with(|| {
-        let (project_nda_id, expected_nda) = create_ok_nda();
+        let (project_id, _, domain_id, ..) = create_ok_project(None);
+        let project_content_id = ProjectContentId::random();
+        let description = H256::random();
+        let content = H256::random();
 
-        let nda_list = Ndas::<Test>::get();
-        let nda_stored = NdaMap::<Test>::get(project_nda_id);
+        assert_ok!(Deip::create_project_content(Origin::signed(DEFAULT_ACCOUNT_ID),
+            project_content_id,
+            project_id,
+            DEFAULT_ACCOUNT_ID,
+            ProjectContentType::Announcement,
+            description,
+            content,
+            vec![DEFAULT_ACCOUNT_ID],
+            None));
 
-        assert!(
-            <NdaMap<Test>>::contains_key(project_nda_id),
-            "NDA Map did not contain key, value was `{}`",
-            project_nda_id
-        );
+        let new_description = H256::random();
+        let new_content = H256::random();
 
-        assert_eq!(expected_nda, nda_stored);
+        assert_ok!(Deip::amend_project_content(Origin::signed(DEFAULT_ACCOUNT_ID),
+            project_id,
+            project_content_id,
+            new_description,
+            new_content,
+            vec![DEFAULT_ACCOUNT_ID, ALICE_ACCOUNT_ID]));
 
-        assert!(
-            nda_list.binary_search_by_key(&project_nda_id, |&(external_id, ..)| external_id).is_ok(),
-            "NDA List did not contain the NDA, value was `{}`",
-            project_nda_id
-        );
+        assert_eq!(Deip::project_content_version(project_content_id), 1);
+
+        let latest = ProjectContentMap::<Test>::get(project_id, project_content_id);
+        assert_eq!(latest.description, new_description);
+        assert_eq!(latest.content, new_content);
+        assert_eq!(latest.authors, vec![DEFAULT_ACCOUNT_ID, ALICE_ACCOUNT_ID]);
+        assert_eq!(Deip::get_project_content_version(&project_id, &project_content_id, 1), Some(latest));
+
+        let original = Deip::get_project_content_version(&project_id, &project_content_id, 0).unwrap();
+        assert_eq!(original.description, description);
+        assert_eq!(original.content, content);
+        assert_eq!(original.authors, vec![DEFAULT_ACCOUNT_ID]);
+
+        assert_eq!(Deip::get_project_content_version(&project_id, &project_content_id, 2), None);
+
+        let review_id = ReviewId::random();
+        assert_ok!(Deip::create_review(Origin::signed(ALICE_ACCOUNT_ID),
+            review_id,
+            ALICE_ACCOUNT_ID,
+            H256::random(),
+            vec![domain_id],
+            0,
+            vec![],
+            project_content_id));
+
+        assert_eq!(ReviewMap::<Test>::get(review_id).project_content_version, 1);
+        assert!(ReviewIdByAuthor::<Test>::contains_key(ALICE_ACCOUNT_ID, review_id));
+        assert!(ReviewIdByProjectContentId::contains_key(project_content_id, review_id));
     })
 }
 
 #[test]
-fn cant_create_project_nda_ends_in_past() {
+fn cant_amend_project_content_of_another_team() {
     new_test_ext().execute_with(|| {
         let (project_id, ..) = create_ok_project(None);
-        let project_nda_id =  NdaId::random();        
-        let end_date = 0;
+        let project_content_id = ProjectContentId::random();
 
-        let contract_hash = H256::random();
-        let maybe_start_date = None;
-        let parties = vec![DEFAULT_ACCOUNT_ID];
-        let projects = vec![project_id];
+        assert_ok!(Deip::create_project_content(Origin::signed(DEFAULT_ACCOUNT_ID),
+            project_content_id,
+            project_id,
+            DEFAULT_ACCOUNT_ID,
+            ProjectContentType::Announcement,
+            H256::random(),
+            H256::random(),
+            vec![DEFAULT_ACCOUNT_ID],
+            None));
 
         assert_noop!(
-            Deip::create_project_nda(
-                Origin::signed(DEFAULT_ACCOUNT_ID), 
-                project_nda_id, 
-                end_date, 
-                contract_hash, 
-                maybe_start_date,
-                parties.clone(),
-                projects.clone()
-            ),
-            Error::<Test>::NdaEndDateMustBeLaterCurrentMoment
+            Deip::amend_project_content(Origin::signed(ALICE_ACCOUNT_ID),
+                project_id,
+                project_content_id,
+                H256::random(),
+                H256::random(),
+                vec![ALICE_ACCOUNT_ID]),
+            Error::<Test>::ProjectNotBelongToTeam
+        );
+
+        assert_noop!(
+            Deip::amend_project_content(Origin::signed(DEFAULT_ACCOUNT_ID),
+                project_id,
+                ProjectContentId::random(),
+                H256::random(),
+                H256::random(),
+                vec![DEFAULT_ACCOUNT_ID]),
+            Error::<Test>::NoSuchProjectContent
+        );
+    })
+}
+
+fn create_ok_project_content(project_id: ProjectId, authors: Vec<AccountIdOf<Test>>) -> ProjectContentId {
+    let project_content_id = ProjectContentId::random();
+
+    assert_ok!(Deip::create_project_content(Origin::signed(DEFAULT_ACCOUNT_ID),
+        project_content_id,
+        project_id,
+        DEFAULT_ACCOUNT_ID,
+        ProjectContentType::Announcement,
+        H256::random(),
+        H256::random(),
+        authors,
+        None));
+
+    project_content_id
+}
+
+#[test]
+fn distribute_project_content_revenue() {
+    new_test_ext2().execute_with(|| {
+        let (project_id, ..) = create_ok_project(None);
+        let project_content_id = create_ok_project_content(project_id, vec![ALICE_ACCOUNT_ID, BOB_ACCOUNT_ID]);
+
+        let asset_id = 0u32;
+        create_issue_asset(DEFAULT_ACCOUNT_ID, asset_id, 10_000u64, None);
+
+        // authors share equally by default
+        assert_ok!(Deip::distribute_project_content_revenue(Origin::signed(DEFAULT_ACCOUNT_ID),
+            project_id,
+            project_content_id,
+            DeipAsset::new(asset_id, 1_001u64)));
+
+        assert_eq!(Assets::balance(asset_id, ALICE_ACCOUNT_ID), 501);
+        assert_eq!(Assets::balance(asset_id, BOB_ACCOUNT_ID), 500);
+
+        assert_ok!(Deip::set_project_content_author_shares(Origin::signed(DEFAULT_ACCOUNT_ID),
+            project_id,
+            project_content_id,
+            vec![(ALICE_ACCOUNT_ID, 7_000), (BOB_ACCOUNT_ID, 3_000)]));
+
+        assert_ok!(Deip::distribute_project_content_revenue(Origin::signed(DEFAULT_ACCOUNT_ID),
+            project_id,
+            project_content_id,
+            DeipAsset::new(asset_id, 1_000u64)));
+
+        assert_eq!(Assets::balance(asset_id, ALICE_ACCOUNT_ID), 501 + 700);
+        assert_eq!(Assets::balance(asset_id, BOB_ACCOUNT_ID), 500 + 300);
+        assert_eq!(Assets::balance(asset_id, DEFAULT_ACCOUNT_ID), 10_000 - 2_001);
+
+        // the rounding remainder goes to the author with the largest share
+        assert_ok!(Deip::set_project_content_author_shares(Origin::signed(DEFAULT_ACCOUNT_ID),
+            project_id,
+            project_content_id,
+            vec![(ALICE_ACCOUNT_ID, 3_000), (BOB_ACCOUNT_ID, 7_000)]));
+
+        assert_ok!(Deip::distribute_project_content_revenue(Origin::signed(DEFAULT_ACCOUNT_ID),
+            project_id,
+            project_content_id,
+            DeipAsset::new(asset_id, 1_001u64)));
+
+        assert_eq!(Assets::balance(asset_id, ALICE_ACCOUNT_ID), 501 + 700 + 300);
+        assert_eq!(Assets::balance(asset_id, BOB_ACCOUNT_ID), 500 + 300 + 701);
+    })
+}
+
+#[test]
+fn amend_project_content_keeps_author_shares() {
+    new_test_ext().execute_with(|| {
+        let (project_id, ..) = create_ok_project(None);
+        let project_content_id = create_ok_project_content(project_id, vec![ALICE_ACCOUNT_ID, BOB_ACCOUNT_ID]);
+        let shares = vec![(ALICE_ACCOUNT_ID, 7_000), (BOB_ACCOUNT_ID, 3_000)];
+
+        assert_ok!(Deip::set_project_content_author_shares(Origin::signed(DEFAULT_ACCOUNT_ID),
+            project_id,
+            project_content_id,
+            shares.clone()));
+
+        // reordering the authors doesn't change their shares
+        assert_ok!(Deip::amend_project_content(Origin::signed(DEFAULT_ACCOUNT_ID),
+            project_id,
+            project_content_id,
+            H256::random(),
+            H256::random(),
+            vec![BOB_ACCOUNT_ID, ALICE_ACCOUNT_ID]));
+
+        assert_eq!(ProjectContentAuthorShares::<Test>::get(project_content_id), shares);
+
+        assert_ok!(Deip::amend_project_content(Origin::signed(DEFAULT_ACCOUNT_ID),
+            project_id,
+            project_content_id,
+            H256::random(),
+            H256::random(),
+            vec![ALICE_ACCOUNT_ID]));
+
+        assert!(!ProjectContentAuthorShares::<Test>::contains_key(project_content_id));
+    })
+}
+
+#[test]
+fn cant_set_wrong_project_content_author_shares() {
+    new_test_ext().execute_with(|| {
+        let (project_id, ..) = create_ok_project(None);
+        let project_content_id = create_ok_project_content(project_id, vec![ALICE_ACCOUNT_ID, BOB_ACCOUNT_ID]);
+
+        assert_noop!(
+            Deip::set_project_content_author_shares(Origin::signed(DEFAULT_ACCOUNT_ID),
+                project_id,
+                project_content_id,
+                vec![(ALICE_ACCOUNT_ID, 7_000), (BOB_ACCOUNT_ID, 2_000)]),
+            Error::<Test>::ProjectContentAuthorSharesMustSumToTotal
+        );
+
+        assert_noop!(
+            Deip::set_project_content_author_shares(Origin::signed(DEFAULT_ACCOUNT_ID),
+                project_id,
+                project_content_id,
+                vec![(ALICE_ACCOUNT_ID, 10_000)]),
+            Error::<Test>::ProjectContentAuthorSharesMustMatchAuthors
+        );
+
+        assert_noop!(
+            Deip::set_project_content_author_shares(Origin::signed(ALICE_ACCOUNT_ID),
+                project_id,
+                project_content_id,
+                vec![(ALICE_ACCOUNT_ID, 5_000), (BOB_ACCOUNT_ID, 5_000)]),
+            Error::<Test>::ProjectNotBelongToTeam
+        );
+    })
+}
+
+#[test]
+fn purchase_licence() {
+    new_test_ext2().execute_with(|| {
+        let (project_id, ..) = create_ok_project(None);
+        let project_content_id = create_ok_project_content(project_id, vec![DEFAULT_ACCOUNT_ID]);
+
+        let asset_id = 0u32;
+        create_issue_asset(ALICE_ACCOUNT_ID, asset_id, 1_000u64, None);
+
+        let offer_id = LicenceOfferId::random();
+        let duration = 10 * BLOCK_TIME;
+        assert_ok!(Deip::create_licence_offer(Origin::signed(DEFAULT_ACCOUNT_ID),
+            offer_id,
+            project_id,
+            project_content_id,
+            DeipAsset::new(asset_id, 100u64),
+            Some(duration),
+            H256::random()));
+
+        let now = pallet_timestamp::Module::<Test>::get();
+        assert!(!Deip::has_valid_licence(&ALICE_ACCOUNT_ID, &project_content_id, &now));
+
+        assert_ok!(Deip::purchase_licence(Origin::signed(ALICE_ACCOUNT_ID), offer_id));
+
+        assert_eq!(Assets::balance(asset_id, ALICE_ACCOUNT_ID), 900);
+        assert_eq!(Assets::balance(asset_id, DEFAULT_ACCOUNT_ID), 100);
+
+        assert!(Deip::has_valid_licence(&ALICE_ACCOUNT_ID, &project_content_id, &now));
+        assert!(Deip::has_valid_licence(&ALICE_ACCOUNT_ID, &project_content_id, &(now + duration - 1)));
+        assert!(!Deip::has_valid_licence(&ALICE_ACCOUNT_ID, &project_content_id, &(now + duration)));
+        assert!(!Deip::has_valid_licence(&ALICE_ACCOUNT_ID, &project_content_id, &(now - 1)));
+        assert!(!Deip::has_valid_licence(&BOB_ACCOUNT_ID, &project_content_id, &now));
+
+        // purchasing a valid licence once more extends it
+        assert_ok!(Deip::purchase_licence(Origin::signed(ALICE_ACCOUNT_ID), offer_id));
+
+        assert_eq!(Assets::balance(asset_id, ALICE_ACCOUNT_ID), 800);
+        assert!(Deip::has_valid_licence(&ALICE_ACCOUNT_ID, &project_content_id, &(now + 2 * duration - 1)));
+        assert!(!Deip::has_valid_licence(&ALICE_ACCOUNT_ID, &project_content_id, &(now + 2 * duration)));
+    })
+}
+
+#[test]
+fn cant_purchase_licence_without_funds() {
+    new_test_ext2().execute_with(|| {
+        let (project_id, ..) = create_ok_project(None);
+        let project_content_id = create_ok_project_content(project_id, vec![DEFAULT_ACCOUNT_ID]);
+
+        let asset_id = 0u32;
+        create_issue_asset(ALICE_ACCOUNT_ID, asset_id, 50u64, None);
+
+        let offer_id = LicenceOfferId::random();
+
+        assert_noop!(
+            Deip::create_licence_offer(Origin::signed(ALICE_ACCOUNT_ID),
+                offer_id,
+                project_id,
+                project_content_id,
+                DeipAsset::new(asset_id, 100u64),
+                None,
+                H256::random()),
+            Error::<Test>::ProjectNotBelongToTeam
+        );
+
+        assert_ok!(Deip::create_licence_offer(Origin::signed(DEFAULT_ACCOUNT_ID),
+            offer_id,
+            project_id,
+            project_content_id,
+            DeipAsset::new(asset_id, 100u64),
+            None,
+            H256::random()));
+
+        assert_noop!(
+            Deip::purchase_licence(Origin::signed(ALICE_ACCOUNT_ID), offer_id),
+            Error::<Test>::LicencePaymentFailed
+        );
+
+        let now = pallet_timestamp::Module::<Test>::get();
+        assert!(!Deip::has_valid_licence(&ALICE_ACCOUNT_ID, &project_content_id, &now));
+    })
+}
+
+#[test]
+fn create_project_nda() {
+    new_test_ext().execute_with(|| {
+        let (project_nda_id, expected_nda) = create_ok_nda();
+
+        let nda_stored = NdaMap::<Test>::get(project_nda_id);
+
+        assert!(
+            <NdaMap<Test>>::contains_key(project_nda_id),
+            "NDA Map did not contain key, value was `{}`",
+            project_nda_id
+        );
+
+        assert_eq!(expected_nda, nda_stored);
+
+        assert!(
+            NdaIdByCreator::<Test>::contains_key(DEFAULT_ACCOUNT_ID, project_nda_id),
+            "NDAs of the creator did not contain the NDA, value was `{}`",
+            project_nda_id
+        );
+        assert!(expected_nda.projects.iter().all(|project_id| NdaIdByProjectId::contains_key(project_id, project_nda_id)));
+    })
+}
+
+#[test]
+fn cant_create_project_nda_ends_in_past() {
+    new_test_ext().execute_with(|| {
+        let (project_id, ..) = create_ok_project(None);
+        let project_nda_id =  NdaId::random();        
+        let end_date = 0;
+
+        let contract_hash = H256::random();
+        let maybe_start_date = None;
+        let parties = vec![DEFAULT_ACCOUNT_ID];
+        let projects = vec![project_id];
+
+        assert_noop!(
+            Deip::create_project_nda(
+                Origin::signed(DEFAULT_ACCOUNT_ID), 
+                project_nda_id, 
+                end_date, 
+                contract_hash, 
+                maybe_start_date,
+                parties.clone(),
+                projects.clone()
+            ),
+            Error::<Test>::NdaEndDateMustBeLaterCurrentMoment
         );
     })
 }
@@ -705,7 +1406,6 @@ fn create_nda_content_access_request() {
 
         let (access_request_id, expected_nda_request) = create_ok_nda_content_access_request(project_nda_id);
 
-        let nda_list = NdaAccessRequests::<Test>::get();
         let nda_stored = NdaAccessRequestMap::<Test>::get(access_request_id);
 
         assert!(
@@ -718,8 +1418,8 @@ fn create_nda_content_access_request() {
         assert_eq!(expected_nda_request, nda_stored);
 
         assert!(
-            nda_list.binary_search_by_key(&access_request_id, |&(external_id, ..)| external_id).is_ok(),
-            "NDA request List did not contain the NDA request, value was `{}`",
+            NdaAccessRequestIdByNdaId::contains_key(project_nda_id, access_request_id),
+            "NDA requests of the NDA did not contain the NDA request, value was `{}`",
             access_request_id
         );
 
@@ -936,7 +1636,8 @@ fn simple_crowdfunding_create_should_fail() {
             start_time + 1,
             DeipAsset::new(0u32, 100u32.into()),
             DeipAsset::new(0u32, 120u32.into()),
-            vec![DeipAsset::new(0u32.into(), 100u32.into()), DeipAsset::new(14u32.into(), 200u32.into())]
+            vec![DeipAsset::new(0u32.into(), 100u32.into()), DeipAsset::new(14u32.into(), 200u32.into())],
+            vec![]
         ),
         Error::<Test>::InvestmentOpportunityWrongAssetId);
 
@@ -946,6 +1647,7 @@ fn simple_crowdfunding_create_should_fail() {
             start_time + 1,
             DeipAsset::new(0u32, 100u32.into()),
             DeipAsset::new(0u32, 120u32.into()),
+            vec![],
             vec![]
         ),
         Error::<Test>::InvestmentOpportunitySecurityTokenNotSpecified);
@@ -990,7 +1692,8 @@ fn simple_crowdfunding_hard_cap_reached() {
             start_time + 100,
             DeipAsset::new(base_asset_id, soft_cap),
             DeipAsset::new(base_asset_id, hard_cap),
-            vec![DeipAsset::new(usd_id, usd_to_sale), DeipAsset::new(eur_id, eur_to_sale)]
+            vec![DeipAsset::new(usd_id, usd_to_sale), DeipAsset::new(eur_id, eur_to_sale)],
+            vec![]
         ));
 
         Deip::offchain_worker(System::block_number());
@@ -1008,6 +1711,12 @@ fn simple_crowdfunding_hard_cap_reached() {
             DeipAsset::new(base_asset_id, hard_cap / 2),
         ));
 
+        let investments = Deip::get_investments(&sale_id);
+        assert_eq!(investments.len(), 1);
+        assert_eq!(investments[0].owner, BOB_ACCOUNT_ID);
+        assert_eq!(investments[0].amount, hard_cap / 2);
+        assert_eq!(Deip::get_investment_opportunity(&sale_id).unwrap().total_amount, hard_cap / 2);
+
         // investors should get their tokens in any case
         let call = pallet_deip_assets::Call::<Test>::freeze(usd_id, BOB_ACCOUNT_ID);
         let _result = call.dispatch_bypass_filter(Origin::signed(*account_id));
@@ -1030,6 +1739,19 @@ fn simple_crowdfunding_hard_cap_reached() {
         assert_eq!(Assets::balance(eur_id, *account_id), Assets::total_supply(eur_id) - eur_to_sale);
 
         assert_eq!(Assets::balance(base_asset_id, *account_id), hard_cap + balance_before);
+
+        // the sale is settled, but investors still can look it up
+        assert!(Deip::get_investments(&sale_id).is_empty());
+        let invested = Deip::get_invested_opportunities(&BOB_ACCOUNT_ID);
+        assert_eq!(invested.len(), 1);
+        assert_eq!(invested[0].status, SimpleCrowdfundingStatus::Finished);
+
+        let by_status = InvestmentOpportunityFilter::ByStatus(SimpleCrowdfundingStatus::Finished);
+        assert_eq!(Deip::get_investment_opportunities_page(&by_status, &None, 10), invested);
+        let by_status = InvestmentOpportunityFilter::ByStatus(SimpleCrowdfundingStatus::Active);
+        assert!(Deip::get_investment_opportunities_page(&by_status, &None, 10).is_empty());
+        let by_share = InvestmentOpportunityFilter::ByAsset(eur_id);
+        assert_eq!(Deip::get_investment_opportunities_page(&by_share, &None, 10), invested);
     })
 }
 
@@ -1079,7 +1801,8 @@ fn simple_crowdfunding_expired() {
             start_time + duration_in_blocks * BLOCK_TIME,
             DeipAsset::new(base_asset_id, soft_cap),
             DeipAsset::new(base_asset_id, hard_cap),
-            vec![DeipAsset::new(usd_id, usd_to_sale), DeipAsset::new(eur_id, eur_to_sale)]
+            vec![DeipAsset::new(usd_id, usd_to_sale), DeipAsset::new(eur_id, eur_to_sale)],
+            vec![]
         ));
 
         let start_block = System::block_number() + start_time_in_blocks + 1;
@@ -1199,7 +1922,8 @@ fn two_simultaneous_crowdfundings_expired() {
             start_time + duration_in_blocks * BLOCK_TIME,
             DeipAsset::new(usd_id, soft_cap),
             DeipAsset::new(usd_id, hard_cap),
-            vec![DeipAsset::new(eur_id, eur_to_sale)]
+            vec![DeipAsset::new(eur_id, eur_to_sale)],
+            vec![]
         ));
 
         assert_ok!(Deip::create_simple_crowdfunding(
@@ -1209,7 +1933,8 @@ fn two_simultaneous_crowdfundings_expired() {
             start_time + duration_in_blocks * BLOCK_TIME,
             DeipAsset::new(base_asset_id, soft_cap),
             DeipAsset::new(base_asset_id, hard_cap),
-            vec![DeipAsset::new(usd_id, usd_to_sale)]
+            vec![DeipAsset::new(usd_id, usd_to_sale)],
+            vec![]
         ));
 
         let start_block = System::block_number() + start_time_in_blocks + 1;
@@ -1289,3 +2014,1736 @@ fn two_simultaneous_crowdfundings_expired() {
     })
 }
 
+
+#[test]
+fn migrate_to_keyed_indexes() {
+    new_test_ext().execute_with(|| {
+        use frame_support::storage::{unhashed, StoragePrefixedMap, migration::{put_storage_value, have_storage_value}};
+
+        assert_eq!(Deip::storage_version(), Releases::V7);
+
+        let (project_id, _, domain_id, account_id) = create_ok_project(None);
+        let project_content_id = create_ok_project_content(project_id, vec![account_id]);
+        let review_id = ReviewId::random();
+
+        // lay out the storage the way the previous release did
+        StorageVersion::put(Releases::V1);
+        ProjectIdByTeamId::<Test>::remove_all();
+        ProjectIdByDomainId::remove_all();
+        ProjectIdByProjectContentId::remove_all();
+        ProjectContentIdByTeamId::<Test>::remove_all();
+        ProjectContentIdByType::remove_all();
+        put_storage_value(b"Deip", b"Projects", &[], vec![(project_id, account_id)]);
+        unhashed::put(&Domains::hashed_key_for(domain_id), &domain_id);
+        unhashed::put(
+            &ReviewMap::<Test>::hashed_key_for(review_id),
+            &(review_id, ALICE_ACCOUNT_ID, H256::random(), vec![domain_id], 0u32, Vec::<u8>::new(), project_content_id),
+        );
+
+        migrations::migrate::<Test>();
+
+        assert_eq!(Deip::storage_version(), Releases::V7);
+        assert!(!have_storage_value(b"Deip", b"Projects", &[]));
+        assert_eq!(Domains::get(domain_id), Domain { external_id: domain_id, ..Default::default() });
+        assert_eq!(ReviewMap::<Test>::get(review_id).project_content_version, 0);
+        assert!(ReviewIdByAuthor::<Test>::contains_key(ALICE_ACCOUNT_ID, review_id));
+        assert!(ReviewIdByProjectContentId::contains_key(project_content_id, review_id));
+        assert!(ProjectIdByTeamId::<Test>::contains_key(account_id, project_id));
+        assert_eq!(ProjectIdByProjectContentId::get(project_content_id), Some(project_id));
+        assert!(ProjectContentIdByTeamId::<Test>::contains_key(account_id, project_content_id));
+        assert!(ProjectIdByDomainId::contains_key(domain_id, project_id));
+        assert!(ReviewIdByDomainId::contains_key(domain_id, review_id));
+        assert!(ProjectContentIdByType::contains_key(ProjectContentType::Announcement, project_content_id));
+    })
+}
+
+#[test]
+fn migrate_contributions_assets() {
+    new_test_ext().execute_with(|| {
+        use frame_support::storage::unhashed;
+
+        let sale_id = H160::random();
+        let asset_id = 3u32;
+        SimpleCrowdfundingMap::<Test>::insert(sale_id, SimpleCrowdfunding { external_id: sale_id, asset_id, ..Default::default() });
+
+        // lay out the storage the way the previous release did
+        StorageVersion::put(Releases::V2);
+        unhashed::put(
+            &InvestmentMap::<Test>::hashed_key_for(sale_id),
+            &vec![(ALICE_ACCOUNT_ID, (sale_id, ALICE_ACCOUNT_ID, 100u64, 5u64))],
+        );
+
+        migrations::migrate::<Test>();
+
+        assert_eq!(Deip::storage_version(), Releases::V7);
+        assert_eq!(
+            InvestmentMap::<Test>::get(sale_id),
+            vec![(
+                ALICE_ACCOUNT_ID,
+                Investment { sale_id, owner: ALICE_ACCOUNT_ID, amount: 100, time: 5, assets: vec![(asset_id, 100)] },
+            )]
+        );
+    })
+}
+
+#[test]
+fn get_projects_page() {
+    new_test_ext().execute_with(|| {
+        let mut project_ids: Vec<_> = (0..3).map(|_| create_ok_project(None).0).collect();
+        let (alice_project_id, _, alice_domain_id, _) = create_ok_project(Some(ALICE_ACCOUNT_ID));
+        project_ids.push(alice_project_id);
+        project_ids.sort();
+
+        let first_page = Deip::get_projects_page(&ProjectFilter::All, &None, 2);
+        assert_eq!(first_page.iter().map(|p| p.external_id).collect::<Vec<_>>(), project_ids[..2].to_vec());
+
+        let next_page = Deip::get_projects_page(&ProjectFilter::All, &Some(project_ids[1]), 10);
+        assert_eq!(next_page.iter().map(|p| p.external_id).collect::<Vec<_>>(), project_ids[2..].to_vec());
+
+        let by_team = Deip::get_projects_page(&ProjectFilter::ByTeam(ALICE_ACCOUNT_ID), &None, 10);
+        assert_eq!(by_team.iter().map(|p| p.external_id).collect::<Vec<_>>(), vec![alice_project_id]);
+
+        let by_domain = Deip::get_projects_page(&ProjectFilter::ByDomain(alice_domain_id), &None, 10);
+        assert_eq!(by_domain.iter().map(|p| p.external_id).collect::<Vec<_>>(), vec![alice_project_id]);
+    })
+}
+
+#[test]
+fn get_reviews_page() {
+    new_test_ext().execute_with(|| {
+        let (project_id, _, domain_id, account_id) = create_ok_project(None);
+        let project_content_id = create_ok_project_content(project_id, vec![account_id]);
+
+        let mut review_ids = vec![ReviewId::random(), ReviewId::random()];
+        for (review_id, author) in review_ids.iter().zip(&[ALICE_ACCOUNT_ID, BOB_ACCOUNT_ID]) {
+            assert_ok!(Deip::create_review(Origin::signed(*author),
+                *review_id,
+                *author,
+                H256::random(),
+                vec![domain_id],
+                0,
+                vec![],
+                project_content_id));
+        }
+        let bob_review_id = review_ids[1];
+        review_ids.sort();
+
+        let by_content = Deip::get_reviews_page(&ReviewFilter::ByProjectContent(project_content_id), &None, 10);
+        assert_eq!(by_content.iter().map(|r| r.external_id).collect::<Vec<_>>(), review_ids);
+
+        let by_author = Deip::get_reviews_page(&ReviewFilter::ByAuthor(BOB_ACCOUNT_ID), &None, 10);
+        assert_eq!(by_author.iter().map(|r| r.external_id).collect::<Vec<_>>(), vec![bob_review_id]);
+
+        let after_first = Deip::get_reviews_page(&ReviewFilter::ByDomain(domain_id), &Some(review_ids[0]), 10);
+        assert_eq!(after_first.iter().map(|r| r.external_id).collect::<Vec<_>>(), review_ids[1..].to_vec());
+
+        assert!(Deip::get_reviews_page(&ReviewFilter::ByDomain(DomainId::random()), &None, 10).is_empty());
+    })
+}
+
+#[test]
+fn get_project_content_page() {
+    new_test_ext().execute_with(|| {
+        let (project_id, ..) = create_ok_project(None);
+        let mut announcement_ids: Vec<_> = (0..3)
+            .map(|_| create_ok_project_content(project_id, vec![DEFAULT_ACCOUNT_ID]))
+            .collect();
+        announcement_ids.sort();
+
+        let final_result_id = ProjectContentId::random();
+        assert_ok!(Deip::create_project_content(Origin::signed(DEFAULT_ACCOUNT_ID),
+            final_result_id,
+            project_id,
+            DEFAULT_ACCOUNT_ID,
+            ProjectContentType::FinalResult,
+            H256::random(),
+            H256::random(),
+            vec![DEFAULT_ACCOUNT_ID],
+            None));
+
+        let by_type = Deip::get_project_content_page(&ProjectContentFilter::ByType(ProjectContentType::Announcement), &None, 2);
+        assert_eq!(by_type.iter().map(|c| c.external_id).collect::<Vec<_>>(), announcement_ids[..2].to_vec());
+
+        let next_page = Deip::get_project_content_page(
+            &ProjectContentFilter::ByType(ProjectContentType::Announcement),
+            &Some(announcement_ids[1]),
+            2,
+        );
+        assert_eq!(next_page.iter().map(|c| c.external_id).collect::<Vec<_>>(), announcement_ids[2..].to_vec());
+
+        let by_type = Deip::get_project_content_page(&ProjectContentFilter::ByType(ProjectContentType::FinalResult), &None, 10);
+        assert_eq!(by_type.iter().map(|c| c.external_id).collect::<Vec<_>>(), vec![final_result_id]);
+
+        assert_eq!(Deip::get_project_content_page(&ProjectContentFilter::ByProject(project_id), &None, 10).len(), 4);
+    })
+}
+
+#[test]
+fn milestone_tranches() {
+    new_test_ext2().execute_with(|| {
+        let (project_id, .., account_id) = create_ok_project(None);
+
+        let base_asset_id = 3u32;
+        let base_asset_total = 2_000u64;
+        create_issue_asset(ALICE_ACCOUNT_ID, base_asset_id, base_asset_total, None);
+
+        let call = pallet_deip_assets::Call::<Test>::transfer(base_asset_id, BOB_ACCOUNT_ID, base_asset_total / 2);
+        let result = call.dispatch_bypass_filter(Origin::signed(ALICE_ACCOUNT_ID));
+        assert_ok!(result);
+
+        let usd_id = 0u32;
+        create_issue_asset(account_id, usd_id, 10_000u64, Some(project_id));
+
+        let team_balance_before = Assets::balance(base_asset_id, account_id);
+
+        let start_time = pallet_timestamp::Module::<Test>::get();
+        let sale_id = H160::random();
+        let hard_cap = 200u64;
+        let funding_model = |tranches| FundingModel::MilestoneTranches {
+            start_time,
+            end_time: start_time + 100,
+            soft_cap: DeipAsset::new(base_asset_id, 100u64),
+            hard_cap: DeipAsset::new(base_asset_id, hard_cap),
+            project_id,
+            tranches,
+        };
+        let terms = |percent, milestone_type, deadline| TrancheTerms {
+            share: sp_runtime::Percent::from_percent(percent),
+            milestone_type,
+            deadline: start_time + deadline,
+        };
+
+        assert_noop!(
+            Deip::create_investment_opportunity(
+                Origin::signed(account_id),
+                sale_id,
+                account_id,
+                vec![DeipAsset::new(usd_id, 1_000u64)],
+                funding_model(vec![terms(40, ProjectContentType::MilestoneData, 1_000), terms(40, ProjectContentType::MilestoneCode, 2_000)]),
+                None,
+                None,
+                None,
+                None,
+                vec![],
+            ),
+            Error::<Test>::MilestoneTranchesMustSumToWhole
+        );
+
+        assert_ok!(Deip::create_investment_opportunity(
+            Origin::signed(account_id),
+            sale_id,
+            account_id,
+            vec![DeipAsset::new(usd_id, 1_000u64)],
+            funding_model(vec![terms(40, ProjectContentType::MilestoneData, 1_000), terms(60, ProjectContentType::MilestoneCode, 2_000)]),
+            None,
+            None,
+            None,
+            None,
+            vec![],
+        ));
+        assert_ok!(Deip::activate_crowdfunding_impl(sale_id));
+        assert_ok!(Deip::invest_to_crowdfunding_impl(ALICE_ACCOUNT_ID, sale_id, DeipAsset::new(base_asset_id, hard_cap / 2)));
+        assert_ok!(Deip::invest_to_crowdfunding_impl(BOB_ACCOUNT_ID, sale_id, DeipAsset::new(base_asset_id, hard_cap / 2)));
+
+        // raised funds stay locked until milestones are approved
+        assert_eq!(Assets::balance(base_asset_id, account_id), team_balance_before);
+        assert_eq!(Deip::get_milestone_tranches(&sale_id).unwrap().locked, hard_cap);
+
+        let create_milestone = |content_type| {
+            let content_id = ProjectContentId::random();
+            assert_ok!(Deip::create_project_content(Origin::signed(account_id),
+                content_id,
+                project_id,
+                account_id,
+                content_type,
+                H256::random(),
+                H256::random(),
+                vec![account_id],
+                None));
+            content_id
+        };
+        let submit = |content_id| Deip::submit_milestone(Origin::signed(account_id), sale_id, content_id);
+
+        let code_id = create_milestone(ProjectContentType::MilestoneCode);
+        assert_noop!(submit(code_id), Error::<Test>::MilestoneTypeMismatch);
+        assert_noop!(
+            Deip::vote_milestone(Origin::signed(ALICE_ACCOUNT_ID), sale_id, true),
+            Error::<Test>::MilestoneNotUnderReview
+        );
+        assert_ok!(submit(create_milestone(ProjectContentType::MilestoneData)));
+
+        // half of the invested amount isn't a majority
+        assert_ok!(Deip::vote_milestone(Origin::signed(ALICE_ACCOUNT_ID), sale_id, true));
+        assert_noop!(
+            Deip::vote_milestone(Origin::signed(ALICE_ACCOUNT_ID), sale_id, true),
+            Error::<Test>::MilestoneAlreadyApproved
+        );
+        assert_noop!(
+            Deip::vote_milestone(Origin::signed(DEFAULT_ACCOUNT_ID), sale_id, true),
+            Error::<Test>::MilestoneVoterNotInvestor
+        );
+        assert_eq!(Assets::balance(base_asset_id, account_id), team_balance_before);
+
+        assert_ok!(Deip::vote_milestone(Origin::signed(BOB_ACCOUNT_ID), sale_id, true));
+        assert_eq!(Assets::balance(base_asset_id, account_id), team_balance_before + 80);
+
+        let tranches = Deip::get_milestone_tranches(&sale_id).unwrap();
+        assert_eq!(tranches.current, 1);
+        assert_eq!(tranches.locked, 120);
+        assert_eq!(tranches.tranches[0].status, TrancheStatus::Released);
+
+        assert_ok!(submit(code_id));
+
+        // the investor who stops funding takes back its part of locked funds
+        let alice_balance_before = Assets::balance(base_asset_id, ALICE_ACCOUNT_ID);
+        assert_ok!(Deip::vote_milestone(Origin::signed(ALICE_ACCOUNT_ID), sale_id, false));
+        assert_eq!(Assets::balance(base_asset_id, ALICE_ACCOUNT_ID), alice_balance_before + 60);
+
+        assert_ok!(Deip::vote_milestone(Origin::signed(BOB_ACCOUNT_ID), sale_id, true));
+        assert_eq!(Assets::balance(base_asset_id, account_id), team_balance_before + 140);
+
+        let tranches = Deip::get_milestone_tranches(&sale_id).unwrap();
+        assert_eq!(tranches.current, 2);
+        assert_eq!(tranches.locked, 0);
+        assert!(Deip::get_investments(&sale_id).is_empty());
+    })
+}
+
+#[test]
+fn refund_missed_milestone() {
+    new_test_ext2().execute_with(|| {
+        let (project_id, .., account_id) = create_ok_project(None);
+
+        let base_asset_id = 3u32;
+        let base_asset_total = 2_000u64;
+        create_issue_asset(ALICE_ACCOUNT_ID, base_asset_id, base_asset_total, None);
+
+        let call = pallet_deip_assets::Call::<Test>::transfer(base_asset_id, BOB_ACCOUNT_ID, base_asset_total / 2);
+        let result = call.dispatch_bypass_filter(Origin::signed(ALICE_ACCOUNT_ID));
+        assert_ok!(result);
+
+        let usd_id = 0u32;
+        create_issue_asset(account_id, usd_id, 10_000u64, Some(project_id));
+
+        let start_time = pallet_timestamp::Module::<Test>::get();
+        let sale_id = H160::random();
+        let funding_model = |deadlines: [u64; 2]| FundingModel::MilestoneTranches {
+            start_time,
+            end_time: start_time + 100,
+            soft_cap: DeipAsset::new(base_asset_id, 100u64),
+            hard_cap: DeipAsset::new(base_asset_id, 200u64),
+            project_id,
+            tranches: vec![
+                TrancheTerms {
+                    share: sp_runtime::Percent::from_percent(50),
+                    milestone_type: ProjectContentType::MilestoneData,
+                    deadline: start_time + deadlines[0],
+                },
+                TrancheTerms {
+                    share: sp_runtime::Percent::from_percent(50),
+                    milestone_type: ProjectContentType::MilestoneCode,
+                    deadline: start_time + deadlines[1],
+                },
+            ],
+        };
+        let create = |deadlines| Deip::create_investment_opportunity(
+            Origin::signed(account_id),
+            sale_id,
+            account_id,
+            vec![DeipAsset::new(usd_id, 1_000u64)],
+            funding_model(deadlines),
+            None,
+            None,
+            None,
+            None,
+            vec![],
+        );
+
+        assert_noop!(create([50, 2_000]), Error::<Test>::MilestoneDeadlinesInvalid);
+        assert_noop!(create([2_000, 1_000]), Error::<Test>::MilestoneDeadlinesInvalid);
+        assert_ok!(create([1_000, 2_000]));
+
+        assert_ok!(Deip::activate_crowdfunding_impl(sale_id));
+        assert_ok!(Deip::invest_to_crowdfunding_impl(ALICE_ACCOUNT_ID, sale_id, DeipAsset::new(base_asset_id, 150u64)));
+        assert_ok!(Deip::invest_to_crowdfunding_impl(BOB_ACCOUNT_ID, sale_id, DeipAsset::new(base_asset_id, 50u64)));
+
+        let alice_balance_before = Assets::balance(base_asset_id, ALICE_ACCOUNT_ID);
+        let bob_balance_before = Assets::balance(base_asset_id, BOB_ACCOUNT_ID);
+
+        assert_noop!(
+            Deip::refund_missed_milestone(Origin::signed(DEFAULT_ACCOUNT_ID), sale_id, 2),
+            Error::<Test>::MilestoneDeadlineNotPassed
+        );
+
+        // anyone can return locked funds once the team missed the deadline
+        Timestamp::set_timestamp(start_time + 1_001);
+        assert_noop!(
+            Deip::refund_missed_milestone(Origin::signed(DEFAULT_ACCOUNT_ID), sale_id, 1),
+            Error::<Test>::MilestoneInvestorsWitnessInvalid
+        );
+        assert_ok!(Deip::refund_missed_milestone(Origin::signed(DEFAULT_ACCOUNT_ID), sale_id, 2));
+
+        assert_eq!(Assets::balance(base_asset_id, ALICE_ACCOUNT_ID), alice_balance_before + 150);
+        assert_eq!(Assets::balance(base_asset_id, BOB_ACCOUNT_ID), bob_balance_before + 50);
+
+        let tranches = Deip::get_milestone_tranches(&sale_id).unwrap();
+        assert_eq!(tranches.current, 2);
+        assert_eq!(tranches.locked, 0);
+        assert!(Deip::get_investments(&sale_id).is_empty());
+
+        assert_noop!(
+            Deip::refund_missed_milestone(Origin::signed(DEFAULT_ACCOUNT_ID), sale_id, 0),
+            Error::<Test>::MilestoneTranchesAlreadyReleased
+        );
+    })
+}
+
+#[test]
+fn refund_milestone_not_approved_in_time() {
+    new_test_ext2().execute_with(|| {
+        let (project_id, .., account_id) = create_ok_project(None);
+
+        let base_asset_id = 3u32;
+        create_issue_asset(ALICE_ACCOUNT_ID, base_asset_id, 1_000u64, None);
+
+        let usd_id = 0u32;
+        create_issue_asset(account_id, usd_id, 10_000u64, Some(project_id));
+
+        let start_time = pallet_timestamp::Module::<Test>::get();
+        let sale_id = H160::random();
+        assert_ok!(Deip::create_investment_opportunity(
+            Origin::signed(account_id),
+            sale_id,
+            account_id,
+            vec![DeipAsset::new(usd_id, 1_000u64)],
+            FundingModel::MilestoneTranches {
+                start_time,
+                end_time: start_time + 100,
+                soft_cap: DeipAsset::new(base_asset_id, 100u64),
+                hard_cap: DeipAsset::new(base_asset_id, 200u64),
+                project_id,
+                tranches: vec![TrancheTerms {
+                    share: sp_runtime::Percent::from_percent(100),
+                    milestone_type: ProjectContentType::MilestoneData,
+                    deadline: start_time + 1_000,
+                }],
+            },
+            None,
+            None,
+            None,
+            None,
+            vec![],
+        ));
+        assert!(MilestoneTranchesIdByProjectId::contains_key(project_id, sale_id));
+
+        assert_ok!(Deip::activate_crowdfunding_impl(sale_id));
+        assert_ok!(Deip::invest_to_crowdfunding_impl(ALICE_ACCOUNT_ID, sale_id, DeipAsset::new(base_asset_id, 200u64)));
+
+        // locked funds are released to the team the project is handed over to
+        assert_ok!(Deip::transfer_project(Origin::signed(account_id), project_id, BOB_ACCOUNT_ID, false));
+        assert_eq!(Deip::get_milestone_tranches(&sale_id).unwrap().team_id, BOB_ACCOUNT_ID);
+
+        let content_id = ProjectContentId::random();
+        assert_ok!(Deip::create_project_content(Origin::signed(BOB_ACCOUNT_ID),
+            content_id,
+            project_id,
+            BOB_ACCOUNT_ID,
+            ProjectContentType::MilestoneData,
+            H256::random(),
+            H256::random(),
+            vec![BOB_ACCOUNT_ID],
+            None));
+        assert_noop!(
+            Deip::submit_milestone(Origin::signed(account_id), sale_id, content_id),
+            Error::<Test>::NoPermission
+        );
+        assert_ok!(Deip::submit_milestone(Origin::signed(BOB_ACCOUNT_ID), sale_id, content_id));
+
+        // the reported milestone isn't approved before the deadline
+        let alice_balance_before = Assets::balance(base_asset_id, ALICE_ACCOUNT_ID);
+        assert_noop!(
+            Deip::refund_missed_milestone(Origin::signed(DEFAULT_ACCOUNT_ID), sale_id, 1),
+            Error::<Test>::MilestoneDeadlineNotPassed
+        );
+        Timestamp::set_timestamp(start_time + 1_001);
+        assert_ok!(Deip::refund_missed_milestone(Origin::signed(DEFAULT_ACCOUNT_ID), sale_id, 1));
+
+        assert_eq!(Assets::balance(base_asset_id, ALICE_ACCOUNT_ID), alice_balance_before + 200);
+        assert_eq!(Assets::balance(base_asset_id, BOB_ACCOUNT_ID), 0);
+        assert_eq!(Deip::get_milestone_tranches(&sale_id).unwrap().locked, 0);
+    })
+}
+
+#[test]
+fn fixed_price_sale_expired() {
+    new_test_ext2().execute_with(|| {
+        let (project_id, .., account_id) = create_ok_project(None);
+
+        let base_asset_id = 3u32;
+        let base_asset_total = 2_000u64;
+        create_issue_asset(ALICE_ACCOUNT_ID, base_asset_id, base_asset_total, None);
+
+        let call = pallet_deip_assets::Call::<Test>::transfer(base_asset_id, BOB_ACCOUNT_ID, base_asset_total / 2);
+        let result = call.dispatch_bypass_filter(Origin::signed(ALICE_ACCOUNT_ID));
+        assert_ok!(result);
+
+        let usd_id = 0u32;
+        let usd_total = 10_000u64;
+        create_issue_asset(account_id, usd_id, usd_total, Some(project_id));
+
+        let start_time = pallet_timestamp::Module::<Test>::get();
+        let sale_id = H160::random();
+        let usd_to_sale = 1_000u64;
+        assert_ok!(Deip::create_investment_opportunity(
+            Origin::signed(account_id),
+            sale_id,
+            account_id,
+            vec![DeipAsset::new(usd_id, usd_to_sale)],
+            FundingModel::FixedPrice {
+                start_time,
+                end_time: start_time + 100,
+                price: DeipAsset::new(base_asset_id, 200u64),
+                max_per_account: DeipAsset::new(base_asset_id, 150u64),
+            },
+            None,
+            None,
+            None,
+            None,
+            vec![],
+        ));
+        assert_eq!(Deip::get_fixed_price_max_per_account(&sale_id), Some(150));
+        assert_ok!(Deip::activate_crowdfunding_impl(sale_id));
+
+        // shares are transferred right away at the quoted rate
+        assert_ok!(Deip::invest_to_crowdfunding_impl(ALICE_ACCOUNT_ID, sale_id, DeipAsset::new(base_asset_id, 100u64)));
+        assert_eq!(Assets::balance(usd_id, ALICE_ACCOUNT_ID), 500);
+
+        assert_noop!(
+            Deip::invest_to_crowdfunding_impl(ALICE_ACCOUNT_ID, sale_id, DeipAsset::new(base_asset_id, 60u64)),
+            Error::<Test>::FixedPriceMaxPerAccountExceeded
+        );
+
+        assert_ok!(Deip::invest_to_crowdfunding_impl(BOB_ACCOUNT_ID, sale_id, DeipAsset::new(base_asset_id, 50u64)));
+        assert_eq!(Assets::balance(usd_id, BOB_ACCOUNT_ID), 250);
+
+        let creator_balance_before = Assets::balance(base_asset_id, account_id);
+        Timestamp::set_timestamp(start_time + 100);
+        assert_ok!(Deip::expire_crowdfunding_impl(sale_id));
+
+        // investors keep bought shares, the creator gets paid funds and unsold shares
+        assert_eq!(Assets::balance(base_asset_id, account_id), creator_balance_before + 150);
+        assert_eq!(Assets::balance(base_asset_id, ALICE_ACCOUNT_ID), base_asset_total / 2 - 100);
+        assert_eq!(Assets::balance(usd_id, ALICE_ACCOUNT_ID), 500);
+        assert_eq!(Assets::balance(usd_id, account_id), usd_total - 750);
+        assert!(Deip::get_investments(&sale_id).is_empty());
+    })
+}
+
+#[test]
+fn dutch_auction_sold_out() {
+    new_test_ext2().execute_with(|| {
+        let (project_id, .., account_id) = create_ok_project(None);
+
+        let base_asset_id = 3u32;
+        let base_asset_total = 2_000u64;
+        create_issue_asset(ALICE_ACCOUNT_ID, base_asset_id, base_asset_total, None);
+
+        let call = pallet_deip_assets::Call::<Test>::transfer(base_asset_id, BOB_ACCOUNT_ID, base_asset_total / 2);
+        let result = call.dispatch_bypass_filter(Origin::signed(ALICE_ACCOUNT_ID));
+        assert_ok!(result);
+
+        let usd_id = 0u32;
+        let usd_total = 10_000u64;
+        create_issue_asset(account_id, usd_id, usd_total, Some(project_id));
+
+        let creator_balance_before = Assets::balance(base_asset_id, account_id);
+
+        let start_time = pallet_timestamp::Module::<Test>::get();
+        let sale_id = H160::random();
+        assert_noop!(
+            Deip::create_investment_opportunity(
+                Origin::signed(account_id),
+                sale_id,
+                account_id,
+                vec![DeipAsset::new(usd_id, 1_000u64)],
+                FundingModel::DutchAuction {
+                    start_time,
+                    end_time: start_time + 100,
+                    start_price: DeipAsset::new(base_asset_id, 100u64),
+                    floor_price: DeipAsset::new(base_asset_id, 200u64),
+                },
+                None,
+                None,
+                None,
+                None,
+                vec![],
+            ),
+            Error::<Test>::DutchAuctionStartPriceMustBeGreaterOrEqualFloorPrice
+        );
+
+        assert_ok!(Deip::create_investment_opportunity(
+            Origin::signed(account_id),
+            sale_id,
+            account_id,
+            vec![DeipAsset::new(usd_id, 1_000u64)],
+            FundingModel::DutchAuction {
+                start_time,
+                end_time: start_time + 100,
+                start_price: DeipAsset::new(base_asset_id, 1_000u64),
+                floor_price: DeipAsset::new(base_asset_id, 200u64),
+            },
+            None,
+            None,
+            None,
+            None,
+            vec![],
+        ));
+        assert_ok!(Deip::activate_crowdfunding_impl(sale_id));
+        assert_eq!(Deip::get_dutch_auction_price(&sale_id), Some(1_000));
+
+        // the price falls linearly, the bid claims half of shares
+        Timestamp::set_timestamp(start_time + 50);
+        assert_eq!(Deip::get_dutch_auction_price(&sale_id), Some(600));
+        assert_ok!(Deip::invest_to_crowdfunding_impl(ALICE_ACCOUNT_ID, sale_id, DeipAsset::new(base_asset_id, 300u64)));
+
+        // only the rest of shares can be claimed, that sells the auction out
+        Timestamp::set_timestamp(start_time + 75);
+        assert_ok!(Deip::invest_to_crowdfunding_impl(BOB_ACCOUNT_ID, sale_id, DeipAsset::new(base_asset_id, 500u64)));
+        assert_eq!(Deip::get_investment_opportunity(&sale_id).unwrap().status, SimpleCrowdfundingStatus::Finished);
+        assert!(Deip::get_dutch_auction_bids(&sale_id).is_empty());
+
+        // both pay the clearing price for their half, the excess returns
+        assert_eq!(Assets::balance(base_asset_id, ALICE_ACCOUNT_ID), base_asset_total / 2 - 200);
+        assert_eq!(Assets::balance(base_asset_id, BOB_ACCOUNT_ID), base_asset_total / 2 - 200);
+        assert_eq!(Assets::balance(base_asset_id, account_id), creator_balance_before + 400);
+        assert_eq!(Assets::balance(usd_id, ALICE_ACCOUNT_ID), 500);
+        assert_eq!(Assets::balance(usd_id, BOB_ACCOUNT_ID), 500);
+    })
+}
+
+#[test]
+fn dutch_auction_bids_limit() {
+    new_test_ext2().execute_with(|| {
+        let (project_id, .., account_id) = create_ok_project(None);
+
+        let base_asset_id = 3u32;
+        create_issue_asset(ALICE_ACCOUNT_ID, base_asset_id, 2_000u64, None);
+
+        let usd_id = 0u32;
+        create_issue_asset(account_id, usd_id, 10_000u64, Some(project_id));
+
+        let start_time = pallet_timestamp::Module::<Test>::get();
+        let sale_id = H160::random();
+        assert_ok!(Deip::create_investment_opportunity(
+            Origin::signed(account_id),
+            sale_id,
+            account_id,
+            vec![DeipAsset::new(usd_id, 1_000u64)],
+            FundingModel::DutchAuction {
+                start_time,
+                end_time: start_time + 100,
+                start_price: DeipAsset::new(base_asset_id, 1_000u64),
+                floor_price: DeipAsset::new(base_asset_id, 200u64),
+            },
+            None,
+            None,
+            None,
+            None,
+            vec![],
+        ));
+        assert_ok!(Deip::activate_crowdfunding_impl(sale_id));
+
+        // a bid claims at least the part of shares per the maximum number of bids
+        let min_bid = 1_000 / MaxDutchAuctionBids::get() as u64;
+        assert_noop!(
+            Deip::invest_to_crowdfunding_impl(ALICE_ACCOUNT_ID, sale_id, DeipAsset::new(base_asset_id, min_bid - 1)),
+            Error::<Test>::DutchAuctionBidTooSmall
+        );
+
+        for _ in 0..MaxDutchAuctionBids::get() {
+            assert_ok!(Deip::invest_to_crowdfunding_impl(ALICE_ACCOUNT_ID, sale_id, DeipAsset::new(base_asset_id, min_bid)));
+        }
+
+        // the maximum number of bids sells the auction out
+        assert_eq!(Deip::get_investment_opportunity(&sale_id).unwrap().status, SimpleCrowdfundingStatus::Finished);
+        assert_eq!(Assets::balance(usd_id, ALICE_ACCOUNT_ID), 1_000);
+    })
+}
+
+#[test]
+fn dutch_auction_retried_settlement_keeps_clearing_price() {
+    new_test_ext2().execute_with(|| {
+        let (project_id, .., account_id) = create_ok_project(None);
+
+        let base_asset_id = 3u32;
+        let base_asset_total = 2_000u64;
+        create_issue_asset(ALICE_ACCOUNT_ID, base_asset_id, base_asset_total, None);
+
+        let call = pallet_deip_assets::Call::<Test>::transfer(base_asset_id, BOB_ACCOUNT_ID, base_asset_total / 2);
+        let result = call.dispatch_bypass_filter(Origin::signed(ALICE_ACCOUNT_ID));
+        assert_ok!(result);
+
+        let usd_id = 0u32;
+        create_issue_asset(account_id, usd_id, 10_000u64, Some(project_id));
+
+        let creator_balance_before = Assets::balance(base_asset_id, account_id);
+
+        let start_time = pallet_timestamp::Module::<Test>::get();
+        let sale_id = H160::random();
+        assert_ok!(Deip::create_investment_opportunity(
+            Origin::signed(account_id),
+            sale_id,
+            account_id,
+            vec![DeipAsset::new(usd_id, 1_000u64)],
+            FundingModel::DutchAuction {
+                start_time,
+                end_time: start_time + 100,
+                start_price: DeipAsset::new(base_asset_id, 1_000u64),
+                floor_price: DeipAsset::new(base_asset_id, 200u64),
+            },
+            None,
+            None,
+            None,
+            None,
+            vec![],
+        ));
+        assert_ok!(Deip::activate_crowdfunding_impl(sale_id));
+
+        // shares can't be distributed while the asset is frozen
+        let call = pallet_assets::Call::<Test>::freeze_asset(usd_id);
+        assert_ok!(call.dispatch_bypass_filter(Origin::signed(account_id)));
+
+        Timestamp::set_timestamp(start_time + 50);
+        assert_ok!(Deip::invest_to_crowdfunding_impl(ALICE_ACCOUNT_ID, sale_id, DeipAsset::new(base_asset_id, 300u64)));
+        assert_ok!(Deip::invest_to_crowdfunding_impl(BOB_ACCOUNT_ID, sale_id, DeipAsset::new(base_asset_id, 300u64)));
+        assert_eq!(Deip::get_investment_opportunity(&sale_id).unwrap().status, SimpleCrowdfundingStatus::SettlementPending);
+
+        // the retry clears at the price of the moment the auction sold out
+        Timestamp::set_timestamp(start_time + 90);
+        assert_eq!(Deip::get_dutch_auction_price(&sale_id), Some(600));
+
+        let call = pallet_assets::Call::<Test>::thaw_asset(usd_id);
+        assert_ok!(call.dispatch_bypass_filter(Origin::signed(account_id)));
+        assert_ok!(Deip::retry_settlement(Origin::signed(BOB_ACCOUNT_ID), sale_id));
+
+        assert_eq!(Deip::get_investment_opportunity(&sale_id).unwrap().status, SimpleCrowdfundingStatus::Finished);
+        assert_eq!(Assets::balance(base_asset_id, ALICE_ACCOUNT_ID), base_asset_total / 2 - 300);
+        assert_eq!(Assets::balance(base_asset_id, BOB_ACCOUNT_ID), base_asset_total / 2 - 300);
+        assert_eq!(Assets::balance(base_asset_id, account_id), creator_balance_before + 600);
+        assert_eq!(Assets::balance(usd_id, ALICE_ACCOUNT_ID), 500);
+        assert_eq!(Assets::balance(usd_id, BOB_ACCOUNT_ID), 500);
+    })
+}
+
+#[test]
+fn vesting_shares() {
+    new_test_ext2().execute_with(|| {
+        let (project_id, .., account_id) = create_ok_project(None);
+
+        let base_asset_id = 3u32;
+        let base_asset_total = 2_000u64;
+        create_issue_asset(ALICE_ACCOUNT_ID, base_asset_id, base_asset_total, None);
+
+        let call = pallet_deip_assets::Call::<Test>::transfer(base_asset_id, BOB_ACCOUNT_ID, base_asset_total / 2);
+        let result = call.dispatch_bypass_filter(Origin::signed(ALICE_ACCOUNT_ID));
+        assert_ok!(result);
+
+        let usd_id = 0u32;
+        create_issue_asset(account_id, usd_id, 10_000u64, Some(project_id));
+
+        let now = pallet_timestamp::Module::<Test>::get();
+        let sale_id = H160::random();
+        let funding_model = FundingModel::SimpleCrowdfunding {
+            start_time: now,
+            end_time: now + 100,
+            soft_cap: DeipAsset::new(base_asset_id, 100u64),
+            hard_cap: DeipAsset::new(base_asset_id, 200u64),
+        };
+
+        assert_noop!(
+            Deip::create_investment_opportunity(
+                Origin::signed(account_id),
+                sale_id,
+                account_id,
+                vec![DeipAsset::new(usd_id, 1_000u64)],
+                funding_model.clone(),
+                Some(VestingSchedule { start: now + 200, cliff: now + 100, end: now + 300 }),
+                None,
+                None,
+                None,
+                vec![],
+            ),
+            Error::<Test>::VestingScheduleInvalid
+        );
+
+        assert_ok!(Deip::create_investment_opportunity(
+            Origin::signed(account_id),
+            sale_id,
+            account_id,
+            vec![DeipAsset::new(usd_id, 1_000u64)],
+            funding_model,
+            Some(VestingSchedule { start: now, cliff: now + 50, end: now + 100 }),
+            None,
+            None,
+            None,
+            vec![],
+        ));
+        assert_ok!(Deip::activate_crowdfunding_impl(sale_id));
+        assert_ok!(Deip::invest_to_crowdfunding_impl(ALICE_ACCOUNT_ID, sale_id, DeipAsset::new(base_asset_id, 100u64)));
+        assert_ok!(Deip::invest_to_crowdfunding_impl(BOB_ACCOUNT_ID, sale_id, DeipAsset::new(base_asset_id, 100u64)));
+
+        // distributed shares are on the balance, but can't be transferred
+        assert_eq!(Assets::balance(usd_id, BOB_ACCOUNT_ID), 500);
+        let transfer = |amount| pallet_deip_assets::Call::<Test>::transfer(usd_id, ALICE_ACCOUNT_ID, amount)
+            .dispatch_bypass_filter(Origin::signed(BOB_ACCOUNT_ID));
+        assert_noop!(transfer(1), pallet_deip_assets::Error::<Test>::InsufficientUnlockedBalance);
+
+        assert_noop!(
+            Deip::claim_vested(Origin::signed(BOB_ACCOUNT_ID), sale_id),
+            Error::<Test>::VestingNothingToClaim
+        );
+
+        Timestamp::set_timestamp(now + 60);
+        assert_eq!(
+            Deip::get_vesting_balances(&BOB_ACCOUNT_ID),
+            vec![VestingBalance { sale_id, asset_id: usd_id, vested: 300, locked: 200 }]
+        );
+
+        assert_ok!(Deip::claim_vested(Origin::signed(BOB_ACCOUNT_ID), sale_id));
+        assert_eq!(
+            Deip::get_vesting_balances(&BOB_ACCOUNT_ID),
+            vec![VestingBalance { sale_id, asset_id: usd_id, vested: 0, locked: 200 }]
+        );
+        assert_noop!(transfer(301), pallet_deip_assets::Error::<Test>::InsufficientUnlockedBalance);
+        assert_ok!(transfer(300));
+
+        Timestamp::set_timestamp(now + 100);
+        assert_ok!(Deip::claim_vested(Origin::signed(BOB_ACCOUNT_ID), sale_id));
+        assert!(Deip::get_vesting_balances(&BOB_ACCOUNT_ID).is_empty());
+        assert_ok!(transfer(200));
+    })
+}
+
+#[test]
+fn vesting_shares_of_expired_fixed_price_sale() {
+    new_test_ext2().execute_with(|| {
+        let (project_id, .., account_id) = create_ok_project(None);
+
+        let base_asset_id = 3u32;
+        create_issue_asset(ALICE_ACCOUNT_ID, base_asset_id, 2_000u64, None);
+
+        let usd_id = 0u32;
+        create_issue_asset(account_id, usd_id, 10_000u64, Some(project_id));
+
+        let now = pallet_timestamp::Module::<Test>::get();
+        let sale_id = H160::random();
+        assert_ok!(Deip::create_investment_opportunity(
+            Origin::signed(account_id),
+            sale_id,
+            account_id,
+            vec![DeipAsset::new(usd_id, 1_000u64)],
+            FundingModel::FixedPrice {
+                start_time: now,
+                end_time: now + 100,
+                price: DeipAsset::new(base_asset_id, 200u64),
+                max_per_account: DeipAsset::new(base_asset_id, 200u64),
+            },
+            Some(VestingSchedule { start: now, cliff: now + 150, end: now + 200 }),
+            None,
+            None,
+            None,
+            vec![],
+        ));
+        assert_ok!(Deip::activate_crowdfunding_impl(sale_id));
+        assert_ok!(Deip::invest_to_crowdfunding_impl(ALICE_ACCOUNT_ID, sale_id, DeipAsset::new(base_asset_id, 100u64)));
+
+        let transfer = |amount| pallet_deip_assets::Call::<Test>::transfer(usd_id, BOB_ACCOUNT_ID, amount)
+            .dispatch_bypass_filter(Origin::signed(ALICE_ACCOUNT_ID));
+        assert_noop!(transfer(1), pallet_deip_assets::Error::<Test>::InsufficientUnlockedBalance);
+
+        // bought shares stay under the vesting schedule after the sale expired
+        Timestamp::set_timestamp(now + 100);
+        assert_ok!(Deip::expire_crowdfunding_impl(sale_id));
+        assert_eq!(Deip::get_vesting_schedule(&sale_id), Some(VestingSchedule { start: now, cliff: now + 150, end: now + 200 }));
+
+        Timestamp::set_timestamp(now + 200);
+        assert_ok!(Deip::claim_vested(Origin::signed(ALICE_ACCOUNT_ID), sale_id));
+        assert!(Deip::get_vesting_balances(&ALICE_ACCOUNT_ID).is_empty());
+        assert_ok!(transfer(500));
+    })
+}
+
+#[test]
+fn investor_allowlist_and_limits() {
+    new_test_ext2().execute_with(|| {
+        let (project_id, .., account_id) = create_ok_project(None);
+
+        let base_asset_id = 3u32;
+        let base_asset_total = 2_000u64;
+        create_issue_asset(ALICE_ACCOUNT_ID, base_asset_id, base_asset_total, None);
+
+        let call = pallet_deip_assets::Call::<Test>::transfer(base_asset_id, BOB_ACCOUNT_ID, base_asset_total / 2);
+        let result = call.dispatch_bypass_filter(Origin::signed(ALICE_ACCOUNT_ID));
+        assert_ok!(result);
+
+        let usd_id = 0u32;
+        create_issue_asset(account_id, usd_id, 10_000u64, Some(project_id));
+
+        let now = pallet_timestamp::Module::<Test>::get();
+        let sale_id = H160::random();
+        let funding_model = FundingModel::SimpleCrowdfunding {
+            start_time: now,
+            end_time: now + 100,
+            soft_cap: DeipAsset::new(base_asset_id, 100u64),
+            hard_cap: DeipAsset::new(base_asset_id, 500u64),
+        };
+        let create = |allowlist, contribution_limits| Deip::create_investment_opportunity(
+            Origin::signed(account_id),
+            sale_id,
+            account_id,
+            vec![DeipAsset::new(usd_id, 1_000u64)],
+            funding_model.clone(),
+            None,
+            allowlist,
+            contribution_limits,
+            None,
+            vec![],
+        );
+
+        assert_noop!(
+            create(Some(InvestmentAllowlist::Reference(H160::random())), None),
+            Error::<Test>::NoSuchAllowlist
+        );
+        assert_noop!(
+            create(None, Some(ContributionLimits { min: Some(300), max: Some(200) })),
+            Error::<Test>::ContributionLimitsInvalid
+        );
+
+        assert_ok!(create(
+            Some(InvestmentAllowlist::Accounts(vec![ALICE_ACCOUNT_ID])),
+            Some(ContributionLimits { min: Some(50), max: Some(200) }),
+        ));
+        assert_ok!(Deip::activate_crowdfunding_impl(sale_id));
+
+        let invest = |account, amount| Deip::invest_to_crowdfunding_impl(account, sale_id, DeipAsset::new(base_asset_id, amount));
+        assert_noop!(invest(BOB_ACCOUNT_ID, 100), Error::<Test>::InvestorNotAllowlisted);
+        assert_noop!(invest(ALICE_ACCOUNT_ID, 20), Error::<Test>::ContributionBelowMinimum);
+        assert_ok!(invest(ALICE_ACCOUNT_ID, 150));
+        assert_noop!(invest(ALICE_ACCOUNT_ID, 100), Error::<Test>::ContributionAboveMaximum);
+
+        // the allowlist of the opportunity is managed by its creator
+        // and doesn't take the id of shared allowlists
+        assert_noop!(
+            Deip::update_allowlist(Origin::signed(account_id), sale_id, vec![BOB_ACCOUNT_ID], vec![]),
+            Error::<Test>::NoSuchAllowlist
+        );
+        assert_ok!(Deip::create_allowlist(Origin::signed(BOB_ACCOUNT_ID), sale_id, vec![BOB_ACCOUNT_ID]));
+        assert!(!Deip::is_investor_allowed(&sale_id, &BOB_ACCOUNT_ID));
+        assert_noop!(
+            Deip::update_investment_allowlist(Origin::signed(BOB_ACCOUNT_ID), sale_id, vec![BOB_ACCOUNT_ID], vec![]),
+            Error::<Test>::NoPermission
+        );
+        assert_ok!(Deip::update_investment_allowlist(
+            Origin::signed(account_id),
+            sale_id,
+            vec![BOB_ACCOUNT_ID],
+            vec![ALICE_ACCOUNT_ID]
+        ));
+        assert!(Deip::is_investor_allowed(&sale_id, &BOB_ACCOUNT_ID));
+        assert!(!Deip::is_investor_allowed(&sale_id, &ALICE_ACCOUNT_ID));
+
+        assert_noop!(invest(ALICE_ACCOUNT_ID, 50), Error::<Test>::InvestorNotAllowlisted);
+        assert_ok!(invest(BOB_ACCOUNT_ID, 200));
+
+        let call = pallet_deip_assets::Call::<Test>::transfer(base_asset_id, account_id, 200);
+        assert_ok!(call.dispatch_bypass_filter(Origin::signed(ALICE_ACCOUNT_ID)));
+        assert_ok!(Deip::update_investment_allowlist(
+            Origin::signed(account_id),
+            sale_id,
+            vec![ALICE_ACCOUNT_ID, account_id],
+            vec![]
+        ));
+        assert_ok!(invest(account_id, 120));
+
+        // the contribution cut at the hard cap is checked against the minimum as offered
+        assert_noop!(invest(ALICE_ACCOUNT_ID, 30), Error::<Test>::ContributionBelowMinimum);
+        assert_ok!(invest(ALICE_ACCOUNT_ID, 50));
+        assert_eq!(SimpleCrowdfundingMap::<Test>::get(sale_id).total_amount, 500);
+    })
+}
+
+#[test]
+fn cancel_investment_opportunity() {
+    new_test_ext2().execute_with(|| {
+        let (project_id, .., account_id) = create_ok_project(None);
+
+        let base_asset_id = 3u32;
+        let base_asset_total = 2_000u64;
+        create_issue_asset(ALICE_ACCOUNT_ID, base_asset_id, base_asset_total, None);
+
+        let usd_id = 0u32;
+        let usd_total = 10_000u64;
+        create_issue_asset(account_id, usd_id, usd_total, Some(project_id));
+
+        let now = pallet_timestamp::Module::<Test>::get();
+        let sale_id = H160::random();
+        assert_ok!(Deip::create_investment_opportunity(
+            Origin::signed(account_id),
+            sale_id,
+            account_id,
+            vec![DeipAsset::new(usd_id, 1_000u64)],
+            FundingModel::SimpleCrowdfunding {
+                start_time: now,
+                end_time: now + 100,
+                soft_cap: DeipAsset::new(base_asset_id, 100u64),
+                hard_cap: DeipAsset::new(base_asset_id, 500u64),
+            },
+            None,
+            None,
+            None,
+            None,
+            vec![],
+        ));
+        assert_ok!(Deip::activate_crowdfunding_impl(sale_id));
+        assert_ok!(Deip::invest_to_crowdfunding_impl(ALICE_ACCOUNT_ID, sale_id, DeipAsset::new(base_asset_id, 100u64)));
+        assert_eq!(Assets::balance(base_asset_id, ALICE_ACCOUNT_ID), base_asset_total - 100);
+
+        assert_noop!(
+            Deip::cancel_investment_opportunity(Origin::signed(ALICE_ACCOUNT_ID), sale_id),
+            Error::<Test>::NoPermission
+        );
+        assert_ok!(Deip::cancel_investment_opportunity(Origin::signed(account_id), sale_id));
+
+        assert!(matches!(
+            SimpleCrowdfundingMap::<Test>::get(sale_id).status,
+            SimpleCrowdfundingStatus::Canceled
+        ));
+        assert_eq!(Assets::balance(base_asset_id, ALICE_ACCOUNT_ID), base_asset_total);
+        assert_eq!(Assets::balance(usd_id, account_id), usd_total);
+
+        assert_noop!(
+            Deip::invest_to_crowdfunding_impl(ALICE_ACCOUNT_ID, sale_id, DeipAsset::new(base_asset_id, 100u64)),
+            Error::<Test>::InvestingNotActive
+        );
+        assert_noop!(
+            Deip::cancel_investment_opportunity(Origin::signed(account_id), sale_id),
+            Error::<Test>::InvestmentOpportunityShouldBeInactiveOrActive
+        );
+    })
+}
+
+#[test]
+fn withdraw_investment() {
+    new_test_ext2().execute_with(|| {
+        let (project_id, .., account_id) = create_ok_project(None);
+
+        let base_asset_id = 3u32;
+        let base_asset_total = 2_000u64;
+        create_issue_asset(ALICE_ACCOUNT_ID, base_asset_id, base_asset_total, None);
+
+        let usd_id = 0u32;
+        create_issue_asset(account_id, usd_id, 10_000u64, Some(project_id));
+
+        let now = pallet_timestamp::Module::<Test>::get();
+        let sale_id = H160::random();
+        assert_ok!(Deip::create_investment_opportunity(
+            Origin::signed(account_id),
+            sale_id,
+            account_id,
+            vec![DeipAsset::new(usd_id, 1_000u64)],
+            FundingModel::SimpleCrowdfunding {
+                start_time: now,
+                end_time: now + 100,
+                soft_cap: DeipAsset::new(base_asset_id, 100u64),
+                hard_cap: DeipAsset::new(base_asset_id, 500u64),
+            },
+            None,
+            None,
+            None,
+            Some(WithdrawalTerms { cooldown: 10, fee: sp_runtime::Percent::from_percent(10) }),
+            vec![],
+        ));
+        assert_ok!(Deip::activate_crowdfunding_impl(sale_id));
+        assert_ok!(Deip::invest_to_crowdfunding_impl(ALICE_ACCOUNT_ID, sale_id, DeipAsset::new(base_asset_id, 300u64)));
+
+        let withdraw = |account, amount| Deip::withdraw_investment(Origin::signed(account), sale_id, amount);
+        assert_noop!(withdraw(BOB_ACCOUNT_ID, 100), Error::<Test>::WithdrawalNoContribution);
+        assert_noop!(withdraw(ALICE_ACCOUNT_ID, 100), Error::<Test>::WithdrawalCooldownNotPassed);
+
+        Timestamp::set_timestamp(now + 10);
+        assert_noop!(withdraw(ALICE_ACCOUNT_ID, 0), Error::<Test>::WithdrawalAmountMustBePositive);
+        assert_noop!(withdraw(ALICE_ACCOUNT_ID, 301), Error::<Test>::WithdrawalAmountExceedsContribution);
+
+        assert_ok!(withdraw(ALICE_ACCOUNT_ID, 100));
+        assert_eq!(SimpleCrowdfundingMap::<Test>::get(sale_id).total_amount, 200);
+        assert_eq!(Assets::balance(base_asset_id, ALICE_ACCOUNT_ID), base_asset_total - 300 + 90);
+        assert_eq!(Assets::balance(base_asset_id, account_id), 10);
+
+        assert_ok!(withdraw(ALICE_ACCOUNT_ID, 200));
+        assert_eq!(SimpleCrowdfundingMap::<Test>::get(sale_id).total_amount, 0);
+        assert!(!InvestmentMap::<Test>::contains_key(sale_id));
+        assert!(!InvestmentIdByInvestor::<Test>::contains_key(ALICE_ACCOUNT_ID, sale_id));
+        assert_eq!(Assets::balance(base_asset_id, ALICE_ACCOUNT_ID), base_asset_total - 30);
+    })
+}
+
+#[test]
+fn multi_asset_crowdfunding() {
+    new_test_ext2().execute_with(|| {
+        let (project_id, .., account_id) = create_ok_project(None);
+
+        let base_asset_id = 3u32;
+        let other_asset_id = 4u32;
+        let asset_total = 2_000u64;
+        create_issue_asset(ALICE_ACCOUNT_ID, base_asset_id, asset_total, None);
+        create_issue_asset(BOB_ACCOUNT_ID, other_asset_id, asset_total, None);
+
+        let usd_id = 0u32;
+        create_issue_asset(account_id, usd_id, 10_000u64, Some(project_id));
+
+        let now = pallet_timestamp::Module::<Test>::get();
+        let funding_model = FundingModel::SimpleCrowdfunding {
+            start_time: now,
+            end_time: now + 100,
+            soft_cap: DeipAsset::new(base_asset_id, 100u64),
+            hard_cap: DeipAsset::new(base_asset_id, 300u64),
+        };
+        // 2 units of the other asset are worth 1 unit of the base asset
+        let rate = |asset_id, amount| ExchangeRate { asset_id, amount, value: 1 };
+        let create = |sale_id, funding_model, exchange_rates| Deip::create_investment_opportunity(
+            Origin::signed(account_id),
+            sale_id,
+            account_id,
+            vec![DeipAsset::new(usd_id, 1_000u64)],
+            funding_model,
+            None,
+            None,
+            None,
+            None,
+            exchange_rates,
+        );
+
+        let sale_id = H160::random();
+        assert_noop!(
+            create(sale_id, funding_model.clone(), vec![rate(base_asset_id, 2)]),
+            Error::<Test>::InvestmentOpportunityWrongAssetId
+        );
+        assert_noop!(
+            create(sale_id, funding_model.clone(), vec![rate(other_asset_id, 0)]),
+            Error::<Test>::ExchangeRateMustBePositive
+        );
+        assert_noop!(
+            create(sale_id, funding_model.clone(), vec![rate(other_asset_id, 2), rate(other_asset_id, 3)]),
+            Error::<Test>::ExchangeRateDuplicateAsset
+        );
+        assert_noop!(
+            create(
+                sale_id,
+                FundingModel::FixedPrice {
+                    start_time: now,
+                    end_time: now + 100,
+                    price: DeipAsset::new(base_asset_id, 300u64),
+                    max_per_account: DeipAsset::new(base_asset_id, 300u64),
+                },
+                vec![rate(other_asset_id, 2)],
+            ),
+            Error::<Test>::ExchangeRatesNotSupported
+        );
+
+        // refunds return assets that were paid
+        let canceled_sale_id = H160::random();
+        assert_ok!(create(canceled_sale_id, funding_model.clone(), vec![rate(other_asset_id, 2)]));
+        assert_ok!(Deip::activate_crowdfunding_impl(canceled_sale_id));
+        assert_ok!(Deip::invest_to_crowdfunding_impl(BOB_ACCOUNT_ID, canceled_sale_id, DeipAsset::new(other_asset_id, 100u64)));
+        assert_eq!(Assets::balance(other_asset_id, BOB_ACCOUNT_ID), asset_total - 100);
+        assert_ok!(Deip::cancel_investment_opportunity(Origin::signed(account_id), canceled_sale_id));
+        assert_eq!(Assets::balance(other_asset_id, BOB_ACCOUNT_ID), asset_total);
+
+        assert_ok!(create(sale_id, funding_model, vec![rate(other_asset_id, 2)]));
+        assert_eq!(Deip::get_exchange_rates(&sale_id), vec![rate(other_asset_id, 2)]);
+        assert_ok!(Deip::activate_crowdfunding_impl(sale_id));
+
+        let invest = |account, asset_id, amount| Deip::invest_to_crowdfunding_impl(account, sale_id, DeipAsset::new(asset_id, amount));
+        assert_noop!(invest(BOB_ACCOUNT_ID, usd_id, 100), Error::<Test>::InvestingWrongAsset);
+        assert_ok!(invest(ALICE_ACCOUNT_ID, base_asset_id, 100));
+        assert_ok!(invest(BOB_ACCOUNT_ID, other_asset_id, 200));
+        assert_eq!(SimpleCrowdfundingMap::<Test>::get(sale_id).total_amount, 200);
+
+        // caps are evaluated by the value of contributions, the excess isn't taken
+        assert_ok!(invest(BOB_ACCOUNT_ID, other_asset_id, 1_000));
+        assert_eq!(Assets::balance(other_asset_id, BOB_ACCOUNT_ID), asset_total - 400);
+
+        let sale = SimpleCrowdfundingMap::<Test>::get(sale_id);
+        assert!(matches!(sale.status, SimpleCrowdfundingStatus::Finished));
+        assert_eq!(sale.total_amount, 300);
+
+        assert_eq!(Assets::balance(usd_id, ALICE_ACCOUNT_ID), 334);
+        assert_eq!(Assets::balance(usd_id, BOB_ACCOUNT_ID), 666);
+        assert_eq!(Assets::balance(base_asset_id, account_id), 100);
+        assert_eq!(Assets::balance(other_asset_id, account_id), 400);
+    })
+}
+
+#[test]
+fn scheduled_crowdfunding_transitions() {
+    new_test_ext2().execute_with(|| {
+        let (project_id, .., account_id) = create_ok_project(None);
+
+        let base_asset_id = 3u32;
+        let base_asset_total = 2_000u64;
+        create_issue_asset(ALICE_ACCOUNT_ID, base_asset_id, base_asset_total, None);
+
+        let usd_id = 0u32;
+        let usd_total = 10_000u64;
+        create_issue_asset(account_id, usd_id, usd_total, Some(project_id));
+
+        let now = pallet_timestamp::Module::<Test>::get();
+        let start_time = now + BLOCK_TIME;
+        let end_time = now + 3 * BLOCK_TIME;
+        let create = |sale_id| Deip::create_investment_opportunity(
+            Origin::signed(account_id),
+            sale_id,
+            account_id,
+            vec![DeipAsset::new(usd_id, 1_000u64)],
+            FundingModel::SimpleCrowdfunding {
+                start_time,
+                end_time,
+                soft_cap: DeipAsset::new(base_asset_id, 100u64),
+                hard_cap: DeipAsset::new(base_asset_id, 500u64),
+            },
+            None,
+            None,
+            None,
+            None,
+            vec![],
+        );
+        let status = |sale_id| SimpleCrowdfundingMap::<Test>::get(sale_id).status;
+
+        let sale_id = H160::random();
+        let expired_sale_id = H160::random();
+        assert_ok!(create(sale_id));
+        assert_ok!(create(expired_sale_id));
+        let scheduled = |time: u64, id| ScheduledTransitions::contains_key(time.to_be_bytes(), id);
+        assert!(scheduled(start_time, sale_id) && scheduled(start_time, expired_sale_id));
+
+        let run_to_time = |time| {
+            Timestamp::set_timestamp(time);
+            System::set_block_number(System::block_number() + 1);
+            <Deip as OnInitialize<u64>>::on_initialize(System::block_number());
+        };
+
+        run_to_time(start_time - 1);
+        assert!(matches!(status(sale_id), SimpleCrowdfundingStatus::Inactive));
+
+        run_to_time(start_time);
+        assert!(matches!(status(sale_id), SimpleCrowdfundingStatus::Active));
+        assert!(matches!(status(expired_sale_id), SimpleCrowdfundingStatus::Active));
+        assert!(!scheduled(start_time, sale_id) && !scheduled(start_time, expired_sale_id));
+        assert!(scheduled(end_time, sale_id) && scheduled(end_time, expired_sale_id));
+
+        assert_ok!(Deip::invest_to_crowdfunding_impl(ALICE_ACCOUNT_ID, sale_id, DeipAsset::new(base_asset_id, 100u64)));
+
+        run_to_time(end_time);
+        assert!(matches!(status(sale_id), SimpleCrowdfundingStatus::Finished));
+        assert!(matches!(status(expired_sale_id), SimpleCrowdfundingStatus::Expired));
+        assert_eq!(ScheduledTransitions::iter().count(), 0);
+
+        assert_eq!(Assets::balance(base_asset_id, account_id), 100);
+        assert_eq!(Assets::balance(usd_id, ALICE_ACCOUNT_ID), 1_000);
+        assert_eq!(Assets::balance(usd_id, account_id), usd_total - 1_000);
+    })
+}
+
+#[test]
+fn scheduled_settlements_within_weight_budget() {
+    new_test_ext2().execute_with(|| {
+        let (project_id, .., account_id) = create_ok_project(None);
+
+        let base_asset_id = 3u32;
+        create_issue_asset(ALICE_ACCOUNT_ID, base_asset_id, 2_000u64, None);
+
+        let usd_id = 0u32;
+        create_issue_asset(account_id, usd_id, 10_000u64, Some(project_id));
+
+        let now = pallet_timestamp::Module::<Test>::get();
+        let start_time = now + BLOCK_TIME;
+        let end_time = now + 3 * BLOCK_TIME;
+        let create = |sale_id| Deip::create_investment_opportunity(
+            Origin::signed(account_id),
+            sale_id,
+            account_id,
+            vec![DeipAsset::new(usd_id, 1_000u64)],
+            FundingModel::SimpleCrowdfunding {
+                start_time,
+                end_time,
+                soft_cap: DeipAsset::new(base_asset_id, 100u64),
+                hard_cap: DeipAsset::new(base_asset_id, 500u64),
+            },
+            None,
+            None,
+            None,
+            None,
+            vec![],
+        );
+        let status = |sale_id| SimpleCrowdfundingMap::<Test>::get(sale_id).status;
+        let scheduled = |time: u64, id| ScheduledTransitions::contains_key(time.to_be_bytes(), id);
+
+        // transitions due at the same moment are ordered by the sale
+        let small_sale_id = H160::repeat_byte(1);
+        let large_sale_id = H160::repeat_byte(2);
+        assert_ok!(create(small_sale_id));
+        assert_ok!(create(large_sale_id));
+
+        let run_to_time = |time| {
+            Timestamp::set_timestamp(time);
+            System::set_block_number(System::block_number() + 1);
+            <Deip as OnInitialize<u64>>::on_initialize(System::block_number())
+        };
+
+        run_to_time(start_time);
+        assert!(matches!(status(large_sale_id), SimpleCrowdfundingStatus::Active));
+
+        assert_ok!(Deip::invest_to_crowdfunding_impl(ALICE_ACCOUNT_ID, small_sale_id, DeipAsset::new(base_asset_id, 100u64)));
+        // settlement of the large sale takes more than the budget
+        for investor in 1_000..1_008u64 {
+            let call = pallet_deip_assets::Call::<Test>::transfer(base_asset_id, investor, 20);
+            assert_ok!(call.dispatch_bypass_filter(Origin::signed(ALICE_ACCOUNT_ID)));
+            assert_ok!(Deip::invest_to_crowdfunding_impl(investor, large_sale_id, DeipAsset::new(base_asset_id, 20u64)));
+        }
+
+        let weight = run_to_time(end_time);
+        assert!(weight <= MaxScheduledWeight::get());
+        assert!(matches!(status(small_sale_id), SimpleCrowdfundingStatus::Finished));
+        assert!(!scheduled(end_time, small_sale_id));
+
+        // the large sale waits for the call submitted by the offchain worker
+        assert!(matches!(status(large_sale_id), SimpleCrowdfundingStatus::Active));
+        assert!(scheduled(end_time, large_sale_id));
+        run_to_time(end_time + BLOCK_TIME);
+        assert!(scheduled(end_time, large_sale_id));
+
+        assert_ok!(Deip::finish_crowdfunding(Origin::none(), large_sale_id));
+        assert!(matches!(status(large_sale_id), SimpleCrowdfundingStatus::Finished));
+        run_to_time(end_time + 2 * BLOCK_TIME);
+        assert_eq!(ScheduledTransitions::iter().count(), 0);
+        assert_eq!(Assets::balance(usd_id, 1_000), 1_000 / 8);
+    })
+}
+
+#[test]
+fn retry_failed_settlement() {
+    new_test_ext2().execute_with(|| {
+        let (project_id, .., account_id) = create_ok_project(None);
+
+        let base_asset_id = 3u32;
+        let base_asset_total = 2_000u64;
+        create_issue_asset(ALICE_ACCOUNT_ID, base_asset_id, base_asset_total, None);
+
+        let usd_id = 0u32;
+        let usd_total = 10_000u64;
+        create_issue_asset(account_id, usd_id, usd_total, Some(project_id));
+
+        let now = pallet_timestamp::Module::<Test>::get();
+        let sale_id = H160::random();
+        assert_ok!(Deip::create_investment_opportunity(
+            Origin::signed(account_id),
+            sale_id,
+            account_id,
+            vec![DeipAsset::new(usd_id, 1_000u64)],
+            FundingModel::SimpleCrowdfunding {
+                start_time: now,
+                end_time: now + 100,
+                soft_cap: DeipAsset::new(base_asset_id, 100u64),
+                hard_cap: DeipAsset::new(base_asset_id, 500u64),
+            },
+            None,
+            None,
+            None,
+            None,
+            vec![],
+        ));
+        assert_ok!(Deip::activate_crowdfunding_impl(sale_id));
+        assert_ok!(Deip::invest_to_crowdfunding_impl(ALICE_ACCOUNT_ID, sale_id, DeipAsset::new(base_asset_id, 100u64)));
+
+        assert_noop!(
+            Deip::retry_settlement(Origin::signed(BOB_ACCOUNT_ID), sale_id),
+            Error::<Test>::SettlementNotPending
+        );
+
+        // reserved contributions can't be refunded while the asset is frozen
+        let call = pallet_assets::Call::<Test>::freeze_asset(base_asset_id);
+        assert_ok!(call.dispatch_bypass_filter(Origin::signed(ALICE_ACCOUNT_ID)));
+
+        assert_ok!(Deip::cancel_investment_opportunity(Origin::signed(account_id), sale_id));
+        assert!(matches!(
+            SimpleCrowdfundingMap::<Test>::get(sale_id).status,
+            SimpleCrowdfundingStatus::SettlementPending
+        ));
+        assert_eq!(PendingSettlements::get(sale_id), Some(SimpleCrowdfundingStatus::Canceled));
+        assert_eq!(Assets::balance(base_asset_id, ALICE_ACCOUNT_ID), base_asset_total - 100);
+        assert_eq!(Assets::balance(usd_id, account_id), usd_total - 1_000);
+
+        assert_noop!(
+            Deip::retry_settlement(Origin::signed(BOB_ACCOUNT_ID), sale_id),
+            Error::<Test>::SettlementRefundFailed
+        );
+
+        let call = pallet_assets::Call::<Test>::thaw_asset(base_asset_id);
+        assert_ok!(call.dispatch_bypass_filter(Origin::signed(ALICE_ACCOUNT_ID)));
+
+        assert_ok!(Deip::retry_settlement(Origin::signed(BOB_ACCOUNT_ID), sale_id));
+        assert!(matches!(
+            SimpleCrowdfundingMap::<Test>::get(sale_id).status,
+            SimpleCrowdfundingStatus::Canceled
+        ));
+        assert_eq!(PendingSettlements::get(sale_id), None);
+        assert_eq!(Assets::balance(base_asset_id, ALICE_ACCOUNT_ID), base_asset_total);
+        assert_eq!(Assets::balance(usd_id, account_id), usd_total);
+    })
+}
+
+#[test]
+fn distribute_dividend() {
+    new_test_ext2().execute_with(|| {
+        let (project_id, .., account_id) = create_ok_project(None);
+
+        let usd_id = 0u32;
+        create_issue_asset(account_id, usd_id, 1_000u64, Some(project_id));
+        let call = pallet_deip_assets::Call::<Test>::transfer(usd_id, ALICE_ACCOUNT_ID, 250);
+        assert_ok!(call.dispatch_bypass_filter(Origin::signed(account_id)));
+
+        let payout_asset_id = 3u32;
+        let payout_total = 2_000u64;
+        create_issue_asset(account_id, payout_asset_id, payout_total, None);
+
+        let distribute = |account, asset_id, payout_asset_id| {
+            let call = pallet_deip_assets::Call::<Test>::distribute_dividend(asset_id, payout_asset_id, 400);
+            call.dispatch_bypass_filter(Origin::signed(account))
+        };
+        assert_noop!(
+            distribute(ALICE_ACCOUNT_ID, usd_id, payout_asset_id),
+            pallet_deip_assets::Error::<Test>::ProjectDoesNotBelongToTeam
+        );
+        assert_noop!(
+            distribute(account_id, payout_asset_id, usd_id),
+            pallet_deip_assets::Error::<Test>::DividendAssetNotSecurityToken
+        );
+        assert_ok!(distribute(account_id, usd_id, payout_asset_id));
+        let dividend_id = 0;
+        assert_eq!(Assets::balance(payout_asset_id, account_id), payout_total - 400);
+
+        // transfers after the distribution don't change shares
+        let call = pallet_deip_assets::Call::<Test>::transfer(usd_id, BOB_ACCOUNT_ID, 250);
+        assert_ok!(call.dispatch_bypass_filter(Origin::signed(ALICE_ACCOUNT_ID)));
+        assert_eq!(DeipAssets::claimable_dividend(dividend_id, &ALICE_ACCOUNT_ID), 100);
+        assert_eq!(DeipAssets::claimable_dividend(dividend_id, &BOB_ACCOUNT_ID), 0);
+
+        let claim = |account| {
+            let call = pallet_deip_assets::Call::<Test>::claim_dividend(dividend_id);
+            call.dispatch_bypass_filter(Origin::signed(account))
+        };
+        assert_noop!(claim(BOB_ACCOUNT_ID), pallet_deip_assets::Error::<Test>::DividendNothingToClaim);
+        assert_ok!(claim(ALICE_ACCOUNT_ID));
+        assert_eq!(Assets::balance(payout_asset_id, ALICE_ACCOUNT_ID), 100);
+        assert_eq!(DeipAssets::claimable_dividend(dividend_id, &ALICE_ACCOUNT_ID), 0);
+        assert_noop!(claim(ALICE_ACCOUNT_ID), pallet_deip_assets::Error::<Test>::DividendAlreadyClaimed);
+
+        let reclaim = || {
+            let call = pallet_deip_assets::Call::<Test>::reclaim_dividend(dividend_id);
+            call.dispatch_bypass_filter(Origin::signed(BOB_ACCOUNT_ID))
+        };
+        assert_noop!(reclaim(), pallet_deip_assets::Error::<Test>::DividendNotExpired);
+
+        System::set_block_number(System::block_number() + 10);
+        assert_noop!(claim(account_id), pallet_deip_assets::Error::<Test>::DividendExpired);
+        assert_ok!(reclaim());
+        assert_eq!(Assets::balance(payout_asset_id, account_id), payout_total - 100);
+        assert_noop!(reclaim(), pallet_deip_assets::Error::<Test>::NoSuchDividend);
+    })
+}
+
+#[test]
+fn balance_snapshots() {
+    new_test_ext2().execute_with(|| {
+        let (project_id, .., account_id) = create_ok_project(None);
+
+        let usd_id = 0u32;
+        create_issue_asset(account_id, usd_id, 1_000u64, Some(project_id));
+
+        let snapshot = |account, asset_id| {
+            let call = pallet_deip_assets::Call::<Test>::snapshot(asset_id);
+            call.dispatch_bypass_filter(account)
+        };
+        assert_noop!(
+            snapshot(Origin::signed(ALICE_ACCOUNT_ID), usd_id),
+            pallet_deip_assets::Error::<Test>::ProjectDoesNotBelongToTeam
+        );
+        assert_eq!(DeipAssets::balance_at(usd_id, &account_id, 1), None);
+
+        assert_ok!(snapshot(Origin::signed(account_id), usd_id));
+        assert_eq!(DeipAssets::balance_at(usd_id, &account_id, 1), Some(1_000));
+        assert_eq!(DeipAssets::total_supply_at(usd_id, 1), Some(1_000));
+
+        let call = pallet_deip_assets::Call::<Test>::transfer(usd_id, ALICE_ACCOUNT_ID, 300);
+        assert_ok!(call.dispatch_bypass_filter(Origin::signed(account_id)));
+        let call = pallet_deip_assets::Call::<Test>::issue_asset(usd_id, BOB_ACCOUNT_ID, 500);
+        assert_ok!(call.dispatch_bypass_filter(Origin::signed(account_id)));
+
+        assert_ok!(snapshot(Origin::signed(account_id), usd_id));
+
+        let call = pallet_deip_assets::Call::<Test>::transfer(usd_id, BOB_ACCOUNT_ID, 100);
+        assert_ok!(call.dispatch_bypass_filter(Origin::signed(ALICE_ACCOUNT_ID)));
+
+        assert_eq!(DeipAssets::balance_at(usd_id, &account_id, 1), Some(1_000));
+        assert_eq!(DeipAssets::balance_at(usd_id, &ALICE_ACCOUNT_ID, 1), Some(0));
+        assert_eq!(DeipAssets::balance_at(usd_id, &BOB_ACCOUNT_ID, 1), Some(0));
+        assert_eq!(DeipAssets::total_supply_at(usd_id, 1), Some(1_000));
+
+        assert_eq!(DeipAssets::balance_at(usd_id, &account_id, 2), Some(700));
+        assert_eq!(DeipAssets::balance_at(usd_id, &ALICE_ACCOUNT_ID, 2), Some(300));
+        assert_eq!(DeipAssets::balance_at(usd_id, &BOB_ACCOUNT_ID, 2), Some(500));
+        assert_eq!(DeipAssets::total_supply_at(usd_id, 2), Some(1_500));
+
+        assert_eq!(DeipAssets::balance_at(usd_id, &ALICE_ACCOUNT_ID, 3), None);
+
+        // snapshots of assets that aren't security tokens are taken by root
+        let eur_id = 1u32;
+        create_issue_asset(account_id, eur_id, 1_000u64, None);
+        assert_noop!(snapshot(Origin::signed(account_id), eur_id), sp_runtime::DispatchError::BadOrigin);
+        assert_ok!(snapshot(Origin::root(), eur_id));
+    })
+}
+
+#[test]
+fn transfer_rules() {
+    new_test_ext2().execute_with(|| {
+        let (project_id, .., account_id) = create_ok_project(None);
+
+        let usd_id = 0u32;
+        create_issue_asset(account_id, usd_id, 1_000u64, Some(project_id));
+        assert_eq!(DeipAssets::holder_count(usd_id), 1);
+
+        let locked_until = Timestamp::get() + 1_000;
+        let rules = pallet_deip_assets::TransferRules {
+            holder_allowlist: true,
+            max_holders: Some(2),
+            locked_until: Some(locked_until),
+            jurisdictions: vec![*b"DE"],
+        };
+        let set_rules = |account, asset_id, rules| {
+            let call = pallet_deip_assets::Call::<Test>::set_transfer_rules(asset_id, rules);
+            call.dispatch_bypass_filter(Origin::signed(account))
+        };
+        assert_noop!(
+            set_rules(ALICE_ACCOUNT_ID, usd_id, Some(rules.clone())),
+            pallet_deip_assets::Error::<Test>::ProjectDoesNotBelongToTeam
+        );
+        let eur_id = 1u32;
+        create_issue_asset(account_id, eur_id, 1_000u64, None);
+        assert_noop!(
+            set_rules(account_id, eur_id, Some(rules.clone())),
+            pallet_deip_assets::Error::<Test>::TransferRulesAssetNotSecurityToken
+        );
+        assert_ok!(set_rules(account_id, usd_id, Some(rules)));
+
+        let transfer = |from, to, amount| {
+            let call = pallet_deip_assets::Call::<Test>::transfer(usd_id, to, amount);
+            call.dispatch_bypass_filter(Origin::signed(from))
+        };
+        assert_noop!(
+            transfer(account_id, ALICE_ACCOUNT_ID, 100),
+            pallet_deip_assets::Error::<Test>::TransferLockedUp
+        );
+
+        Timestamp::set_timestamp(locked_until);
+        assert_noop!(
+            transfer(account_id, ALICE_ACCOUNT_ID, 100),
+            pallet_deip_assets::Error::<Test>::TransferRecipientNotAllowlisted
+        );
+
+        let call = pallet_deip_assets::Call::<Test>::update_holder_allowlist(
+            usd_id,
+            vec![ALICE_ACCOUNT_ID, BOB_ACCOUNT_ID],
+            vec![],
+        );
+        assert_ok!(call.dispatch_bypass_filter(Origin::signed(account_id)));
+        assert_noop!(
+            transfer(account_id, ALICE_ACCOUNT_ID, 100),
+            pallet_deip_assets::Error::<Test>::TransferRecipientJurisdictionUnknown
+        );
+
+        let set_jurisdiction = |origin, who, jurisdiction| {
+            let call = pallet_deip_assets::Call::<Test>::set_jurisdiction(who, jurisdiction);
+            call.dispatch_bypass_filter(origin)
+        };
+        assert_noop!(
+            set_jurisdiction(Origin::signed(account_id), ALICE_ACCOUNT_ID, Some(*b"DE")),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_ok!(set_jurisdiction(Origin::root(), ALICE_ACCOUNT_ID, Some(*b"DE")));
+        assert_ok!(set_jurisdiction(Origin::root(), BOB_ACCOUNT_ID, Some(*b"US")));
+        assert_noop!(
+            transfer(account_id, BOB_ACCOUNT_ID, 100),
+            pallet_deip_assets::Error::<Test>::TransferRecipientJurisdictionNotAllowed
+        );
+
+        assert_ok!(transfer(account_id, ALICE_ACCOUNT_ID, 100));
+        assert_eq!(DeipAssets::holder_count(usd_id), 2);
+
+        assert_ok!(set_jurisdiction(Origin::root(), BOB_ACCOUNT_ID, Some(*b"DE")));
+        assert_noop!(
+            transfer(account_id, BOB_ACCOUNT_ID, 100),
+            pallet_deip_assets::Error::<Test>::TransferMaxHoldersExceeded
+        );
+
+        // the holder that transfers all its tokens leaves room for the recipient
+        assert_ok!(transfer(ALICE_ACCOUNT_ID, BOB_ACCOUNT_ID, 100));
+        assert_eq!(DeipAssets::holder_count(usd_id), 2);
+        assert_eq!(Assets::balance(usd_id, BOB_ACCOUNT_ID), 100);
+
+        // rules apply to transfers made on behalf of investment opportunities as well
+        let outsider = 42u64;
+        assert!(DeipAssets::transactionally_transfer(&account_id, usd_id, &[(outsider, 100)]).is_err());
+        assert_eq!(Assets::balance(usd_id, outsider), 0);
+
+        assert_ok!(set_rules(account_id, usd_id, None));
+        assert_ok!(transfer(account_id, ALICE_ACCOUNT_ID, 100));
+        assert_eq!(DeipAssets::holder_count(usd_id), 3);
+
+        let locked_until = Timestamp::get() + 1_000;
+        let rules = pallet_deip_assets::TransferRules {
+            holder_allowlist: true,
+            max_holders: None,
+            locked_until: Some(locked_until),
+            jurisdictions: vec![*b"DE"],
+        };
+        assert_ok!(set_rules(account_id, usd_id, Some(rules)));
+
+        // issued tokens are subject to the rules
+        let issue = |to, amount| {
+            let call = pallet_deip_assets::Call::<Test>::issue_asset(usd_id, to, amount);
+            call.dispatch_bypass_filter(Origin::signed(account_id))
+        };
+        assert_noop!(
+            issue(outsider, 100),
+            pallet_deip_assets::Error::<Test>::TransferRecipientNotAllowlisted
+        );
+        assert_ok!(issue(ALICE_ACCOUNT_ID, 100));
+        assert_eq!(Assets::balance(usd_id, ALICE_ACCOUNT_ID), 200);
+
+        // settlements with investors aren't subject to the lock-up
+        let sale_id = H160::random();
+        assert!(DeipAssets::transactionally_reserve(&account_id, sale_id, &[(usd_id, 200)], eur_id, &[]).is_ok());
+        assert!(DeipAssets::transfer_from_reserved(sale_id, &BOB_ACCOUNT_ID, usd_id, 100).is_ok());
+        assert_eq!(Assets::balance(usd_id, BOB_ACCOUNT_ID), 200);
+        assert!(DeipAssets::transfer_from_reserved(sale_id, &outsider, usd_id, 100).is_err());
+        assert_noop!(
+            transfer(BOB_ACCOUNT_ID, ALICE_ACCOUNT_ID, 100),
+            pallet_deip_assets::Error::<Test>::TransferLockedUp
+        );
+    })
+}
+
+#[test]
+fn track_holders_on_upgrade() {
+    new_test_ext2().execute_with(|| {
+        use frame_support::{storage::migration::{put_storage_value, remove_storage_prefix}, traits::OnRuntimeUpgrade};
+
+        let (project_id, .., account_id) = create_ok_project(None);
+
+        let usd_id = 0u32;
+        create_issue_asset(account_id, usd_id, 1_000u64, Some(project_id));
+        let call = pallet_deip_assets::Call::<Test>::transfer(usd_id, ALICE_ACCOUNT_ID, 100);
+        assert_ok!(call.dispatch_bypass_filter(Origin::signed(account_id)));
+        let sale_id = H160::random();
+        assert!(DeipAssets::transactionally_reserve(&account_id, sale_id, &[(usd_id, 200)], usd_id, &[]).is_ok());
+        assert_eq!(DeipAssets::holder_count(usd_id), 3);
+
+        // lay out the storage the way the previous release did
+        remove_storage_prefix(b"DeipAssets", b"Holders", &[]);
+        remove_storage_prefix(b"DeipAssets", b"HolderCount", &[]);
+        put_storage_value(b"DeipAssets", b"StorageVersion", &[], pallet_deip_assets::Releases::V2);
+        assert_eq!(DeipAssets::holder_count(usd_id), 0);
+
+        <DeipAssets as OnRuntimeUpgrade>::on_runtime_upgrade();
+
+        assert_eq!(DeipAssets::holder_count(usd_id), 3);
+        // the holder that transfers all its tokens is replaced by the recipient
+        let call = pallet_deip_assets::Call::<Test>::transfer(usd_id, BOB_ACCOUNT_ID, 100);
+        assert_ok!(call.dispatch_bypass_filter(Origin::signed(ALICE_ACCOUNT_ID)));
+        assert_eq!(DeipAssets::holder_count(usd_id), 3);
+    })
+}
+
+#[test]
+fn deip_assets_rpc_getters() {
+    new_test_ext2().execute_with(|| {
+        let (project_id, .., account_id) = create_ok_project(None);
+
+        let usd_id = 0u32;
+        create_issue_asset(account_id, usd_id, 1_000u64, Some(project_id));
+        let eur_id = 1u32;
+        create_issue_asset(account_id, eur_id, 500u64, None);
+
+        assert_eq!(DeipAssets::project_security_tokens(&project_id), vec![usd_id]);
+        assert_eq!(DeipAssets::rpc_asset_project(usd_id), Some(project_id));
+        assert_eq!(DeipAssets::rpc_asset_project(eur_id), None);
+
+        let mut balances = DeipAssets::rpc_account_balances(&account_id);
+        balances.sort();
+        assert_eq!(balances, vec![(usd_id, 1_000), (eur_id, 500)]);
+        assert!(DeipAssets::rpc_account_balances(&ALICE_ACCOUNT_ID).is_empty());
+
+        assert_eq!(DeipAssets::rpc_asset_metadata(eur_id), None);
+        let call = pallet_deip_assets::Call::<Test>::set_metadata(
+            eur_id,
+            b"Euro".to_vec(),
+            b"EUR".to_vec(),
+            2,
+        );
+        assert_ok!(call.dispatch_bypass_filter(Origin::signed(account_id)));
+        assert_eq!(
+            DeipAssets::rpc_asset_metadata(eur_id),
+            Some(pallet_deip_assets::AssetMetadata {
+                name: b"Euro".to_vec(),
+                symbol: b"EUR".to_vec(),
+                decimals: 2,
+            })
+        );
+
+        let call = pallet_deip_assets::Call::<Test>::set_metadata(eur_id, vec![], vec![], 0);
+        assert_ok!(call.dispatch_bypass_filter(Origin::signed(account_id)));
+        assert_eq!(DeipAssets::rpc_asset_metadata(eur_id), None);
+    })
+}
+
+#[test]
+fn register_known_assets_on_upgrade() {
+    new_test_ext2().execute_with(|| {
+        use frame_support::{storage::migration::{put_storage_value, remove_storage_prefix}, traits::OnRuntimeUpgrade};
+
+        let (project_id, .., account_id) = create_ok_project(None);
+
+        let usd_id = 0u32;
+        create_issue_asset(account_id, usd_id, 1_000u64, Some(project_id));
+        // raised by an investment opportunity
+        let eur_id = 1u32;
+        create_issue_asset(account_id, eur_id, 500u64, None);
+        let sale_id = H160::random();
+        assert!(DeipAssets::transactionally_reserve(&account_id, sale_id, &[(usd_id, 100)], eur_id, &[]).is_ok());
+        // known to neither the pallet's indexes nor investment opportunities
+        let gbp_id = 2u32;
+        create_issue_asset(account_id, gbp_id, 300u64, None);
+
+        // lay out the storage the way the previous release did
+        remove_storage_prefix(b"DeipAssets", b"KnownAssets", &[]);
+        put_storage_value(b"DeipAssets", b"StorageVersion", &[], pallet_deip_assets::Releases::V1);
+        assert!(DeipAssets::rpc_account_balances(&account_id).is_empty());
+
+        <DeipAssets as OnRuntimeUpgrade>::on_runtime_upgrade();
+
+        let mut balances = DeipAssets::rpc_account_balances(&account_id);
+        balances.sort();
+        assert_eq!(balances, vec![(usd_id, 900), (eur_id, 500)]);
+    })
+}
@@ -14,11 +14,13 @@ pub trait DeipAssetSystem<AccountId> {
     /// Tries to transfer assets specified by `shares` from
     /// `account` to a specific balance identified by `id`.
     /// Some collateral fee may be locked from `account`.
+    /// Contributions in `asset` and `other_assets` are accepted to the balance.
     fn transactionally_reserve(
         account: &AccountId,
         id: InvestmentId,
         shares: &[(Self::AssetId, Self::Balance)],
         asset: Self::AssetId,
+        other_assets: &[Self::AssetId],
     ) -> Result<(), ReserveError<Self::AssetId>>;
 
     /// Transfers all assets currently owned by `id` to the account, used in
@@ -33,11 +35,32 @@ pub trait DeipAssetSystem<AccountId> {
         amount: Self::Balance,
     ) -> Result<(), UnreserveError<Self::AssetId>>;
 
-    /// Transfers `amount` of assets from `who` to account specified by `id`.
-    /// Assets should be specified in call to `transactionally_reserve`.
+    /// Transfers `amount` of `asset` from `who` to account specified by `id`.
+    /// The asset should be specified in call to `transactionally_reserve`.
     fn transfer_to_reserved(
         who: &AccountId,
         id: InvestmentId,
+        asset: Self::AssetId,
         amount: Self::Balance,
     ) -> Result<(), UnreserveError<Self::AssetId>>;
+
+    /// Transfers `asset` from `from` to every account specified in `transfers`
+    /// in a transactional way.
+    fn transactionally_transfer(
+        from: &AccountId,
+        asset: Self::AssetId,
+        transfers: &[(AccountId, Self::Balance)],
+    ) -> Result<(), ()>;
+
+    /// Forbids `who` to transfer `amount` of `asset` until it is unlocked.
+    fn lock(who: &AccountId, asset: Self::AssetId, amount: Self::Balance) -> Result<(), ()>;
+
+    /// Allows `who` to transfer `amount` of `asset` locked earlier.
+    fn unlock(who: &AccountId, asset: Self::AssetId, amount: Self::Balance);
+
+    /// Returns the account that reserved assets with `transactionally_reserve` for `id`.
+    fn get_investment_creator(id: &InvestmentId) -> Option<AccountId>;
+
+    /// Returns security tokens that are issued for the project `id`.
+    fn get_project_security_tokens(id: &ProjectId) -> Vec<Self::AssetId>;
 }
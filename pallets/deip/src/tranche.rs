@@ -0,0 +1,381 @@
+use crate::*;
+
+use crate::traits::DeipAssetSystem;
+use frame_support::transactional;
+use sp_runtime::{traits::{Saturating, Zero}, Percent, SaturatedConversion};
+
+/// Part of raised funds released to the team once the milestone is approved
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct Terms<Moment> {
+    /// Share of raised funds
+    pub share: Percent,
+    /// Type of Project Content which reports the milestone
+    pub milestone_type: ProjectContentType,
+    /// The milestone should be approved before the moment.
+    /// Otherwise anyone can return funds that are still locked to investors
+    pub deadline: Moment,
+}
+
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub enum Status {
+    /// The milestone isn't reached yet
+    Locked,
+    /// The milestone is reported by the Project Content and awaits approval of investors
+    UnderReview(ProjectContentId),
+    /// Funds are transferred to the team
+    Released,
+}
+
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct Tranche<Moment> {
+    pub terms: Terms<Moment>,
+    pub status: Status,
+}
+
+/// Raised funds of an investment opportunity that are released by milestones
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct Tranches<AccountId, Balance, Moment> {
+    /// Reference to the Project the milestones are reported in
+    pub project_id: ProjectId,
+    /// Reference to the Team funds are released to. Follows the team of the Project
+    pub team_id: AccountId,
+    pub tranches: Vec<Tranche<Moment>>,
+    /// Index of the tranche to be released next
+    pub current: u32,
+    /// Raised funds that are neither released nor refunded yet
+    pub locked: Balance,
+}
+
+impl<AccountId, Balance, Moment> Tranches<AccountId, Balance, Moment> {
+    fn current_tranche_mut(&mut self) -> Option<&mut Tranche<Moment>> {
+        self.tranches.get_mut(self.current as usize)
+    }
+
+    /// Share of the current tranche in funds that are still locked
+    fn current_share(&self) -> (u128, u128) {
+        let shares = self.tranches[self.current as usize..]
+            .iter()
+            .map(|tranche| tranche.terms.share.deconstruct() as u128);
+        let remaining: u128 = shares.clone().sum();
+        let current = shares.take(1).sum();
+        (current, remaining)
+    }
+}
+
+impl<T: Config> Module<T> {
+    #[allow(clippy::too_many_arguments)]
+    pub(super) fn create_milestone_tranches(
+        account: T::AccountId,
+        external_id: InvestmentId,
+        start_time: T::Moment,
+        end_time: T::Moment,
+        soft_cap: DeipAssetOf<T>,
+        hard_cap: DeipAssetOf<T>,
+        shares: Vec<DeipAssetOf<T>>,
+        project_id: ProjectId,
+        tranches: Vec<Terms<T::Moment>>,
+    ) -> DispatchResult {
+        let project = ProjectMap::<T>::try_get(project_id).map_err(|_| Error::<T>::NoSuchProject)?;
+        ensure!(project.team_id == account, Error::<T>::ProjectNotBelongToTeam);
+
+        ensure!(!tranches.is_empty(), Error::<T>::MilestoneTranchesNotSpecified);
+        ensure!(
+            tranches.iter().all(|terms| !terms.share.is_zero()),
+            Error::<T>::MilestoneTrancheShareMustBePositive
+        );
+        let total_share: u32 = tranches.iter().map(|terms| terms.share.deconstruct() as u32).sum();
+        ensure!(
+            total_share == Percent::from_percent(100).deconstruct() as u32,
+            Error::<T>::MilestoneTranchesMustSumToWhole
+        );
+        let mut previous_deadline = end_time;
+        for terms in &tranches {
+            ensure!(terms.deadline > previous_deadline, Error::<T>::MilestoneDeadlinesInvalid);
+            previous_deadline = terms.deadline;
+        }
+
+        Self::create_simple_crowdfunding(
+            account.clone(),
+            external_id,
+            start_time,
+            end_time,
+            soft_cap,
+            hard_cap,
+            shares,
+            Vec::new(),
+        )?;
+
+        MilestoneTranchesMap::<T>::insert(
+            external_id,
+            Tranches {
+                project_id,
+                team_id: account,
+                tranches: tranches
+                    .into_iter()
+                    .map(|terms| Tranche { terms, status: Status::Locked })
+                    .collect(),
+                current: 0,
+                locked: Zero::zero(),
+            },
+        );
+        MilestoneTranchesIdByProjectId::insert(project_id, external_id, ());
+
+        Ok(())
+    }
+
+    /// Keeps raised funds of the finished sale in the investment account
+    pub(super) fn lock_tranches(sale: &SimpleCrowdfundingOf<T>) {
+        MilestoneTranchesMap::<T>::mutate(sale.external_id, |maybe_tranches| {
+            if let Some(tranches) = maybe_tranches {
+                tranches.locked = sale.total_amount;
+            }
+        });
+    }
+
+    pub(super) fn submit_milestone_impl(
+        account: T::AccountId,
+        sale_id: InvestmentId,
+        content_id: ProjectContentId,
+    ) -> DispatchResult {
+        let sale = SimpleCrowdfundingMap::<T>::try_get(sale_id)
+            .map_err(|_| Error::<T>::InvestmentOpportunityNotFound)?;
+        ensure!(
+            matches!(sale.status, SimpleCrowdfundingStatus::Finished),
+            Error::<T>::MilestoneTranchesNotFunded
+        );
+
+        let mut tranches = MilestoneTranchesMap::<T>::get(sale_id).ok_or(Error::<T>::NoSuchMilestoneTranches)?;
+        ensure!(tranches.team_id == account, Error::<T>::NoPermission);
+
+        let content = ProjectContentMap::<T>::try_get(tranches.project_id, content_id)
+            .map_err(|_| Error::<T>::NoSuchProjectContent)?;
+
+        let tranche = tranches.current_tranche_mut().ok_or(Error::<T>::MilestoneTranchesAlreadyReleased)?;
+        ensure!(
+            matches!(tranche.status, Status::Locked),
+            Error::<T>::MilestoneAlreadySubmitted
+        );
+        ensure!(
+            tranche.terms.milestone_type == content.content_type,
+            Error::<T>::MilestoneTypeMismatch
+        );
+
+        tranche.status = Status::UnderReview(content_id);
+        MilestoneTranchesMap::<T>::insert(sale_id, tranches);
+
+        Self::deposit_event(RawEvent::MilestoneSubmitted(sale_id, content_id));
+
+        Ok(())
+    }
+
+    #[transactional]
+    pub(super) fn vote_milestone_impl(
+        account: T::AccountId,
+        sale_id: InvestmentId,
+        approve: bool,
+    ) -> DispatchResult {
+        let sale = SimpleCrowdfundingMap::<T>::try_get(sale_id)
+            .map_err(|_| Error::<T>::InvestmentOpportunityNotFound)?;
+        let mut tranches = MilestoneTranchesMap::<T>::get(sale_id).ok_or(Error::<T>::NoSuchMilestoneTranches)?;
+        ensure!(
+            matches!(
+                tranches.current_tranche_mut().map(|tranche| &tranche.status),
+                Some(Status::UnderReview(_))
+            ),
+            Error::<T>::MilestoneNotUnderReview
+        );
+
+        let mut investments = InvestmentMap::<T>::get(sale_id);
+        let index = investments
+            .binary_search_by_key(&&account, |(investor, _)| investor)
+            .map_err(|_| Error::<T>::MilestoneVoterNotInvestor)?;
+
+        if approve {
+            ensure!(
+                !MilestoneApprovals::<T>::contains_key(sale_id, &account),
+                Error::<T>::MilestoneAlreadyApproved
+            );
+            MilestoneApprovals::<T>::insert(sale_id, &account, ());
+        } else {
+            // the investor leaves with the pro rata part of funds that are still locked
+            let invested = investments.iter().fold(Zero::zero(), |total: DeipAssetBalanceOf<T>, (_, investment)| {
+                total.saturating_add(investment.amount)
+            });
+            let (_, investment) = investments.remove(index);
+            let refund: DeipAssetBalanceOf<T> = (tranches.locked.saturated_into::<u128>()
+                .saturating_mul(investment.amount.saturated_into())
+                / invested.saturated_into::<u128>())
+                .saturated_into();
+
+            if !refund.is_zero() {
+                T::AssetSystem::transfer_from_reserved(sale_id, &account, sale.asset_id, refund)
+                    .map_err(|_| Error::<T>::MilestoneTrancheTransferFailed)?;
+            }
+            tranches.locked -= refund;
+            MilestoneApprovals::<T>::remove(sale_id, &account);
+            InvestmentMap::<T>::insert(sale_id, &investments);
+
+            Self::deposit_event(RawEvent::MilestoneTrancheRefunded(
+                sale_id,
+                account,
+                DeipAsset::new(sale.asset_id, refund),
+            ));
+        }
+
+        let invested = investments.iter().fold(Zero::zero(), |total: DeipAssetBalanceOf<T>, (_, investment)| {
+            total.saturating_add(investment.amount)
+        });
+        let approved = investments
+            .iter()
+            .filter(|(investor, _)| MilestoneApprovals::<T>::contains_key(sale_id, investor))
+            .fold(Zero::zero(), |total: DeipAssetBalanceOf<T>, (_, investment)| {
+                total.saturating_add(investment.amount)
+            });
+
+        if investments.is_empty() {
+            // nobody is left to release the funds to
+            tranches.current = tranches.tranches.len() as u32;
+            Self::close_tranches(sale_id, tranches)?;
+        } else if approved.saturating_add(approved) > invested {
+            Self::release_tranche(&sale, tranches)?;
+        } else {
+            MilestoneTranchesMap::<T>::insert(sale_id, tranches);
+        }
+
+        Ok(())
+    }
+
+    fn release_tranche(
+        sale: &SimpleCrowdfundingOf<T>,
+        mut tranches: TranchesOf<T>,
+    ) -> DispatchResult {
+        let (share, remaining) = tranches.current_share();
+        let amount: DeipAssetBalanceOf<T> = if share == remaining {
+            tranches.locked
+        } else {
+            (tranches.locked.saturated_into::<u128>().saturating_mul(share) / remaining).saturated_into()
+        };
+
+        if !amount.is_zero() {
+            T::AssetSystem::transfer_from_reserved(sale.external_id, &tranches.team_id, sale.asset_id, amount)
+                .map_err(|_| Error::<T>::MilestoneTrancheTransferFailed)?;
+        }
+
+        tranches.locked -= amount;
+        if let Some(tranche) = tranches.current_tranche_mut() {
+            tranche.status = Status::Released;
+        }
+        tranches.current += 1;
+        MilestoneApprovals::<T>::remove_prefix(sale.external_id);
+
+        Self::deposit_event(RawEvent::MilestoneTrancheReleased(
+            sale.external_id,
+            DeipAsset::new(sale.asset_id, amount),
+        ));
+
+        if tranches.current as usize == tranches.tranches.len() {
+            Self::close_tranches(sale.external_id, tranches)
+        } else {
+            MilestoneTranchesMap::<T>::insert(sale.external_id, tranches);
+            Ok(())
+        }
+    }
+
+    /// Moves funds that are still locked to be released to the new team of the project
+    pub(super) fn move_tranches_to_team(project_id: ProjectId, team_id: &T::AccountId) {
+        for (sale_id, _) in MilestoneTranchesIdByProjectId::iter_prefix(project_id) {
+            MilestoneTranchesMap::<T>::mutate(sale_id, |maybe_tranches| {
+                if let Some(tranches) = maybe_tranches {
+                    tranches.team_id = team_id.clone();
+                }
+            });
+        }
+    }
+
+    /// Returns funds that are still locked to investors pro rata when the milestone
+    /// of the current tranche wasn't approved before its deadline
+    #[transactional]
+    pub(super) fn refund_missed_milestone_impl(sale_id: InvestmentId, investors: u32) -> DispatchResult {
+        let sale = SimpleCrowdfundingMap::<T>::try_get(sale_id)
+            .map_err(|_| Error::<T>::InvestmentOpportunityNotFound)?;
+        ensure!(
+            matches!(sale.status, SimpleCrowdfundingStatus::Finished),
+            Error::<T>::MilestoneTranchesNotFunded
+        );
+
+        let mut tranches = MilestoneTranchesMap::<T>::get(sale_id).ok_or(Error::<T>::NoSuchMilestoneTranches)?;
+        // the milestone may be reported, but investors didn't approve it in time
+        let tranche = tranches.current_tranche_mut().ok_or(Error::<T>::MilestoneTranchesAlreadyReleased)?;
+        let now = pallet_timestamp::Module::<T>::get();
+        ensure!(now > tranche.terms.deadline, Error::<T>::MilestoneDeadlineNotPassed);
+
+        let investments = InvestmentMap::<T>::get(sale_id);
+        ensure!(investments.len() as u32 <= investors, Error::<T>::MilestoneInvestorsWitnessInvalid);
+        let invested = investments.iter().fold(Zero::zero(), |total: DeipAssetBalanceOf<T>, (_, investment)| {
+            total.saturating_add(investment.amount)
+        });
+
+        let mut remainder = tranches.locked;
+        for (index, (investor, investment)) in investments.iter().enumerate() {
+            // the last investor gets the rounding remainder
+            let refund: DeipAssetBalanceOf<T> = if index + 1 == investments.len() {
+                remainder
+            } else {
+                (tranches.locked.saturated_into::<u128>()
+                    .saturating_mul(investment.amount.saturated_into())
+                    / invested.saturated_into::<u128>())
+                    .saturated_into()
+            };
+
+            if !refund.is_zero() {
+                T::AssetSystem::transfer_from_reserved(sale_id, investor, sale.asset_id, refund)
+                    .map_err(|_| Error::<T>::MilestoneTrancheTransferFailed)?;
+            }
+            remainder = remainder.saturating_sub(refund);
+
+            Self::deposit_event(RawEvent::MilestoneTrancheRefunded(
+                sale_id,
+                investor.clone(),
+                DeipAsset::new(sale.asset_id, refund),
+            ));
+        }
+
+        tranches.locked = Zero::zero();
+        tranches.current = tranches.tranches.len() as u32;
+        Self::close_tranches(sale_id, tranches)?;
+
+        Self::deposit_event(RawEvent::MilestoneDeadlineMissed(sale_id));
+
+        Ok(())
+    }
+
+    /// Weight of `refund_missed_milestone` that refunds every investor of the sale
+    pub(super) fn refund_missed_milestone_weight(investors: u32) -> Weight {
+        let investors = investors as Weight;
+        T::DbWeight::get().reads_writes(6 + 3 * investors, 5 + 3 * investors)
+    }
+
+    /// All funds are either released or refunded, so the investment account isn't needed anymore
+    fn close_tranches(sale_id: InvestmentId, tranches: TranchesOf<T>) -> DispatchResult {
+        T::AssetSystem::transactionally_unreserve(sale_id)
+            .map_err(|_| Error::<T>::MilestoneTrancheTransferFailed)?;
+
+        InvestmentMap::<T>::remove(sale_id);
+        MilestoneApprovals::<T>::remove_prefix(sale_id);
+        MilestoneTranchesMap::<T>::insert(sale_id, tranches);
+
+        Ok(())
+    }
+
+    pub fn get_milestone_tranches(sale_id: &InvestmentId) -> Option<TranchesOf<T>> {
+        MilestoneTranchesMap::<T>::get(sale_id)
+    }
+}
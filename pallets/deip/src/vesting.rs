@@ -0,0 +1,158 @@
+use crate::*;
+
+use crate::traits::DeipAssetSystem;
+use sp_runtime::{
+    traits::{Saturating, Zero},
+    SaturatedConversion,
+};
+
+/// Release of security tokens distributed by the investment opportunity.
+/// Nothing is released before `cliff`, then tokens are released linearly
+/// as if the release had started at `start` so that all are released by `end`.
+#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct Schedule<Moment> {
+    pub start: Moment,
+    pub cliff: Moment,
+    pub end: Moment,
+}
+
+/// Security tokens distributed to the account and locked under the vesting schedule
+#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct Lock<AssetId, Balance> {
+    pub asset_id: AssetId,
+    pub total: Balance,
+    pub claimed: Balance,
+}
+
+/// State of tokens under the vesting schedule at the current moment
+#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct Balance<AssetId, AssetBalance> {
+    pub sale_id: InvestmentId,
+    pub asset_id: AssetId,
+    /// Released, but not claimed yet
+    pub vested: AssetBalance,
+    /// Not released yet
+    pub locked: AssetBalance,
+}
+
+impl<Moment: Ord> Schedule<Moment> {
+    pub(super) fn is_valid(&self) -> bool {
+        self.start < self.end && self.start <= self.cliff && self.cliff <= self.end
+    }
+}
+
+impl<T: Config> Module<T> {
+    /// Transfers shares of the sale to `who` and locks them if the sale has a vesting schedule
+    pub(super) fn transfer_shares(
+        sale_id: InvestmentId,
+        who: &T::AccountId,
+        asset_id: DeipAssetIdOf<T>,
+        amount: DeipAssetBalanceOf<T>,
+    ) -> Result<(), ()> {
+        T::AssetSystem::transfer_from_reserved(sale_id, who, asset_id, amount).map_err(|_| ())?;
+
+        if !VestingScheduleMap::<T>::contains_key(sale_id) {
+            return Ok(());
+        }
+
+        T::AssetSystem::lock(who, asset_id, amount)?;
+
+        VestingLocks::<T>::mutate(who, sale_id, |locks| {
+            match locks.iter_mut().find(|lock| lock.asset_id == asset_id) {
+                Some(lock) => lock.total = lock.total.saturating_add(amount),
+                None => locks.push(VestingLock {
+                    asset_id,
+                    total: amount,
+                    claimed: Zero::zero(),
+                }),
+            }
+        });
+
+        Ok(())
+    }
+
+    fn vested_amount(
+        schedule: &VestingScheduleOf<T>,
+        total: DeipAssetBalanceOf<T>,
+        now: T::Moment,
+    ) -> DeipAssetBalanceOf<T> {
+        if now < schedule.cliff {
+            return Zero::zero();
+        }
+        if now >= schedule.end {
+            return total;
+        }
+
+        let elapsed: u128 = (now - schedule.start).saturated_into();
+        let duration: u128 = (schedule.end - schedule.start).saturated_into();
+
+        (total.saturated_into::<u128>().saturating_mul(elapsed) / duration).saturated_into()
+    }
+
+    pub(super) fn claim_vested_impl(account: T::AccountId, sale_id: InvestmentId) -> DispatchResult {
+        let schedule = VestingScheduleMap::<T>::get(sale_id).ok_or(Error::<T>::VestingScheduleNotFound)?;
+        let mut locks = VestingLocks::<T>::get(&account, sale_id);
+        ensure!(!locks.is_empty(), Error::<T>::VestingNothingToClaim);
+
+        let now = pallet_timestamp::Module::<T>::get();
+        let mut claimed_any = false;
+        for lock in locks.iter_mut() {
+            let vested = Self::vested_amount(&schedule, lock.total, now);
+            let claimable = vested.saturating_sub(lock.claimed);
+            if claimable.is_zero() {
+                continue;
+            }
+
+            T::AssetSystem::unlock(&account, lock.asset_id, claimable);
+            lock.claimed = vested;
+            claimed_any = true;
+
+            Self::deposit_event(RawEvent::VestedClaimed(
+                sale_id,
+                account.clone(),
+                DeipAsset::new(lock.asset_id, claimable),
+            ));
+        }
+
+        ensure!(claimed_any, Error::<T>::VestingNothingToClaim);
+
+        locks.retain(|lock| lock.claimed < lock.total);
+        if locks.is_empty() {
+            VestingLocks::<T>::remove(&account, sale_id);
+        } else {
+            VestingLocks::<T>::insert(&account, sale_id, locks);
+        }
+
+        Ok(())
+    }
+
+    pub fn get_vesting_schedule(sale_id: &InvestmentId) -> Option<VestingScheduleOf<T>> {
+        VestingScheduleMap::<T>::get(sale_id)
+    }
+
+    /// Returns vested and still locked amounts of every asset the account got under vesting schedules
+    pub fn get_vesting_balances(account: &T::AccountId) -> Vec<VestingBalanceOf<T>> {
+        let now = pallet_timestamp::Module::<T>::get();
+
+        VestingLocks::<T>::iter_prefix(account)
+            .flat_map(|(sale_id, locks)| {
+                let schedule = VestingScheduleMap::<T>::get(sale_id).unwrap_or_default();
+                locks.into_iter().map(move |lock| {
+                    let vested = Self::vested_amount(&schedule, lock.total, now);
+                    VestingBalance {
+                        sale_id,
+                        asset_id: lock.asset_id,
+                        vested: vested.saturating_sub(lock.claimed),
+                        locked: lock.total.saturating_sub(vested),
+                    }
+                })
+            })
+            .collect()
+    }
+}
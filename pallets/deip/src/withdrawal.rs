@@ -0,0 +1,106 @@
+use crate::*;
+
+use crate::traits::DeipAssetSystem;
+use frame_support::transactional;
+use sp_runtime::{
+    traits::{Saturating, Zero},
+    Percent,
+};
+
+/// Conditions investors withdraw their contributions from the active sale on
+#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct Terms<Moment> {
+    /// Time since the first contribution of the investor before it can be withdrawn
+    pub cooldown: Moment,
+    /// Part of the withdrawn amount that goes to the creator of the sale
+    pub fee: Percent,
+}
+
+impl<T: Config> Module<T> {
+    #[transactional]
+    pub(super) fn withdraw_investment_impl(
+        account: T::AccountId,
+        sale_id: InvestmentId,
+        amount: DeipAssetBalanceOf<T>,
+    ) -> DispatchResult {
+        let sale = SimpleCrowdfundingMap::<T>::try_get(sale_id)
+            .map_err(|_| Error::<T>::InvestmentOpportunityNotFound)?;
+        ensure!(
+            matches!(sale.status, SimpleCrowdfundingStatus::Active),
+            Error::<T>::InvestmentOpportunityShouldBeActive
+        );
+        // shares of the fixed price sale are already bought, bids of the dutch auction
+        // claim shares at the price of the moment and contributions in several assets
+        // can't be told apart by the amount to withdraw
+        ensure!(
+            !FixedPriceMaxPerAccount::<T>::contains_key(sale_id)
+                && !DutchAuctionMap::<T>::contains_key(sale_id)
+                && !ExchangeRatesMap::<T>::contains_key(sale_id),
+            Error::<T>::WithdrawalNotSupported
+        );
+        ensure!(!amount.is_zero(), Error::<T>::WithdrawalAmountMustBePositive);
+
+        let mut investments = InvestmentMap::<T>::get(sale_id);
+        let index = investments
+            .binary_search_by_key(&&account, |(investor, _)| investor)
+            .map_err(|_| Error::<T>::WithdrawalNoContribution)?;
+        let contribution = &mut investments[index].1;
+        ensure!(
+            contribution.amount >= amount,
+            Error::<T>::WithdrawalAmountExceedsContribution
+        );
+
+        let terms = WithdrawalTermsMap::<T>::get(sale_id).unwrap_or_default();
+        ensure!(
+            pallet_timestamp::Module::<T>::get() >= contribution.time.saturating_add(terms.cooldown),
+            Error::<T>::WithdrawalCooldownNotPassed
+        );
+
+        let fee = terms.fee * amount;
+        let withdrawn = amount - fee;
+        if !fee.is_zero() {
+            let creator = T::AssetSystem::get_investment_creator(&sale_id)
+                .ok_or(Error::<T>::WithdrawalTransferFailed)?;
+            T::AssetSystem::transfer_from_reserved(sale_id, &creator, sale.asset_id, fee)
+                .map_err(|_| Error::<T>::WithdrawalTransferFailed)?;
+        }
+        if !withdrawn.is_zero() {
+            T::AssetSystem::transfer_from_reserved(sale_id, &account, sale.asset_id, withdrawn)
+                .map_err(|_| Error::<T>::WithdrawalTransferFailed)?;
+        }
+
+        contribution.amount -= amount;
+        for (asset_id, paid) in contribution.assets.iter_mut() {
+            if *asset_id == sale.asset_id {
+                *paid = paid.saturating_sub(amount);
+            }
+        }
+        if contribution.amount.is_zero() {
+            investments.remove(index);
+            InvestmentIdByInvestor::<T>::remove(&account, sale_id);
+        }
+        if investments.is_empty() {
+            InvestmentMap::<T>::remove(sale_id);
+        } else {
+            InvestmentMap::<T>::insert(sale_id, investments);
+        }
+
+        SimpleCrowdfundingMap::<T>::mutate(sale_id, |sale| {
+            sale.total_amount = sale.total_amount.saturating_sub(amount);
+        });
+
+        Self::deposit_event(RawEvent::InvestmentWithdrawn(
+            sale_id,
+            account,
+            DeipAsset::new(sale.asset_id, withdrawn),
+        ));
+
+        Ok(())
+    }
+
+    pub fn get_withdrawal_terms(sale_id: &InvestmentId) -> Option<WithdrawalTermsOf<T>> {
+        WithdrawalTermsMap::<T>::get(sale_id)
+    }
+}
@@ -0,0 +1,52 @@
+use codec::Codec;
+use sp_runtime::traits::Zero;
+use sp_std::prelude::*;
+
+use super::{
+    AssetMetadata, AssetMetadataMap, Config, CoreAssetId, DeipInvestmentIdOf, DeipProjectIdOf,
+    DividendId, InvestmentByAssetId, KnownAssets, Pallet, ProjectIdByAssetId, SnapshotId,
+};
+
+sp_api::decl_runtime_apis! {
+    pub trait DeipAssetsRuntimeApi<AssetId, AccountId, Balance, ProjectId, InvestmentId>
+        where AssetId: Codec, AccountId: Codec, Balance: Codec, ProjectId: Codec, InvestmentId: Codec
+    {
+        /// Amount of the payout asset the account can claim from the dividend
+        fn claimable_dividend(id: DividendId, account: AccountId) -> Balance;
+        /// Balance of the account at the snapshot of the asset
+        fn balance_at(asset: AssetId, account: AccountId, snapshot_id: SnapshotId) -> Option<Balance>;
+        /// Total supply of the asset at the snapshot
+        fn total_supply_at(asset: AssetId, snapshot_id: SnapshotId) -> Option<Balance>;
+        /// Security tokens of the project
+        fn project_assets(project_id: ProjectId) -> Vec<AssetId>;
+        /// Project the security token belongs to
+        fn asset_project(asset: AssetId) -> Option<ProjectId>;
+        fn core_asset_id() -> AssetId;
+        /// Investments that reserve the asset
+        fn asset_investments(asset: AssetId) -> Vec<InvestmentId>;
+        /// Positive balances of the account in assets created through the pallet
+        fn account_balances(account: AccountId) -> Vec<(AssetId, Balance)>;
+        fn asset_metadata(asset: AssetId) -> Option<AssetMetadata>;
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    pub fn rpc_asset_project(asset: T::AssetId) -> Option<DeipProjectIdOf<T>> {
+        ProjectIdByAssetId::<T>::get(asset)
+    }
+    pub fn rpc_core_asset_id() -> T::AssetId {
+        CoreAssetId::<T>::get()
+    }
+    pub fn rpc_asset_investments(asset: T::AssetId) -> Vec<DeipInvestmentIdOf<T>> {
+        InvestmentByAssetId::<T>::get(asset).unwrap_or_default()
+    }
+    pub fn rpc_account_balances(who: &T::AccountId) -> Vec<(T::AssetId, T::Balance)> {
+        KnownAssets::<T>::iter()
+            .map(|(asset, _)| (asset, pallet_assets::Module::<T>::balance(asset, who.clone())))
+            .filter(|(_, balance)| !balance.is_zero())
+            .collect()
+    }
+    pub fn rpc_asset_metadata(asset: T::AssetId) -> Option<AssetMetadata> {
+        AssetMetadataMap::<T>::get(asset)
+    }
+}
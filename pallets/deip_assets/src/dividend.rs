@@ -0,0 +1,206 @@
+use frame_support::{
+    pallet_prelude::*,
+    traits::{Currency, ExistenceRequirement, UnfilteredDispatchable, WithdrawReasons},
+    transactional,
+};
+use frame_system::RawOrigin;
+use sp_runtime::{
+    traits::{Saturating, StaticLookup, Zero},
+    SaturatedConversion,
+};
+
+use super::traits::DeipProjectsInfo;
+use super::{
+    Config, DividendClaims, DividendCount, DividendMap, DividendOf, Error, Event, Pallet,
+    ProjectIdByAssetId, SnapshotId,
+};
+
+/// Identifier of a dividend
+pub type DividendId = u32;
+
+/// Payout to holders of a security token pro rata to their balances at the snapshot
+#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq)]
+pub struct Dividend<AccountId, AssetId, Balance, BlockNumber> {
+    /// Account that paid the dividend. It gets back the unclaimed remainder
+    pub distributor: AccountId,
+    /// Security token holders of which are paid
+    pub asset: AssetId,
+    /// Snapshot of balances of the security token
+    pub snapshot_id: SnapshotId,
+    pub payout_asset: AssetId,
+    pub amount: Balance,
+    /// Part of the amount that isn't claimed yet
+    pub unclaimed: Balance,
+    /// Holders can't claim the dividend since the block
+    pub expires_at: BlockNumber,
+}
+
+impl<T: Config> Pallet<T> {
+    pub fn dividend_key(id: DividendId) -> T::AccountId {
+        let entropy = (b"deip/dividends/", id).using_encoded(sp_io::hashing::blake2_256);
+        T::AccountId::decode(&mut &entropy[..]).unwrap_or_default()
+    }
+
+    #[transactional]
+    pub(crate) fn distribute_dividend_impl(
+        account: T::AccountId,
+        asset: T::AssetId,
+        payout_asset: T::AssetId,
+        amount: T::Balance,
+    ) -> DispatchResult {
+        let project_id =
+            ProjectIdByAssetId::<T>::get(asset).ok_or(Error::<T>::DividendAssetNotSecurityToken)?;
+        ensure!(
+            T::ProjectsInfo::try_get_project_team(&project_id).as_ref() == Some(&account),
+            Error::<T>::ProjectDoesNotBelongToTeam
+        );
+        ensure!(payout_asset != asset, Error::<T>::DividendPayoutAssetIsSecurityToken);
+        ensure!(!amount.is_zero(), Error::<T>::DividendAmountMustBePositive);
+        ensure!(
+            Self::unlocked_balance(payout_asset, &account) >= amount,
+            Error::<T>::InsufficientUnlockedBalance
+        );
+
+        let id = DividendCount::<T>::get();
+        let id_account = Self::dividend_key(id);
+
+        // the account of the dividend isn't a zombie of the payout asset
+        let deposit = T::Currency::withdraw(
+            &account,
+            T::Currency::minimum_balance(),
+            WithdrawReasons::RESERVE,
+            ExistenceRequirement::AllowDeath,
+        )
+        .map_err(|_| Error::<T>::DividendTransferFailed)?;
+        T::Currency::resolve_creating(&id_account, deposit);
+
+        Self::transfer_payout(payout_asset, &account, &id_account, amount)?;
+
+        let snapshot_id = Self::take_snapshot(asset);
+        let expires_at =
+            frame_system::Module::<T>::block_number().saturating_add(T::DividendClaimPeriod::get());
+
+        DividendMap::<T>::insert(
+            id,
+            DividendOf::<T> {
+                distributor: account,
+                asset,
+                snapshot_id,
+                payout_asset,
+                amount,
+                unclaimed: amount,
+                expires_at,
+            },
+        );
+        DividendCount::<T>::put(id.saturating_add(1));
+
+        Self::deposit_event(Event::DividendDistributed(id, asset, payout_asset, amount));
+
+        Ok(())
+    }
+
+    #[transactional]
+    pub(crate) fn claim_dividend_impl(account: T::AccountId, id: DividendId) -> DispatchResult {
+        let mut dividend = DividendMap::<T>::get(id).ok_or(Error::<T>::NoSuchDividend)?;
+        ensure!(
+            frame_system::Module::<T>::block_number() < dividend.expires_at,
+            Error::<T>::DividendExpired
+        );
+        ensure!(
+            !DividendClaims::<T>::contains_key(id, &account),
+            Error::<T>::DividendAlreadyClaimed
+        );
+
+        let share = Self::dividend_share(&dividend, &account);
+        ensure!(!share.is_zero(), Error::<T>::DividendNothingToClaim);
+
+        Self::transfer_payout(dividend.payout_asset, &Self::dividend_key(id), &account, share)?;
+
+        dividend.unclaimed = dividend.unclaimed.saturating_sub(share);
+        DividendMap::<T>::insert(id, dividend);
+        DividendClaims::<T>::insert(id, &account, ());
+
+        Self::deposit_event(Event::DividendClaimed(id, account, share));
+
+        Ok(())
+    }
+
+    #[transactional]
+    pub(crate) fn reclaim_dividend_impl(id: DividendId) -> DispatchResult {
+        let dividend = DividendMap::<T>::get(id).ok_or(Error::<T>::NoSuchDividend)?;
+        ensure!(
+            frame_system::Module::<T>::block_number() >= dividend.expires_at,
+            Error::<T>::DividendNotExpired
+        );
+
+        // rounding leaves dust on the account of the dividend, it goes back too
+        let id_account = Self::dividend_key(id);
+        let unclaimed = pallet_assets::Module::<T>::balance(dividend.payout_asset, id_account.clone());
+        if !unclaimed.is_zero() {
+            Self::transfer_payout(dividend.payout_asset, &id_account, &dividend.distributor, unclaimed)?;
+        }
+
+        let deposited =
+            T::Currency::deposit_creating(&dividend.distributor, T::Currency::minimum_balance());
+        T::Currency::settle(
+            &id_account,
+            deposited,
+            WithdrawReasons::TRANSFER,
+            ExistenceRequirement::AllowDeath,
+        )
+        .map_err(|_| Error::<T>::DividendTransferFailed)?;
+
+        DividendMap::<T>::remove(id);
+        DividendClaims::<T>::remove_prefix(id);
+
+        Self::deposit_event(Event::DividendReclaimed(id, unclaimed));
+
+        Ok(())
+    }
+
+    /// Returns the amount of the payout asset the account can claim from the dividend
+    pub fn claimable_dividend(id: DividendId, who: &T::AccountId) -> T::Balance {
+        match DividendMap::<T>::get(id) {
+            Some(dividend)
+                if frame_system::Module::<T>::block_number() < dividend.expires_at
+                    && !DividendClaims::<T>::contains_key(id, who) =>
+            {
+                Self::dividend_share(&dividend, who)
+            }
+            _ => Zero::zero(),
+        }
+    }
+
+    fn dividend_share(dividend: &DividendOf<T>, who: &T::AccountId) -> T::Balance {
+        let balance = Self::balance_at(dividend.asset, who, dividend.snapshot_id).unwrap_or_default();
+        let total_supply =
+            Self::total_supply_at(dividend.asset, dividend.snapshot_id).unwrap_or_default();
+        if total_supply.is_zero() {
+            return Zero::zero();
+        }
+
+        (dividend
+            .amount
+            .saturated_into::<u128>()
+            .saturating_mul(balance.saturated_into())
+            / total_supply.saturated_into::<u128>())
+        .saturated_into()
+    }
+
+    fn transfer_payout(
+        asset: T::AssetId,
+        from: &T::AccountId,
+        to: &T::AccountId,
+        amount: T::Balance,
+    ) -> DispatchResult {
+        Self::record_balances(asset, &[from, to]);
+
+        let to_source = <T::Lookup as StaticLookup>::unlookup(to.clone());
+        let call = pallet_assets::Call::<T>::transfer(asset, to_source, amount);
+        call.dispatch_bypass_filter(RawOrigin::Signed(from.clone()).into())
+            .map_err(|_| Error::<T>::DividendTransferFailed)?;
+        Self::update_holders(asset, &[from, to]);
+
+        Ok(())
+    }
+}
@@ -24,6 +24,13 @@
 //! * [`set_team`](./enum.Call.html#variant.set_team)
 //! * [`set_max_zombies`](./enum.Call.html#variant.set_max_zombies)
 //! * [`set_metadata`](./enum.Call.html#variant.set_metadata)
+//! * [`snapshot`](./enum.Call.html#variant.snapshot)
+//! * [`distribute_dividend`](./enum.Call.html#variant.distribute_dividend)
+//! * [`claim_dividend`](./enum.Call.html#variant.claim_dividend)
+//! * [`reclaim_dividend`](./enum.Call.html#variant.reclaim_dividend)
+//! * [`set_transfer_rules`](./enum.Call.html#variant.set_transfer_rules)
+//! * [`update_holder_allowlist`](./enum.Call.html#variant.update_holder_allowlist)
+//! * [`set_jurisdiction`](./enum.Call.html#variant.set_jurisdiction)
 //!
 //! [`Config`]: ./trait.Config.html
 
@@ -38,6 +45,20 @@ pub mod traits;
 pub mod serializable;
 pub use serializable::{AssetBalance as SerializableAssetBalance, AssetId as SerializableAssetId};
 
+pub mod api;
+
+mod snapshot;
+pub use snapshot::SnapshotId;
+
+mod dividend;
+pub use dividend::{Dividend, DividendId};
+
+mod transfer_rules;
+pub use transfer_rules::{Jurisdiction, TransferRules};
+
+mod migrations;
+pub use migrations::Releases;
+
 #[doc(inline)]
 pub use pallet::*;
 
@@ -46,33 +67,54 @@ pub use pallet::*;
 pub mod pallet {
     use frame_support::pallet_prelude::*;
     use frame_support::{
-        traits::{Currency, ExistenceRequirement, UnfilteredDispatchable, WithdrawReasons},
+        traits::{
+            Currency, EnsureOrigin, ExistenceRequirement, UnfilteredDispatchable, UnixTime,
+            WithdrawReasons,
+        },
         transactional,
     };
     use frame_system::{pallet_prelude::*, RawOrigin};
-    use sp_runtime::traits::{One, StaticLookup, Zero};
+    use sp_runtime::traits::{One, Saturating, StaticLookup, Zero};
     use sp_std::{prelude::*, vec};
 
+    #[cfg(feature = "std")]
+    use serde::{Deserialize, Serialize};
+
     #[cfg(feature = "std")]
     use frame_support::traits::GenesisBuild;
 
     use pallet_assets::WeightInfo;
 
     use super::traits::DeipProjectsInfo;
+    use super::{Dividend, DividendId, Jurisdiction, Releases, SnapshotId, TransferRules};
 
     type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
-    type DeipProjectIdOf<T> =
+    pub(crate) type DeipProjectIdOf<T> =
         <<T as Config>::ProjectsInfo as DeipProjectsInfo<AccountIdOf<T>>>::ProjectId;
-    type DeipInvestmentIdOf<T> =
+    pub(crate) type DeipInvestmentIdOf<T> =
         <<T as Config>::ProjectsInfo as DeipProjectsInfo<AccountIdOf<T>>>::InvestmentId;
     pub(crate) type AssetsAssetIdOf<T> = <T as pallet_assets::Config>::AssetId;
     pub(crate) type AssetsBalanceOf<T> = <T as pallet_assets::Config>::Balance;
     type AssetsWeightInfoOf<T> = <T as pallet_assets::Config>::WeightInfo;
+    pub(crate) type DividendOf<T> = Dividend<
+        AccountIdOf<T>,
+        AssetsAssetIdOf<T>,
+        AssetsBalanceOf<T>,
+        <T as frame_system::Config>::BlockNumber,
+    >;
 
     #[pallet::config]
     pub trait Config: frame_system::Config + pallet_assets::Config {
+        type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
         type ProjectsInfo: DeipProjectsInfo<Self::AccountId>;
         type DeipAccountId: Into<Self::AccountId> + Parameter + Member;
+        /// Period holders have to claim a dividend. Then the unclaimed remainder can be
+        /// returned to the distributor
+        type DividendClaimPeriod: Get<Self::BlockNumber>;
+        /// Source of the time lock-ups of transfer rules are checked against
+        type UnixTime: UnixTime;
+        /// Origin allowed to set jurisdictions of accounts
+        type JurisdictionOrigin: EnsureOrigin<Self::Origin>;
     }
 
     #[doc(hidden)]
@@ -82,7 +124,31 @@ pub mod pallet {
 
     #[doc(hidden)]
     #[pallet::hooks]
-    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_runtime_upgrade() -> Weight {
+            super::migrations::migrate::<T>()
+        }
+    }
+
+    #[pallet::event]
+    #[pallet::metadata(T::AccountId = "AccountId", T::AssetId = "AssetId", T::Balance = "Balance")]
+    #[pallet::generate_deposit(pub(crate) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// Balances of the asset are recorded at the snapshot. [asset, snapshot_id]
+        SnapshotTaken(T::AssetId, SnapshotId),
+        /// Dividend is distributed to holders of the security token. [dividend_id, asset, payout_asset, amount]
+        DividendDistributed(DividendId, T::AssetId, T::AssetId, T::Balance),
+        /// The holder claimed its share of the dividend. [dividend_id, who, amount]
+        DividendClaimed(DividendId, T::AccountId, T::Balance),
+        /// Unclaimed remainder of the expired dividend returned to the distributor. [dividend_id, amount]
+        DividendReclaimed(DividendId, T::Balance),
+        /// Transfer rules of the security token are set or removed. [asset]
+        TransferRulesSet(T::AssetId),
+        /// Accounts are added to or removed from the holder allowlist of the security token. [asset]
+        HolderAllowlistUpdated(T::AssetId),
+        /// Jurisdiction of the account is set or removed. [who, jurisdiction]
+        JurisdictionSet(T::AccountId, Option<Jurisdiction>),
+    }
 
     #[pallet::error]
     pub enum Error<T> {
@@ -94,6 +160,37 @@ pub mod pallet {
         ProjectSecurityTokenAccountCannotBeFreezed,
         ReservedAssetCannotBeFreezed,
         ReservedAssetAccountCannotBeFreezed,
+        /// Part of the balance is locked, e.g. by a vesting schedule
+        InsufficientUnlockedBalance,
+        /// Dividends are paid to holders of project security tokens only
+        DividendAssetNotSecurityToken,
+        /// Dividend can't be paid in the security token itself
+        DividendPayoutAssetIsSecurityToken,
+        /// Amount of the dividend should be positive
+        DividendAmountMustBePositive,
+        /// Failed to transfer the payout asset
+        DividendTransferFailed,
+        NoSuchDividend,
+        /// The claim period of the dividend is over
+        DividendExpired,
+        /// The claim period of the dividend isn't over yet
+        DividendNotExpired,
+        /// The holder already claimed its share of the dividend
+        DividendAlreadyClaimed,
+        /// The account held no security tokens at the snapshot of the dividend
+        DividendNothingToClaim,
+        /// Transfer rules can be set for project security tokens only
+        TransferRulesAssetNotSecurityToken,
+        /// The asset is locked up by its transfer rules
+        TransferLockedUp,
+        /// The recipient isn't in the holder allowlist of the asset
+        TransferRecipientNotAllowlisted,
+        /// Transfer rules of the asset require a jurisdiction the recipient has no one
+        TransferRecipientJurisdictionUnknown,
+        /// Jurisdiction of the recipient isn't allowed by transfer rules of the asset
+        TransferRecipientJurisdictionNotAllowed,
+        /// The recipient would exceed the maximum number of holders of the asset
+        TransferMaxHoldersExceeded,
     }
 
     #[pallet::storage]
@@ -127,6 +224,116 @@ pub mod pallet {
         OptionQuery,
     >;
 
+    /// Assets created through the pallet
+    #[pallet::storage]
+    pub(super) type KnownAssets<T: Config> =
+        StorageMap<_, Identity, AssetsAssetIdOf<T>, (), OptionQuery>;
+
+    #[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq)]
+    #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+    pub struct AssetMetadata {
+        pub name: Vec<u8>,
+        pub symbol: Vec<u8>,
+        pub decimals: u8,
+    }
+
+    /// Metadata set through the pallet
+    #[pallet::storage]
+    pub(super) type AssetMetadataMap<T: Config> =
+        StorageMap<_, Identity, AssetsAssetIdOf<T>, AssetMetadata, OptionQuery>;
+
+    /// Storage layout version, used to run migrations on runtime upgrade
+    #[pallet::storage]
+    pub(super) type StorageVersion<T: Config> = StorageValue<_, Releases, ValueQuery>;
+
+    /// Amounts of the asset the account isn't allowed to transfer
+    #[pallet::storage]
+    pub(super) type LockedBalance<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        AssetsAssetIdOf<T>,
+        Blake2_128Concat,
+        AccountIdOf<T>,
+        AssetsBalanceOf<T>,
+        ValueQuery,
+    >;
+
+    /// Last snapshot of balances of the asset
+    #[pallet::storage]
+    pub(super) type CurrentSnapshotId<T: Config> =
+        StorageMap<_, Identity, AssetsAssetIdOf<T>, SnapshotId, ValueQuery>;
+
+    /// Balances of the account at snapshots of the asset in order of ids.
+    /// The balance is recorded before it changes first after the snapshot
+    #[pallet::storage]
+    pub(super) type BalanceSnapshots<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        AssetsAssetIdOf<T>,
+        Blake2_128Concat,
+        AccountIdOf<T>,
+        Vec<(SnapshotId, AssetsBalanceOf<T>)>,
+        ValueQuery,
+    >;
+
+    /// Total supply of the asset at snapshots in order of ids
+    #[pallet::storage]
+    pub(super) type TotalSupplySnapshots<T: Config> = StorageMap<
+        _,
+        Identity,
+        AssetsAssetIdOf<T>,
+        Vec<(SnapshotId, AssetsBalanceOf<T>)>,
+        ValueQuery,
+    >;
+
+    #[pallet::storage]
+    pub(super) type DividendCount<T> = StorageValue<_, DividendId, ValueQuery>;
+
+    #[pallet::storage]
+    pub(super) type DividendMap<T: Config> =
+        StorageMap<_, Identity, DividendId, DividendOf<T>, OptionQuery>;
+
+    /// Holders that claimed their shares of the dividend
+    #[pallet::storage]
+    pub(super) type DividendClaims<T: Config> =
+        StorageDoubleMap<_, Identity, DividendId, Blake2_128Concat, AccountIdOf<T>, (), OptionQuery>;
+
+    #[pallet::storage]
+    pub(super) type TransferRulesMap<T: Config> =
+        StorageMap<_, Identity, AssetsAssetIdOf<T>, TransferRules, OptionQuery>;
+
+    /// Accounts allowed to receive the asset if its transfer rules require so
+    #[pallet::storage]
+    pub(super) type HolderAllowlist<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        AssetsAssetIdOf<T>,
+        Blake2_128Concat,
+        AccountIdOf<T>,
+        (),
+        OptionQuery,
+    >;
+
+    /// Accounts with positive balances of project security tokens
+    #[pallet::storage]
+    pub(super) type Holders<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        AssetsAssetIdOf<T>,
+        Blake2_128Concat,
+        AccountIdOf<T>,
+        (),
+        OptionQuery,
+    >;
+
+    #[pallet::storage]
+    pub(super) type HolderCount<T: Config> =
+        StorageMap<_, Identity, AssetsAssetIdOf<T>, u32, ValueQuery>;
+
+    #[pallet::storage]
+    pub(super) type AccountJurisdiction<T: Config> =
+        StorageMap<_, Blake2_128Concat, AccountIdOf<T>, Jurisdiction, OptionQuery>;
+
     #[pallet::genesis_config]
     pub struct GenesisConfig<T: Config> {
         pub core_asset_admin: AccountIdOf<T>,
@@ -149,6 +356,8 @@ pub mod pallet {
     impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
         fn build(&self) {
             CoreAssetId::<T>::put(self.core_asset_id.0);
+            KnownAssets::<T>::insert(self.core_asset_id.0, ());
+            StorageVersion::<T>::put(Releases::V3);
 
             let admin_source = T::Lookup::unlookup(self.core_asset_admin.clone());
             let call = pallet_assets::Call::<T>::create(
@@ -199,12 +408,45 @@ pub mod pallet {
             T::AccountId::decode(&mut &entropy[..]).unwrap_or_default()
         }
 
+        pub fn project_security_tokens(id: &DeipProjectIdOf<T>) -> Vec<T::AssetId> {
+            AssetIdByProjectId::<T>::try_get(id.clone()).unwrap_or_default()
+        }
+
+        pub fn investment_creator(id: &DeipInvestmentIdOf<T>) -> Option<T::AccountId> {
+            InvestmentMap::<T>::get(id.clone()).map(|investment| investment.creator)
+        }
+
+        /// Returns the part of the balance the account is allowed to transfer
+        pub fn unlocked_balance(asset: T::AssetId, who: &T::AccountId) -> T::Balance {
+            pallet_assets::Module::<T>::balance(asset, who.clone())
+                .saturating_sub(LockedBalance::<T>::get(asset, who))
+        }
+
+        /// Forbids `who` to transfer `amount` of `asset` until it is unlocked.
+        /// The amount should be present on the balance and not locked yet.
+        pub fn lock(who: &T::AccountId, asset: T::AssetId, amount: T::Balance) -> Result<(), ()> {
+            ensure!(Self::unlocked_balance(asset, who) >= amount, ());
+
+            LockedBalance::<T>::mutate(asset, who, |locked| *locked = locked.saturating_add(amount));
+
+            Ok(())
+        }
+
+        /// Allows `who` to transfer `amount` of `asset` locked earlier
+        pub fn unlock(who: &T::AccountId, asset: T::AssetId, amount: T::Balance) {
+            LockedBalance::<T>::mutate_exists(asset, who, |maybe_locked| {
+                let locked = maybe_locked.unwrap_or_default().saturating_sub(amount);
+                *maybe_locked = if locked.is_zero() { None } else { Some(locked) };
+            });
+        }
+
         #[transactional]
         pub fn transactionally_reserve(
             account: &T::AccountId,
             id: DeipInvestmentIdOf<T>,
             security_tokens_on_sale: &[(T::AssetId, T::Balance)],
             asset_to_raise: T::AssetId,
+            other_assets_to_raise: &[T::AssetId],
         ) -> Result<(), deip_assets_error::ReserveError<T::AssetId>> {
             use deip_assets_error::ReserveError;
 
@@ -230,11 +472,17 @@ pub mod pallet {
                 Vec::<T::AssetId>::with_capacity(security_tokens_on_sale.len());
 
             for (asset, amount) in security_tokens_on_sale {
+                if Self::unlocked_balance(*asset, account) < *amount {
+                    return Err(ReserveError::AssetTransferFailed(*asset));
+                }
+
+                Self::record_balances(*asset, &[account, &id_account]);
                 let call = pallet_assets::Call::<T>::transfer(*asset, id_source.clone(), *amount);
                 let result = call.dispatch_bypass_filter(RawOrigin::Signed(account.clone()).into());
                 if result.is_err() {
                     return Err(ReserveError::AssetTransferFailed(*asset));
                 }
+                Self::update_holders(*asset, &[account, &id_account]);
 
                 assets_to_reserve.push(*asset);
 
@@ -246,12 +494,18 @@ pub mod pallet {
                 });
             }
 
-            InvestmentByAssetId::<T>::mutate_exists(asset_to_raise, |investments| {
-                match investments.as_mut() {
-                    None => *investments = Some(vec![id.clone()]),
-                    Some(c) => c.push(id.clone()),
-                };
-            });
+            // other assets to raise are kept along with security tokens,
+            // so that they are returned to the creator on unreserve
+            assets_to_reserve.extend_from_slice(other_assets_to_raise);
+
+            for asset in other_assets_to_raise.iter().chain(&[asset_to_raise]) {
+                InvestmentByAssetId::<T>::mutate_exists(*asset, |investments| {
+                    match investments.as_mut() {
+                        None => *investments = Some(vec![id.clone()]),
+                        Some(c) => c.push(id.clone()),
+                    };
+                });
+            }
 
             InvestmentMap::<T>::insert(
                 id.clone(),
@@ -283,25 +537,29 @@ pub mod pallet {
             let creator_source = <T::Lookup as StaticLookup>::unlookup(info.creator.clone());
 
             for asset_id in info.assets.iter().chain(&[info.asset_id]) {
-                InvestmentByAssetId::<T>::mutate_exists(*asset_id, |maybe_investments| {
-                    let investments = maybe_investments
-                        .as_mut()
-                        .expect("checked in transactionally_reserve");
-                    let index = investments
-                        .iter()
-                        .position(|a| *a == id)
-                        .expect("checked in transactionally_reserve");
-                    investments.remove(index);
-                    if investments.is_empty() {
-                        *maybe_investments = None;
-                    }
-                });
+                InvestmentByAssetId::<T>::try_mutate_exists(
+                    *asset_id,
+                    |maybe_investments| -> Result<_, UnreserveError<T::AssetId>> {
+                        let investments =
+                            maybe_investments.as_mut().ok_or(UnreserveError::NoSuchInvestment)?;
+                        let index = investments
+                            .iter()
+                            .position(|a| *a == id)
+                            .ok_or(UnreserveError::NoSuchInvestment)?;
+                        investments.remove(index);
+                        if investments.is_empty() {
+                            *maybe_investments = None;
+                        }
+                        Ok(())
+                    },
+                )?;
 
                 let amount = pallet_assets::Module::<T>::balance(*asset_id, id_account.clone());
                 if amount.is_zero() {
                     continue;
                 }
 
+                Self::record_balances(*asset_id, &[&id_account, &info.creator]);
                 let call =
                     pallet_assets::Call::<T>::transfer(*asset_id, creator_source.clone(), amount);
                 let result =
@@ -309,6 +567,7 @@ pub mod pallet {
                 if result.is_err() {
                     return Err(UnreserveError::AssetTransferFailed(*asset_id));
                 }
+                Self::update_holders(*asset_id, &[&id_account, &info.creator]);
             }
 
             T::Currency::settle(
@@ -317,7 +576,7 @@ pub mod pallet {
                 WithdrawReasons::TRANSFER,
                 ExistenceRequirement::AllowDeath,
             )
-            .unwrap_or_else(|_| panic!("should be reserved in transactionally_reserve"));
+            .map_err(|_| UnreserveError::NotEnoughBalance)?;
 
             Ok(())
         }
@@ -338,11 +597,40 @@ pub mod pallet {
             let id_account = Self::investment_key(&id);
             let who_source = <T::Lookup as StaticLookup>::unlookup(who.clone());
 
+            Self::ensure_settlement_allowed(asset, &id_account, who, amount)
+                .map_err(|_| UnreserveError::AssetTransferFailed(asset))?;
+            Self::record_balances(asset, &[&id_account, who]);
             let call = pallet_assets::Call::<T>::transfer(asset, who_source, amount);
             let result = call.dispatch_bypass_filter(RawOrigin::Signed(id_account.clone()).into());
             if result.is_err() {
                 return Err(UnreserveError::AssetTransferFailed(asset));
             }
+            Self::update_holders(asset, &[&id_account, who]);
+
+            Ok(())
+        }
+
+        #[transactional]
+        pub fn transactionally_transfer(
+            from: &T::AccountId,
+            asset: T::AssetId,
+            transfers: &[(T::AccountId, T::Balance)],
+        ) -> Result<(), ()> {
+            for (to, amount) in transfers {
+                if Self::unlocked_balance(asset, from) < *amount {
+                    return Err(());
+                }
+                Self::ensure_transfer_allowed(asset, from, to, *amount).map_err(|_| ())?;
+
+                Self::record_balances(asset, &[from, to]);
+                let to_source = <T::Lookup as StaticLookup>::unlookup(to.clone());
+                let call = pallet_assets::Call::<T>::transfer(asset, to_source, *amount);
+                let result = call.dispatch_bypass_filter(RawOrigin::Signed(from.clone()).into());
+                if result.is_err() {
+                    return Err(());
+                }
+                Self::update_holders(asset, &[from, to]);
+            }
 
             Ok(())
         }
@@ -350,6 +638,7 @@ pub mod pallet {
         pub fn transfer_to_reserved(
             who: &T::AccountId,
             id: DeipInvestmentIdOf<T>,
+            asset: T::AssetId,
             amount: T::Balance,
         ) -> Result<(), deip_assets_error::UnreserveError<T::AssetId>> {
             use deip_assets_error::UnreserveError;
@@ -359,14 +648,26 @@ pub mod pallet {
                 Err(_) => return Err(UnreserveError::NoSuchInvestment),
             };
 
+            if asset != info.asset_id && !info.assets.contains(&asset) {
+                return Err(UnreserveError::AssetTransferFailed(asset));
+            }
+
+            if Self::unlocked_balance(asset, who) < amount {
+                return Err(UnreserveError::AssetTransferFailed(asset));
+            }
+
             let id_account = Self::investment_key(&id);
-            let id_source = <T::Lookup as StaticLookup>::unlookup(id_account);
+            Self::ensure_settlement_allowed(asset, who, &id_account, amount)
+                .map_err(|_| UnreserveError::AssetTransferFailed(asset))?;
+            Self::record_balances(asset, &[who, &id_account]);
+            let id_source = <T::Lookup as StaticLookup>::unlookup(id_account.clone());
 
-            let call = pallet_assets::Call::<T>::transfer(info.asset_id, id_source, amount);
+            let call = pallet_assets::Call::<T>::transfer(asset, id_source, amount);
             let result = call.dispatch_bypass_filter(RawOrigin::Signed(who.clone()).into());
             if result.is_err() {
-                return Err(UnreserveError::AssetTransferFailed(info.asset_id));
+                return Err(UnreserveError::AssetTransferFailed(asset));
             }
+            Self::update_holders(asset, &[who, &id_account]);
 
             Ok(())
         }
@@ -400,6 +701,8 @@ pub mod pallet {
                 return result;
             }
 
+            KnownAssets::<T>::insert(id, ());
+
             if let Some(project_id) = project_id {
                 ProjectIdByAssetId::<T>::insert(id, project_id.clone());
                 AssetIdByProjectId::<T>::mutate_exists(project_id, |security_tokens| {
@@ -425,22 +728,35 @@ pub mod pallet {
             );
 
             let call = pallet_assets::Call::<T>::destroy(id, zombies_witness);
-            call.dispatch_bypass_filter(origin)
+            let result = call.dispatch_bypass_filter(origin);
+            if result.is_ok() {
+                KnownAssets::<T>::remove(id);
+                AssetMetadataMap::<T>::remove(id);
+            }
+
+            result
         }
 
-        #[pallet::weight(AssetsWeightInfoOf::<T>::mint())]
+        #[pallet::weight(AssetsWeightInfoOf::<T>::mint().saturating_add(Pallet::<T>::record_weight(1, true)))]
         pub(super) fn issue_asset(
             origin: OriginFor<T>,
             #[pallet::compact] id: T::AssetId,
             beneficiary: T::DeipAccountId,
             #[pallet::compact] amount: AssetsBalanceOf<T>,
         ) -> DispatchResultWithPostInfo {
-            let beneficiary_source = <T::Lookup as StaticLookup>::unlookup(beneficiary.into());
+            let beneficiary = beneficiary.into();
+            Self::ensure_issue_allowed(id, &beneficiary, amount)?;
+            Self::record_balances(id, &[&beneficiary]);
+            Self::record_total_supply(id);
+
+            let beneficiary_source = <T::Lookup as StaticLookup>::unlookup(beneficiary.clone());
             let call = pallet_assets::Call::<T>::mint(id, beneficiary_source, amount);
-            call.dispatch_bypass_filter(origin)
+            let result = call.dispatch_bypass_filter(origin);
+            Self::update_holders(id, &[&beneficiary]);
+            result
         }
 
-        #[pallet::weight(AssetsWeightInfoOf::<T>::burn())]
+        #[pallet::weight(AssetsWeightInfoOf::<T>::burn().saturating_add(Pallet::<T>::record_weight(1, true)))]
         pub(super) fn burn(
             origin: OriginFor<T>,
             #[pallet::compact] id: T::AssetId,
@@ -452,21 +768,39 @@ pub mod pallet {
                 Error::<T>::ProjectSecurityTokenCannotBeBurned
             );
 
-            let who_source = <T::Lookup as StaticLookup>::unlookup(who.into());
+            let who = who.into();
+            Self::record_balances(id, &[&who]);
+            Self::record_total_supply(id);
+
+            let who_source = <T::Lookup as StaticLookup>::unlookup(who.clone());
             let call = pallet_assets::Call::<T>::burn(id, who_source, amount);
-            call.dispatch_bypass_filter(origin)
+            let result = call.dispatch_bypass_filter(origin);
+            Self::update_holders(id, &[&who]);
+            result
         }
 
-        #[pallet::weight(AssetsWeightInfoOf::<T>::transfer())]
+        #[pallet::weight(AssetsWeightInfoOf::<T>::transfer().saturating_add(Pallet::<T>::record_weight(2, false)))]
         pub(super) fn transfer(
             origin: OriginFor<T>,
             #[pallet::compact] id: T::AssetId,
             target: T::DeipAccountId,
             #[pallet::compact] amount: AssetsBalanceOf<T>,
         ) -> DispatchResultWithPostInfo {
-            let target_source = <T::Lookup as StaticLookup>::unlookup(target.into());
+            let who = ensure_signed(origin.clone())?;
+            ensure!(
+                Self::unlocked_balance(id, &who) >= amount,
+                Error::<T>::InsufficientUnlockedBalance
+            );
+
+            let target = target.into();
+            Self::ensure_transfer_allowed(id, &who, &target, amount)?;
+            Self::record_balances(id, &[&who, &target]);
+
+            let target_source = <T::Lookup as StaticLookup>::unlookup(target.clone());
             let call = pallet_assets::Call::<T>::transfer(id, target_source, amount);
-            call.dispatch_bypass_filter(origin)
+            let result = call.dispatch_bypass_filter(origin);
+            Self::update_holders(id, &[&who, &target]);
+            result
         }
 
         #[pallet::weight(AssetsWeightInfoOf::<T>::freeze())]
@@ -566,7 +900,8 @@ pub mod pallet {
             call.dispatch_bypass_filter(origin)
         }
 
-        #[pallet::weight(AssetsWeightInfoOf::<T>::set_metadata(name.len() as u32, symbol.len() as u32))]
+        #[pallet::weight(AssetsWeightInfoOf::<T>::set_metadata(name.len() as u32, symbol.len() as u32)
+            .saturating_add(T::DbWeight::get().writes(1)))]
         pub(super) fn set_metadata(
             origin: OriginFor<T>,
             #[pallet::compact] id: T::AssetId,
@@ -574,8 +909,157 @@ pub mod pallet {
             symbol: Vec<u8>,
             decimals: u8,
         ) -> DispatchResultWithPostInfo {
-            let call = pallet_assets::Call::<T>::set_metadata(id, name, symbol, decimals);
-            call.dispatch_bypass_filter(origin)
+            let call =
+                pallet_assets::Call::<T>::set_metadata(id, name.clone(), symbol.clone(), decimals);
+            let result = call.dispatch_bypass_filter(origin);
+            if result.is_ok() {
+                // `pallet_assets` clears the metadata if both name and symbol are empty
+                if name.is_empty() && symbol.is_empty() {
+                    AssetMetadataMap::<T>::remove(id);
+                } else {
+                    AssetMetadataMap::<T>::insert(id, AssetMetadata { name, symbol, decimals });
+                }
+            }
+
+            result
+        }
+
+        /// Records balances and the total supply of the asset at the moment. They are available
+        /// by the id from `SnapshotTaken` event.
+        ///
+        /// The origin must be the team of the project for project security tokens
+        /// and _Root_ for other assets.
+        #[pallet::weight(T::DbWeight::get().reads_writes(3, 2))]
+        pub(super) fn snapshot(
+            origin: OriginFor<T>,
+            #[pallet::compact] id: T::AssetId,
+        ) -> DispatchResultWithPostInfo {
+            match ProjectIdByAssetId::<T>::get(id) {
+                Some(project_id) => {
+                    let account = ensure_signed(origin)?;
+                    ensure!(
+                        T::ProjectsInfo::try_get_project_team(&project_id) == Some(account),
+                        Error::<T>::ProjectDoesNotBelongToTeam
+                    );
+                }
+                None => ensure_root(origin)?,
+            };
+
+            Self::take_snapshot(id);
+
+            Ok(().into())
+        }
+
+        /// Pays `amount` of `payout_asset` to holders of the project security token `id`
+        /// pro rata to their balances at the moment. Holders claim their shares
+        /// during `DividendClaimPeriod`.
+        ///
+        /// The origin must be the team of the project.
+        #[pallet::weight(AssetsWeightInfoOf::<T>::transfer().saturating_add(T::DbWeight::get().reads_writes(4, 5)))]
+        pub(super) fn distribute_dividend(
+            origin: OriginFor<T>,
+            #[pallet::compact] id: T::AssetId,
+            #[pallet::compact] payout_asset: T::AssetId,
+            #[pallet::compact] amount: AssetsBalanceOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            let account = ensure_signed(origin)?;
+            Self::distribute_dividend_impl(account, id, payout_asset, amount)?;
+            Ok(().into())
+        }
+
+        /// Transfers to the holder its share of the dividend.
+        #[pallet::weight(AssetsWeightInfoOf::<T>::transfer().saturating_add(T::DbWeight::get().reads_writes(5, 3)))]
+        pub(super) fn claim_dividend(
+            origin: OriginFor<T>,
+            dividend_id: DividendId,
+        ) -> DispatchResultWithPostInfo {
+            let account = ensure_signed(origin)?;
+            Self::claim_dividend_impl(account, dividend_id)?;
+            Ok(().into())
+        }
+
+        /// Returns the unclaimed remainder of the expired dividend to the distributor.
+        /// Anyone can call it.
+        #[pallet::weight(AssetsWeightInfoOf::<T>::transfer().saturating_add(T::DbWeight::get().reads_writes(3, 4)))]
+        pub(super) fn reclaim_dividend(
+            origin: OriginFor<T>,
+            dividend_id: DividendId,
+        ) -> DispatchResultWithPostInfo {
+            ensure_signed(origin)?;
+            Self::reclaim_dividend_impl(dividend_id)?;
+            Ok(().into())
+        }
+
+        /// Sets transfer rules `transfer` enforces for the project security token `id`.
+        /// `None` removes the rules.
+        ///
+        /// The origin must be the team of the project.
+        #[pallet::weight(T::DbWeight::get().reads_writes(2, 1))]
+        pub(super) fn set_transfer_rules(
+            origin: OriginFor<T>,
+            #[pallet::compact] id: T::AssetId,
+            rules: Option<TransferRules>,
+        ) -> DispatchResultWithPostInfo {
+            let account = ensure_signed(origin)?;
+            Self::ensure_security_token_team(id, &account)?;
+
+            match rules {
+                Some(rules) => TransferRulesMap::<T>::insert(id, rules),
+                None => TransferRulesMap::<T>::remove(id),
+            };
+
+            Self::deposit_event(Event::TransferRulesSet(id));
+
+            Ok(().into())
+        }
+
+        /// Adds accounts to and removes them from the holder allowlist of the project
+        /// security token `id`.
+        ///
+        /// The origin must be the team of the project.
+        #[pallet::weight(T::DbWeight::get().reads_writes(2, (add.len() + remove.len()) as Weight))]
+        pub(super) fn update_holder_allowlist(
+            origin: OriginFor<T>,
+            #[pallet::compact] id: T::AssetId,
+            add: Vec<T::DeipAccountId>,
+            remove: Vec<T::DeipAccountId>,
+        ) -> DispatchResultWithPostInfo {
+            let account = ensure_signed(origin)?;
+            Self::ensure_security_token_team(id, &account)?;
+
+            for who in add {
+                HolderAllowlist::<T>::insert(id, who.into(), ());
+            }
+            for who in remove {
+                HolderAllowlist::<T>::remove(id, who.into());
+            }
+
+            Self::deposit_event(Event::HolderAllowlistUpdated(id));
+
+            Ok(().into())
+        }
+
+        /// Tags the account with the jurisdiction transfer rules are checked against.
+        /// `None` removes the tag.
+        ///
+        /// The origin must be `JurisdictionOrigin`.
+        #[pallet::weight(T::DbWeight::get().writes(1))]
+        pub(super) fn set_jurisdiction(
+            origin: OriginFor<T>,
+            who: T::DeipAccountId,
+            jurisdiction: Option<Jurisdiction>,
+        ) -> DispatchResultWithPostInfo {
+            T::JurisdictionOrigin::ensure_origin(origin)?;
+
+            let who = who.into();
+            match jurisdiction {
+                Some(jurisdiction) => AccountJurisdiction::<T>::insert(&who, jurisdiction),
+                None => AccountJurisdiction::<T>::remove(&who),
+            };
+
+            Self::deposit_event(Event::JurisdictionSet(who, jurisdiction));
+
+            Ok(().into())
         }
     }
 }
@@ -0,0 +1,94 @@
+use frame_support::pallet_prelude::*;
+use sp_std::{collections::btree_set::BTreeSet, prelude::*};
+
+use super::{
+    BalanceSnapshots, Config, CoreAssetId, DividendClaims, InvestmentByAssetId, InvestmentMap,
+    KnownAssets, LockedBalance, Pallet, ProjectIdByAssetId, StorageVersion,
+};
+
+/// Storage layout versions of the pallet
+#[derive(Encode, Decode, Clone, Copy, RuntimeDebug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Releases {
+    /// Assets created through the pallet aren't registered
+    V1,
+    /// Assets created through the pallet are registered in `KnownAssets`
+    V2,
+    /// Holders of project security tokens are tracked in `Holders`
+    V3,
+}
+
+impl Default for Releases {
+    fn default() -> Self {
+        Releases::V1
+    }
+}
+
+/// Brings the storage to the latest layout, returns consumed weight
+pub(crate) fn migrate<T: Config>() -> Weight {
+    let mut weight = T::DbWeight::get().reads(1);
+
+    if StorageVersion::<T>::get() < Releases::V2 {
+        weight = weight.saturating_add(v2::migrate::<T>());
+        StorageVersion::<T>::put(Releases::V2);
+        weight = weight.saturating_add(T::DbWeight::get().writes(1));
+    }
+
+    if StorageVersion::<T>::get() < Releases::V3 {
+        weight = weight.saturating_add(v3::migrate::<T>());
+        StorageVersion::<T>::put(Releases::V3);
+        weight = weight.saturating_add(T::DbWeight::get().writes(1));
+    }
+
+    weight
+}
+
+mod v2 {
+    use super::*;
+
+    /// Registers assets created before the registry was introduced: the core asset,
+    /// project security tokens and assets reserved or raised by investments
+    pub(super) fn migrate<T: Config>() -> Weight {
+        let mut assets = BTreeSet::new();
+        assets.insert(CoreAssetId::<T>::get());
+        assets.extend(ProjectIdByAssetId::<T>::iter().map(|(id, _)| id));
+        assets.extend(InvestmentByAssetId::<T>::iter().map(|(id, _)| id));
+
+        for id in &assets {
+            KnownAssets::<T>::insert(id, ());
+        }
+
+        let count = assets.len() as Weight;
+        T::DbWeight::get().reads_writes(count + 1, count)
+    }
+}
+
+mod v3 {
+    use super::*;
+
+    /// Fills `Holders` and `HolderCount` with accounts having positive balances of
+    /// project security tokens. Balances of `pallet_assets` can't be iterated, so
+    /// candidates are system accounts and accounts known to the pallet.
+    pub(super) fn migrate<T: Config>() -> Weight {
+        let assets: Vec<_> = ProjectIdByAssetId::<T>::iter().collect();
+
+        let mut candidates: BTreeSet<T::AccountId> =
+            frame_system::Account::<T>::iter().map(|(who, _)| who).collect();
+        candidates.extend(LockedBalance::<T>::iter().map(|(_, who, _)| who));
+        candidates.extend(BalanceSnapshots::<T>::iter().map(|(_, who, _)| who));
+        candidates.extend(DividendClaims::<T>::iter().map(|(_, who, _)| who));
+        candidates
+            .extend(InvestmentMap::<T>::iter().map(|(id, _)| Pallet::<T>::investment_key(&id)));
+        candidates.extend(assets.iter().map(|(_, project)| Pallet::<T>::project_key(project)));
+
+        let reads = candidates.len() + assets.len();
+        for (asset, _) in &assets {
+            for who in &candidates {
+                Pallet::<T>::update_holders(*asset, &[who]);
+            }
+        }
+
+        // every pair reads the index, the balance and the holder and may update the count
+        let pairs = (assets.len() * candidates.len()) as Weight;
+        T::DbWeight::get().reads_writes(reads as Weight + 3 * pairs, 2 * pairs)
+    }
+}
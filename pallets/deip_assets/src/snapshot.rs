@@ -0,0 +1,99 @@
+use frame_support::pallet_prelude::*;
+
+use super::{BalanceSnapshots, Config, CurrentSnapshotId, Event, Pallet, TotalSupplySnapshots};
+
+/// Identifier of a snapshot of balances of an asset, unique per asset
+pub type SnapshotId = u32;
+
+/// Value at the snapshot is recorded before it changes first after the snapshot,
+/// so a snapshot without record holds the value of the next recorded one or the current value
+fn value_at<Balance: Copy>(records: &[(SnapshotId, Balance)], id: SnapshotId) -> Option<Balance> {
+    let index = match records.binary_search_by_key(&id, |(snapshot_id, _)| *snapshot_id) {
+        Ok(index) | Err(index) => index,
+    };
+    records.get(index).map(|(_, value)| *value)
+}
+
+impl<T: Config> Pallet<T> {
+    pub(crate) fn take_snapshot(asset: T::AssetId) -> SnapshotId {
+        let id = CurrentSnapshotId::<T>::mutate(asset, |id| {
+            *id = id.saturating_add(1);
+            *id
+        });
+
+        Self::deposit_event(Event::SnapshotTaken(asset, id));
+
+        id
+    }
+
+    /// Records balances of `accounts` at the last snapshot of the asset. Called before balances change
+    pub(crate) fn record_balances(asset: T::AssetId, accounts: &[&T::AccountId]) {
+        let current = CurrentSnapshotId::<T>::get(asset);
+        if current == 0 {
+            return;
+        }
+
+        for who in accounts {
+            let mut records = BalanceSnapshots::<T>::get(asset, *who);
+            if records.last().map_or(false, |(id, _)| *id == current) {
+                continue;
+            }
+
+            records.push((current, pallet_assets::Module::<T>::balance(asset, (*who).clone())));
+            BalanceSnapshots::<T>::insert(asset, *who, records);
+        }
+    }
+
+    /// Weight of recording balances of `accounts` accounts and, if `total_supply`,
+    /// the total supply of the asset
+    pub(crate) fn record_weight(accounts: Weight, total_supply: bool) -> Weight {
+        // the current snapshot along with records and balances of accounts
+        let mut weight = T::DbWeight::get().reads_writes(1 + 2 * accounts, accounts);
+        if total_supply {
+            weight = weight.saturating_add(T::DbWeight::get().reads_writes(2, 1));
+        }
+        weight
+    }
+
+    /// Records the total supply at the last snapshot of the asset. Called before it changes
+    pub(crate) fn record_total_supply(asset: T::AssetId) {
+        let current = CurrentSnapshotId::<T>::get(asset);
+        if current == 0 {
+            return;
+        }
+
+        let mut records = TotalSupplySnapshots::<T>::get(asset);
+        if records.last().map_or(false, |(id, _)| *id == current) {
+            return;
+        }
+
+        records.push((current, pallet_assets::Module::<T>::total_supply(asset)));
+        TotalSupplySnapshots::<T>::insert(asset, records);
+    }
+
+    /// Returns the balance of the account at the snapshot or `None` if there is no such snapshot
+    pub fn balance_at(
+        asset: T::AssetId,
+        who: &T::AccountId,
+        id: SnapshotId,
+    ) -> Option<T::Balance> {
+        if id == 0 || id > CurrentSnapshotId::<T>::get(asset) {
+            return None;
+        }
+
+        let balance = value_at(&BalanceSnapshots::<T>::get(asset, who), id)
+            .unwrap_or_else(|| pallet_assets::Module::<T>::balance(asset, who.clone()));
+        Some(balance)
+    }
+
+    /// Returns the total supply of the asset at the snapshot or `None` if there is no such snapshot
+    pub fn total_supply_at(asset: T::AssetId, id: SnapshotId) -> Option<T::Balance> {
+        if id == 0 || id > CurrentSnapshotId::<T>::get(asset) {
+            return None;
+        }
+
+        let total_supply = value_at(&TotalSupplySnapshots::<T>::get(asset), id)
+            .unwrap_or_else(|| pallet_assets::Module::<T>::total_supply(asset));
+        Some(total_supply)
+    }
+}
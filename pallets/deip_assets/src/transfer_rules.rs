@@ -0,0 +1,158 @@
+use frame_support::{pallet_prelude::*, traits::UnixTime};
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_runtime::traits::Zero;
+use sp_std::prelude::*;
+
+use super::traits::DeipProjectsInfo;
+use super::{
+    AccountJurisdiction, Config, Error, HolderAllowlist, HolderCount, Holders, Pallet,
+    ProjectIdByAssetId, TransferRulesMap,
+};
+
+/// Jurisdiction of an account as ISO 3166-1 alpha-2 country code, e.g. `*b"DE"`
+pub type Jurisdiction = [u8; 2];
+
+/// Restrictions on transfers of a project security token through `transfer`
+#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct TransferRules {
+    /// Only accounts in the holder allowlist of the asset can receive it
+    pub holder_allowlist: bool,
+    /// Maximum number of accounts holding the asset
+    pub max_holders: Option<u32>,
+    /// Transfers are forbidden until the moment, milliseconds since the Unix epoch
+    pub locked_until: Option<u64>,
+    /// Recipients should be tagged with one of the jurisdictions. Any account if empty
+    pub jurisdictions: Vec<Jurisdiction>,
+}
+
+impl<T: Config> Pallet<T> {
+    /// Checks that the transfer of `amount` of the asset from `from` to `to` satisfies
+    /// transfer rules of the asset
+    pub(crate) fn ensure_transfer_allowed(
+        asset: T::AssetId,
+        from: &T::AccountId,
+        to: &T::AccountId,
+        amount: T::Balance,
+    ) -> DispatchResult {
+        Self::ensure_rules_met(asset, Some(from), to, amount, true)
+    }
+
+    /// Same as `ensure_transfer_allowed`, but the lock-up doesn't apply to transfers
+    /// between investors and investment opportunities
+    pub(crate) fn ensure_settlement_allowed(
+        asset: T::AssetId,
+        from: &T::AccountId,
+        to: &T::AccountId,
+        amount: T::Balance,
+    ) -> DispatchResult {
+        Self::ensure_rules_met(asset, Some(from), to, amount, false)
+    }
+
+    /// Checks that issuing `amount` of the asset to `to` satisfies transfer rules of the asset
+    pub(crate) fn ensure_issue_allowed(
+        asset: T::AssetId,
+        to: &T::AccountId,
+        amount: T::Balance,
+    ) -> DispatchResult {
+        Self::ensure_rules_met(asset, None, to, amount, false)
+    }
+
+    /// Tokens come from `from` or are issued if it's `None`
+    fn ensure_rules_met(
+        asset: T::AssetId,
+        from: Option<&T::AccountId>,
+        to: &T::AccountId,
+        amount: T::Balance,
+        check_lock_up: bool,
+    ) -> DispatchResult {
+        let rules = match TransferRulesMap::<T>::get(asset) {
+            None => return Ok(()),
+            Some(rules) => rules,
+        };
+
+        if let (true, Some(locked_until)) = (check_lock_up, rules.locked_until) {
+            ensure!(
+                T::UnixTime::now().as_millis() >= locked_until as u128,
+                Error::<T>::TransferLockedUp
+            );
+        }
+
+        if rules.holder_allowlist {
+            ensure!(
+                HolderAllowlist::<T>::contains_key(asset, to),
+                Error::<T>::TransferRecipientNotAllowlisted
+            );
+        }
+
+        if !rules.jurisdictions.is_empty() {
+            let jurisdiction = AccountJurisdiction::<T>::get(to)
+                .ok_or(Error::<T>::TransferRecipientJurisdictionUnknown)?;
+            ensure!(
+                rules.jurisdictions.contains(&jurisdiction),
+                Error::<T>::TransferRecipientJurisdictionNotAllowed
+            );
+        }
+
+        if let Some(max_holders) = rules.max_holders {
+            let new_holder = from != Some(to)
+                && pallet_assets::Module::<T>::balance(asset, to.clone()).is_zero();
+            // the sender that transfers all its tokens gives its place to the recipient
+            let sender_leaves = from.map_or(false, |from| {
+                pallet_assets::Module::<T>::balance(asset, from.clone()) <= amount
+            });
+            if new_holder && !sender_leaves {
+                ensure!(
+                    HolderCount::<T>::get(asset) < max_holders,
+                    Error::<T>::TransferMaxHoldersExceeded
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn ensure_security_token_team(
+        asset: T::AssetId,
+        who: &T::AccountId,
+    ) -> DispatchResult {
+        let project_id = ProjectIdByAssetId::<T>::get(asset)
+            .ok_or(Error::<T>::TransferRulesAssetNotSecurityToken)?;
+        ensure!(
+            T::ProjectsInfo::try_get_project_team(&project_id).as_ref() == Some(who),
+            Error::<T>::ProjectDoesNotBelongToTeam
+        );
+
+        Ok(())
+    }
+
+    /// Keeps track of holders of project security tokens. Called after balances
+    /// of `accounts` change
+    pub(crate) fn update_holders(asset: T::AssetId, accounts: &[&T::AccountId]) {
+        if !ProjectIdByAssetId::<T>::contains_key(asset) {
+            return;
+        }
+
+        for who in accounts {
+            let holds = !pallet_assets::Module::<T>::balance(asset, (*who).clone()).is_zero();
+            if holds == Holders::<T>::contains_key(asset, *who) {
+                continue;
+            }
+
+            if holds {
+                Holders::<T>::insert(asset, *who, ());
+                HolderCount::<T>::mutate(asset, |count| *count = count.saturating_add(1));
+            } else {
+                Holders::<T>::remove(asset, *who);
+                HolderCount::<T>::mutate(asset, |count| *count = count.saturating_sub(1));
+            }
+        }
+    }
+
+    /// Returns the number of accounts holding the project security token
+    pub fn holder_count(asset: T::AssetId) -> u32 {
+        HolderCount::<T>::get(asset)
+    }
+}
@@ -0,0 +1,144 @@
+use jsonrpc_core::{Error as RpcError, ErrorCode as RpcErrorCode, Result as RpcResult};
+use jsonrpc_derive::rpc;
+
+use std::vec::Vec;
+use std::sync::Arc;
+
+use codec::Codec;
+
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+
+use pallet_deip_assets::{AssetMetadata, DividendId, SnapshotId};
+pub use pallet_deip_assets::api::{DeipAssetsRuntimeApi};
+
+
+#[rpc]
+pub trait DeipAssetsRpcApi<BlockHash, AssetId, AccountId, Balance, ProjectId, InvestmentId> {
+    #[rpc(name = "deipAssets_getClaimableDividend")]
+    fn get_claimable_dividend(&self, at: Option<BlockHash>, id: DividendId, account: AccountId) -> RpcResult<Balance>;
+
+    #[rpc(name = "deipAssets_getBalanceAt")]
+    fn get_balance_at(&self, at: Option<BlockHash>, asset: AssetId, account: AccountId, snapshot_id: SnapshotId) -> RpcResult<Option<Balance>>;
+
+    #[rpc(name = "deipAssets_getTotalSupplyAt")]
+    fn get_total_supply_at(&self, at: Option<BlockHash>, asset: AssetId, snapshot_id: SnapshotId) -> RpcResult<Option<Balance>>;
+
+    #[rpc(name = "deipAssets_getProjectAssets")]
+    fn get_project_assets(&self, at: Option<BlockHash>, project_id: ProjectId) -> RpcResult<Vec<AssetId>>;
+
+    #[rpc(name = "deipAssets_getAssetProject")]
+    fn get_asset_project(&self, at: Option<BlockHash>, asset: AssetId) -> RpcResult<Option<ProjectId>>;
+
+    #[rpc(name = "deipAssets_getCoreAssetId")]
+    fn get_core_asset_id(&self, at: Option<BlockHash>) -> RpcResult<AssetId>;
+
+    #[rpc(name = "deipAssets_getAssetInvestments")]
+    fn get_asset_investments(&self, at: Option<BlockHash>, asset: AssetId) -> RpcResult<Vec<InvestmentId>>;
+
+    #[rpc(name = "deipAssets_getAccountBalances")]
+    fn get_account_balances(&self, at: Option<BlockHash>, account: AccountId) -> RpcResult<Vec<(AssetId, Balance)>>;
+
+    #[rpc(name = "deipAssets_getAssetMetadata")]
+    fn get_asset_metadata(&self, at: Option<BlockHash>, asset: AssetId) -> RpcResult<Option<AssetMetadata>>;
+}
+
+
+/// A struct that implements the `DeipAssetsRpcApi`.
+pub struct DeipAssetsRpcApiObj<C, Block> {
+    client: Arc<C>,
+    _marker: std::marker::PhantomData<Block>,
+}
+
+impl<C, Block> DeipAssetsRpcApiObj<C, Block> {
+    /// Create new `DeipAssetsRpcApiObj` instance with the given reference to the client.
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            _marker: Default::default(),
+        }
+    }
+}
+
+impl<C, Block> DeipAssetsRpcApiObj<C, Block>
+    where
+        Block: BlockT,
+        C: HeaderBackend<Block>,
+{
+    fn block_id(&self, at: Option<<Block as BlockT>::Hash>) -> BlockId<Block> {
+        BlockId::hash(at.unwrap_or_else(||
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash))
+    }
+}
+
+fn to_rpc_error<E: std::fmt::Debug>(e: E) -> RpcError {
+    RpcError {
+        code: RpcErrorCode::ServerError(9876), // No real reason for this value
+        message: "Something wrong".into(),
+        data: Some(format!("{:?}", e).into()),
+    }
+}
+
+impl<C, Block, AssetId, AccountId, Balance, ProjectId, InvestmentId>
+    DeipAssetsRpcApi<<Block as BlockT>::Hash, AssetId, AccountId, Balance, ProjectId, InvestmentId>
+    for DeipAssetsRpcApiObj<C, Block>
+        where
+            Block: BlockT,
+            C: Send + Sync + 'static,
+            C: ProvideRuntimeApi<Block>,
+            C: HeaderBackend<Block>,
+            C::Api: DeipAssetsRuntimeApi<Block, AssetId, AccountId, Balance, ProjectId, InvestmentId>,
+            AssetId: Codec,
+            AccountId: Codec,
+            Balance: Codec,
+            ProjectId: Codec,
+            InvestmentId: Codec,
+{
+    fn get_claimable_dividend(&self, at: Option<<Block as BlockT>::Hash>, id: DividendId, account: AccountId) -> RpcResult<Balance> {
+        let api = self.client.runtime_api();
+        api.claimable_dividend(&self.block_id(at), id, account).map_err(to_rpc_error)
+    }
+
+    fn get_balance_at(&self, at: Option<<Block as BlockT>::Hash>, asset: AssetId, account: AccountId, snapshot_id: SnapshotId) -> RpcResult<Option<Balance>> {
+        let api = self.client.runtime_api();
+        api.balance_at(&self.block_id(at), asset, account, snapshot_id).map_err(to_rpc_error)
+    }
+
+    fn get_total_supply_at(&self, at: Option<<Block as BlockT>::Hash>, asset: AssetId, snapshot_id: SnapshotId) -> RpcResult<Option<Balance>> {
+        let api = self.client.runtime_api();
+        api.total_supply_at(&self.block_id(at), asset, snapshot_id).map_err(to_rpc_error)
+    }
+
+    fn get_project_assets(&self, at: Option<<Block as BlockT>::Hash>, project_id: ProjectId) -> RpcResult<Vec<AssetId>> {
+        let api = self.client.runtime_api();
+        api.project_assets(&self.block_id(at), project_id).map_err(to_rpc_error)
+    }
+
+    fn get_asset_project(&self, at: Option<<Block as BlockT>::Hash>, asset: AssetId) -> RpcResult<Option<ProjectId>> {
+        let api = self.client.runtime_api();
+        api.asset_project(&self.block_id(at), asset).map_err(to_rpc_error)
+    }
+
+    fn get_core_asset_id(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<AssetId> {
+        let api = self.client.runtime_api();
+        api.core_asset_id(&self.block_id(at)).map_err(to_rpc_error)
+    }
+
+    fn get_asset_investments(&self, at: Option<<Block as BlockT>::Hash>, asset: AssetId) -> RpcResult<Vec<InvestmentId>> {
+        let api = self.client.runtime_api();
+        api.asset_investments(&self.block_id(at), asset).map_err(to_rpc_error)
+    }
+
+    fn get_account_balances(&self, at: Option<<Block as BlockT>::Hash>, account: AccountId) -> RpcResult<Vec<(AssetId, Balance)>> {
+        let api = self.client.runtime_api();
+        api.account_balances(&self.block_id(at), account).map_err(to_rpc_error)
+    }
+
+    fn get_asset_metadata(&self, at: Option<<Block as BlockT>::Hash>, asset: AssetId) -> RpcResult<Option<AssetMetadata>> {
+        let api = self.client.runtime_api();
+        api.asset_metadata(&self.block_id(at), asset).map_err(to_rpc_error)
+    }
+}
@@ -85,6 +85,9 @@ pub type DigestItem = generic::DigestItem<Hash>;
 
 pub type AssetId = compact_h160::H160;
 
+/// Balance of a DEIP asset.
+pub type DeipAssetBalance = u64;
+
 /// Opaque types. These are used by the CLI to instantiate machinery that don't need to know
 /// the specifics of the runtime. They can then be made to be agnostic over specific formats
 /// of data like extrinsics, allowing for them to continue syncing the network through upgrades
@@ -113,10 +116,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
     spec_name: create_runtime_str!("node-template"),
     impl_name: create_runtime_str!("node-template"),
     authoring_version: 1,
-    spec_version: 1,
+    spec_version: 2,
     impl_version: 1,
     apis: RUNTIME_API_VERSIONS,
-    transaction_version: 1,
+    transaction_version: 2,
 };
 
 
@@ -282,7 +285,7 @@ impl pallet_template::Config for Runtime {
 }
 
 impl pallet_deip::traits::DeipAssetSystem<AccountId> for Runtime {
-    type Balance = u64;
+    type Balance = DeipAssetBalance;
     type AssetId = AssetId;
 
     fn transactionally_reserve(
@@ -290,8 +293,9 @@ impl pallet_deip::traits::DeipAssetSystem<AccountId> for Runtime {
         id: InvestmentId,
         shares: &[(Self::AssetId, Self::Balance)],
         asset: Self::AssetId,
+        other_assets: &[Self::AssetId],
     ) -> Result<(), deip_assets_error::ReserveError<Self::AssetId>> {
-        DeipAssets::transactionally_reserve(account, id, shares, asset)
+        DeipAssets::transactionally_reserve(account, id, shares, asset, other_assets)
     }
 
     fn transactionally_unreserve(id: InvestmentId) -> Result<(), deip_assets_error::UnreserveError<Self::AssetId>> {
@@ -310,17 +314,54 @@ impl pallet_deip::traits::DeipAssetSystem<AccountId> for Runtime {
     fn transfer_to_reserved(
         who: &AccountId,
         id: InvestmentId,
+        asset: Self::AssetId,
         amount: Self::Balance,
     ) -> Result<(), deip_assets_error::UnreserveError<Self::AssetId>> {
-        DeipAssets::transfer_to_reserved(who, id, amount)
+        DeipAssets::transfer_to_reserved(who, id, asset, amount)
+    }
+
+    fn transactionally_transfer(
+        from: &AccountId,
+        asset: Self::AssetId,
+        transfers: &[(AccountId, Self::Balance)],
+    ) -> Result<(), ()> {
+        DeipAssets::transactionally_transfer(from, asset, transfers)
+    }
+
+    fn lock(who: &AccountId, asset: Self::AssetId, amount: Self::Balance) -> Result<(), ()> {
+        DeipAssets::lock(who, asset, amount)
+    }
+
+    fn unlock(who: &AccountId, asset: Self::AssetId, amount: Self::Balance) {
+        DeipAssets::unlock(who, asset, amount)
+    }
+
+    fn get_investment_creator(id: &InvestmentId) -> Option<AccountId> {
+        DeipAssets::investment_creator(id)
+    }
+
+    fn get_project_security_tokens(id: &ProjectId) -> Vec<Self::AssetId> {
+        DeipAssets::project_security_tokens(id)
     }
 }
 
+parameter_types! {
+    pub const MaxDomains: u32 = 100;
+    pub const MaxTransitionsPerBlock: u32 = 10;
+    pub MaxScheduledWeight: Weight = Perbill::from_percent(10) * BlockWeights::get().max_block;
+    pub const MaxDutchAuctionBids: u32 = 100;
+}
+
 impl pallet_deip::Config for Runtime {
     type Event = Event;
     type DeipAccountId = deip_account::DeipAccountId<Self::AccountId>;
     type Currency = Balances;
     type AssetSystem = Self;
+    type MaxDomains = MaxDomains;
+    type MaxTransitionsPerBlock = MaxTransitionsPerBlock;
+    type MaxScheduledWeight = MaxScheduledWeight;
+    type MaxDutchAuctionBids = MaxDutchAuctionBids;
+    type DomainAdminOrigin = frame_system::EnsureRoot<AccountId>;
 }
 
 parameter_types! {
@@ -404,9 +445,17 @@ impl pallet_deip_assets::traits::DeipProjectsInfo<AccountId> for Runtime {
     }
 }
 
+parameter_types! {
+    pub const DividendClaimPeriod: BlockNumber = 30 * DAYS;
+}
+
 impl pallet_deip_assets::Config for Runtime {
+    type Event = Event;
     type ProjectsInfo = Self;
     type DeipAccountId = deip_account::DeipAccountId<Self::AccountId>;
+    type DividendClaimPeriod = DividendClaimPeriod;
+    type UnixTime = Timestamp;
+    type JurisdictionOrigin = frame_system::EnsureRoot<AccountId>;
 }
 
 // Create the runtime by composing the FRAME pallets that were previously configured.
@@ -432,7 +481,7 @@ construct_runtime!(
         DeipOrg: pallet_deip_org::{Module, Call, Storage, Event<T>, Config},
         Multisig: pallet_multisig::{Module, Call, Storage, Event<T>},
         Assets: pallet_assets::{Module, Storage, Event<T>},
-        DeipAssets: pallet_deip_assets::{Module, Storage, Call, Config<T>},
+        DeipAssets: pallet_deip_assets::{Module, Storage, Call, Config<T>, Event<T>},
     }
 );
 
@@ -544,8 +593,38 @@ impl_runtime_apis! {
         }
     }
     
+    impl pallet_deip_assets::api::DeipAssetsRuntimeApi<Block, AssetId, AccountId, DeipAssetBalance, ProjectId, InvestmentId> for Runtime {
+        fn claimable_dividend(id: pallet_deip_assets::DividendId, account: AccountId) -> DeipAssetBalance {
+            DeipAssets::claimable_dividend(id, &account)
+        }
+        fn balance_at(asset: AssetId, account: AccountId, snapshot_id: pallet_deip_assets::SnapshotId) -> Option<DeipAssetBalance> {
+            DeipAssets::balance_at(asset, &account, snapshot_id)
+        }
+        fn total_supply_at(asset: AssetId, snapshot_id: pallet_deip_assets::SnapshotId) -> Option<DeipAssetBalance> {
+            DeipAssets::total_supply_at(asset, snapshot_id)
+        }
+        fn project_assets(project_id: ProjectId) -> Vec<AssetId> {
+            DeipAssets::project_security_tokens(&project_id)
+        }
+        fn asset_project(asset: AssetId) -> Option<ProjectId> {
+            DeipAssets::rpc_asset_project(asset)
+        }
+        fn core_asset_id() -> AssetId {
+            DeipAssets::rpc_core_asset_id()
+        }
+        fn asset_investments(asset: AssetId) -> Vec<InvestmentId> {
+            DeipAssets::rpc_asset_investments(asset)
+        }
+        fn account_balances(account: AccountId) -> Vec<(AssetId, DeipAssetBalance)> {
+            DeipAssets::rpc_account_balances(&account)
+        }
+        fn asset_metadata(asset: AssetId) -> Option<pallet_deip_assets::AssetMetadata> {
+            DeipAssets::rpc_asset_metadata(asset)
+        }
+    }
+
     // Here we implement our custom runtime API.
-    impl deip_runtime_api::DeipApi<Block,  AccountId> for Runtime {
+    impl deip_runtime_api::DeipApi<Block, AccountId, AssetId, DeipAssetBalance> for Runtime {
         fn get_projects() -> Vec<(ProjectId, AccountId)> {
             // This Runtime API calls into a specific pallet. Calling a pallet is a common
             // design pattern. You can see most other APIs in this file do the same.
@@ -556,30 +635,108 @@ impl_runtime_apis! {
         fn get_project(project_id: &ProjectId) -> Project<Hash, AccountId> {
             Deip::get_project(project_id)
         }
+        fn get_projects_page(filter: &ProjectFilter<AccountId>, start_id: &Option<ProjectId>, limit: u32) -> Vec<Project<Hash, AccountId>> {
+            Deip::get_projects_page(filter, start_id, limit)
+        }
+        fn get_project_removal_witness(project_id: &ProjectId) -> ProjectRemovalWitness {
+            Deip::get_project_removal_witness(project_id)
+        }
         fn get_project_content_list(content_ids: &Option<Vec<ProjectContentId>>) -> Vec<ProjectContent<H256, AccountId>> {
             Deip::get_project_content_list(content_ids)
         }
+        fn get_project_content_page(filter: &ProjectContentFilter, start_id: &Option<ProjectContentId>, limit: u32) -> Vec<ProjectContent<H256, AccountId>> {
+            Deip::get_project_content_page(filter, start_id, limit)
+        }
         fn get_project_content(project_id: &ProjectId, project_content_id: &ProjectContentId) -> ProjectContent<H256, AccountId> {
             Deip::get_project_content(project_id, project_content_id)
         }
+        fn get_project_content_version(project_id: &ProjectId, project_content_id: &ProjectContentId, version: ProjectContentVersion) -> Option<ProjectContent<H256, AccountId>> {
+            Deip::get_project_content_version(project_id, project_content_id, version)
+        }
+        fn get_references_of(project_content_id: &ProjectContentId, depth: u32) -> Vec<ProjectContentId> {
+            Deip::get_references_of(project_content_id, depth)
+        }
+        fn get_cited_by(project_content_id: &ProjectContentId, depth: u32) -> Vec<ProjectContentId> {
+            Deip::get_cited_by(project_content_id, depth)
+        }
+        fn has_valid_licence(account: &AccountId, project_content_id: &ProjectContentId, moment: u64) -> bool {
+            Deip::has_valid_licence(account, project_content_id, &moment)
+        }
         fn get_domains() -> Vec<Domain> {
             Deip::get_domains()
         }
         fn get_domain(domain_id: &DomainId) -> Domain {
             Deip::get_domain(domain_id)
         }
+        fn get_domain_subtree(domain_id: &DomainId) -> Vec<Domain> {
+            Deip::get_domain_subtree(domain_id)
+        }
+        fn get_domain_merge_witness(domain_id: &DomainId) -> DomainMergeWitness {
+            Deip::get_domain_merge_witness(domain_id)
+        }
         fn get_nda_list() -> Vec<Nda<H256, AccountId, u64>> {
             Deip::get_nda_list()
         }
+        fn get_nda_page(start_id: &Option<NdaId>, limit: u32) -> Vec<Nda<H256, AccountId, u64>> {
+            Deip::get_nda_page(start_id, limit)
+        }
         fn get_nda(nda_id: &NdaId) -> Nda<H256, AccountId, u64> {
             Deip::get_nda(nda_id)
         }
         fn get_reviews() -> Vec<Review<H256, AccountId>> {
             Deip::get_reviews()
         }
+        fn get_reviews_page(filter: &ReviewFilter<AccountId>, start_id: &Option<ReviewId>, limit: u32) -> Vec<Review<H256, AccountId>> {
+            Deip::get_reviews_page(filter, start_id, limit)
+        }
         fn get_review(review_id: &ReviewId) -> Review<H256, AccountId> {
             Deip::get_review(review_id)
         }
+        fn get_investment_opportunity(id: &InvestmentId) -> Option<SimpleCrowdfunding<u64, AssetId, DeipAssetBalance>> {
+            Deip::get_investment_opportunity(id)
+        }
+        fn get_investment_opportunities_page(filter: &InvestmentOpportunityFilter<AssetId>, start_id: &Option<InvestmentId>, limit: u32) -> Vec<SimpleCrowdfunding<u64, AssetId, DeipAssetBalance>> {
+            Deip::get_investment_opportunities_page(filter, start_id, limit)
+        }
+        fn get_investments(sale_id: &InvestmentId) -> Vec<Investment<AccountId, AssetId, DeipAssetBalance, u64>> {
+            Deip::get_investments(sale_id)
+        }
+        fn get_invested_opportunities(investor: &AccountId) -> Vec<SimpleCrowdfunding<u64, AssetId, DeipAssetBalance>> {
+            Deip::get_invested_opportunities(investor)
+        }
+        fn get_milestone_tranches(sale_id: &InvestmentId) -> Option<MilestoneTranches<AccountId, DeipAssetBalance, u64>> {
+            Deip::get_milestone_tranches(sale_id)
+        }
+        fn get_fixed_price_max_per_account(sale_id: &InvestmentId) -> Option<DeipAssetBalance> {
+            Deip::get_fixed_price_max_per_account(sale_id)
+        }
+        fn get_dutch_auction(sale_id: &InvestmentId) -> Option<DutchAuctionTerms<DeipAssetBalance>> {
+            Deip::get_dutch_auction(sale_id)
+        }
+        fn get_dutch_auction_price(sale_id: &InvestmentId) -> Option<DeipAssetBalance> {
+            Deip::get_dutch_auction_price(sale_id)
+        }
+        fn get_dutch_auction_bids(sale_id: &InvestmentId) -> Vec<DutchAuctionBid<AccountId, DeipAssetBalance>> {
+            Deip::get_dutch_auction_bids(sale_id)
+        }
+        fn get_vesting_schedule(sale_id: &InvestmentId) -> Option<VestingSchedule<u64>> {
+            Deip::get_vesting_schedule(sale_id)
+        }
+        fn get_vesting_balances(account: &AccountId) -> Vec<VestingBalance<AssetId, DeipAssetBalance>> {
+            Deip::get_vesting_balances(account)
+        }
+        fn is_investor_allowed(sale_id: &InvestmentId, account: &AccountId) -> bool {
+            Deip::is_investor_allowed(sale_id, account)
+        }
+        fn get_contribution_limits(sale_id: &InvestmentId) -> Option<ContributionLimits<DeipAssetBalance>> {
+            Deip::get_contribution_limits(sale_id)
+        }
+        fn get_withdrawal_terms(sale_id: &InvestmentId) -> Option<WithdrawalTerms<u64>> {
+            Deip::get_withdrawal_terms(sale_id)
+        }
+        fn get_exchange_rates(sale_id: &InvestmentId) -> Vec<ExchangeRate<AssetId, DeipAssetBalance>> {
+            Deip::get_exchange_rates(sale_id)
+        }
     }
 
     impl sp_consensus_aura::AuraApi<Block, AuraId> for Runtime {